@@ -0,0 +1,160 @@
+//! Runtime API for querying claims stored by pallet-poe.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use sp_std::vec::Vec;
+
+/// The state of a claim as seen by front-ends, read in one call.
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum ClaimStatus<AccountId, BlockNumber> {
+	/// No claim is stored under the key.
+	NotFound,
+	/// The claim is valid. `expires_in` is the number of blocks until its expiry, or `None` if
+	/// it never expires.
+	Active { owner: AccountId, expires_in: Option<BlockNumber> },
+	/// The claim has passed its expiry block but has not been purged yet.
+	Expired { owner: AccountId },
+}
+
+/// What creating a claim would cost and whether it would succeed, for wallets to show before
+/// the user signs.
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct SimulateResult<Balance> {
+	/// The deposit reserved for the claim.
+	pub deposit: Balance,
+	/// The non-refundable creation fee.
+	pub fee: Balance,
+	/// Whether the claim would be created.
+	pub would_succeed: bool,
+	/// The stable code of the pallet error creation would fail with, as returned by
+	/// `Pallet::error_code`, if it fails with one.
+	pub error: Option<u16>,
+}
+
+/// [`SimulateResult`] as of version 2, reporting the index of the pallet error rather than its
+/// stable code.
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct SimulateResultBeforeVersion3<Balance> {
+	/// The deposit reserved for the claim.
+	pub deposit: Balance,
+	/// The non-refundable creation fee.
+	pub fee: Balance,
+	/// Whether the claim would be created.
+	pub would_succeed: bool,
+	/// The index of the pallet error creation would fail with, if it fails with one.
+	pub error: Option<u8>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Read access to the claims stored by pallet-poe, for light clients and front-ends.
+	///
+	/// Version 2 added the `viewer` of private claims to `get_claim`, `claim_exists`,
+	/// `claim_history`, `claim_status` and `ownership_challenge`. Clients of a runtime at version
+	/// 1 call the `*_before_version_2` methods instead. Version 3 reports the stable error code
+	/// in `simulate_create`; clients of a runtime at version 2 call
+	/// `simulate_create_before_version_3`.
+	#[api_version(3)]
+	pub trait PoeApi<AccountId, BlockNumber, Balance>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// `get_claim` without a viewer, as of version 1.
+		#[changed_in(2)]
+		fn get_claim(namespace: Vec<u8>, claim: Vec<u8>) -> Option<(AccountId, BlockNumber)>;
+
+		/// The owner and creation block of `claim` within `namespace`, unless it is missing,
+		/// expired, or private and `viewer` is neither one of its owners nor one of its viewers.
+		fn get_claim(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<AccountId>,
+		) -> Option<(AccountId, BlockNumber)>;
+
+		/// `claim_exists` without a viewer, as of version 1.
+		#[changed_in(2)]
+		fn claim_exists(namespace: Vec<u8>, claim: Vec<u8>) -> bool;
+
+		/// Whether `claim` is stored within `namespace` and has not expired. A private claim is
+		/// missing unless `viewer` is one of its owners or viewers.
+		fn claim_exists(namespace: Vec<u8>, claim: Vec<u8>, viewer: Option<AccountId>) -> bool;
+
+		/// `claim_history` without a viewer, as of version 1.
+		#[changed_in(2)]
+		fn claim_history(namespace: Vec<u8>, claim: Vec<u8>) -> Vec<(AccountId, BlockNumber)>;
+
+		/// The owners of `claim` within `namespace` after its creation and each transfer, with
+		/// the block of every change, oldest first. Empty for a private claim unless `viewer` is
+		/// one of its owners or viewers.
+		fn claim_history(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<AccountId>,
+		) -> Vec<(AccountId, BlockNumber)>;
+
+		/// Up to `limit` unexpired public claims of any namespace starting with `prefix`, with
+		/// their first owner.
+		fn claims_with_prefix(prefix: Vec<u8>, limit: u32) -> Vec<(Vec<u8>, AccountId)>;
+
+		/// `claim_status` without a viewer, as of version 1.
+		#[changed_in(2)]
+		fn claim_status(namespace: Vec<u8>, claim: Vec<u8>) -> ClaimStatus<AccountId, BlockNumber>;
+
+		/// Whether `claim` within `namespace` is missing, active or expired, with its first
+		/// owner and remaining lifetime. A private claim is missing unless `viewer` is one of its
+		/// owners or viewers.
+		fn claim_status(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<AccountId>,
+		) -> ClaimStatus<AccountId, BlockNumber>;
+
+		/// Up to `limit` public claims of any namespace with their first owner and creation block,
+		/// starting after the cursor `start_key`, and the cursor of the next page if there is
+		/// one.
+		fn list_claims(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(Vec<u8>, AccountId, BlockNumber)>, Option<Vec<u8>>);
+
+		/// `simulate_create` with the index of the pallet error, as of version 2.
+		#[changed_in(3)]
+		fn simulate_create(
+			who: AccountId,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> SimulateResultBeforeVersion3<Balance>;
+
+		/// The deposit and fee of creating `claim` within `namespace` as `who`, and whether it
+		/// would succeed, without creating it.
+		fn simulate_create(
+			who: AccountId,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> SimulateResult<Balance>;
+
+		/// The `count` most recently created claims with their sequence number and creator,
+		/// newest first. Claims stay listed after they are revoked or transferred, until they
+		/// are among the `MaxRecentClaims` newest no more; private claims are left out.
+		fn recent_claims(count: u32) -> Vec<(u64, Vec<u8>, AccountId)>;
+
+		/// `ownership_challenge` without a viewer, as of version 1.
+		#[changed_in(2)]
+		fn ownership_challenge(namespace: Vec<u8>, claim: Vec<u8>) -> Option<[u8; 32]>;
+
+		/// A challenge the first owner of `claim` within `namespace` can sign to prove its
+		/// ownership off-chain, unless the claim is missing, expired, or private and `viewer` is
+		/// neither one of its owners nor one of its viewers. Changes every `ChallengeEpochLength`
+		/// blocks.
+		fn ownership_challenge(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<AccountId>,
+		) -> Option<[u8; 32]>;
+	}
+}
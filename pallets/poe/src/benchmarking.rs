@@ -0,0 +1,1294 @@
+//! Benchmarking setup for pallet-poe
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as PoeModule;
+use crate::{deposits::ClaimDeposits, patch::PatchOp};
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	traits::{Get, Hooks},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, One, Zero};
+use sp_std::{vec, vec::Vec};
+
+fn funded_caller<T: Config>() -> T::AccountId {
+	let caller: T::AccountId = whitelisted_caller();
+	T::Deposits::fund(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	AllowedCreators::<T>::insert(&caller, ());
+	caller
+}
+
+// 越过转移锁定期，使刚创建的存证可以转移
+fn pass_transfer_lock<T: Config>() {
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(now + T::TransferLockPeriod::get());
+}
+
+// 尚未登记的最长命名空间，在其中创建存证时需要为创建者登记
+fn fresh_namespace<T: Config>() -> Vec<u8> {
+	vec![1u8; T::MaxNamespaceLength::get() as usize]
+}
+
+benchmarks! {
+	create_claim {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		// 账户已拥有的存证数量，最多还能再创建一个
+		let c in 0 .. T::MaxClaimsPerAccount::get() - 1;
+		let caller = funded_caller::<T>();
+		AccountQuota::<T>::insert(&caller, T::MaxClaimsPerAccount::get());
+		let namespace = fresh_namespace::<T>();
+		let start = frame_system::Pallet::<T>::block_number();
+		for i in 0 .. c {
+			// 每个区块只创建一个存证，避开单个区块的创建数量限制
+			frame_system::Pallet::<T>::set_block_number(start + i.into());
+			PoeModule::<T>::create_claim(
+				RawOrigin::Signed(caller.clone()).into(),
+				namespace.clone(),
+				i.encode(),
+				None,
+				None,
+				None,
+				None,
+				None,
+			)?;
+		}
+		if c > 0 {
+			// 释放命名空间，让被测调用重新登记它
+			PoeModule::<T>::release_namespace(
+				RawOrigin::Signed(caller.clone()).into(),
+				namespace.clone(),
+			)?;
+		}
+		frame_system::Pallet::<T>::set_block_number(start + c.into());
+		// 与预先创建的存证不会重复，有存证时引用第一个作为父存证
+		let claim = vec![u8::MAX; l as usize];
+		let metadata = vec![0u8; T::MaxMetadataLength::get() as usize];
+		let parent = if c > 0 { Some(0u32.encode()) } else { None };
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		namespace.clone(),
+		claim.clone(),
+		Some(metadata),
+		None,
+		parent.clone(),
+		Some(vec![0u8; 32]),
+		None
+	)
+	verify {
+		let key = PoeModule::<T>::claim_key(&namespace, &claim).unwrap();
+		let info = Proofs::<T>::get(&key).unwrap();
+		assert_eq!(info.owners.into_inner(), vec![caller]);
+		assert_eq!(info.parent.map(|parent| parent.into_inner()), parent);
+	}
+
+	revoke_claim {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), Some(RevokeReason::NO_LONGER_NEEDED))
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(!Proofs::<T>::contains_key(&key));
+	}
+
+	transfer_claim {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		pass_transfer_lock::<T>();
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), dest.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(
+			Proofs::<T>::get(&key).map(|info| info.owners.into_inner()),
+			Some(vec![dest.clone()])
+		);
+		assert!(ClaimsByOwner::<T>::get(&dest).contains(&key));
+	}
+
+	create_claims {
+		let c in 1 .. T::MaxBatchSize::get();
+		let caller = funded_caller::<T>();
+		let claims: Vec<Vec<u8>> =
+			(0 .. c).map(|i| vec![i as u8; T::MaxClaimLength::get() as usize]).collect();
+	}: _(RawOrigin::Signed(caller.clone()), fresh_namespace::<T>(), claims)
+	verify {
+		assert_eq!(ClaimsByOwner::<T>::get(&caller).len() as u32, c);
+	}
+
+	revoke_claims {
+		let c in 1 .. T::MaxBatchSize::get();
+		let caller = funded_caller::<T>();
+		let claims: Vec<Vec<u8>> =
+			(0 .. c).map(|i| vec![i as u8; T::MaxClaimLength::get() as usize]).collect();
+		PoeModule::<T>::create_claims(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claims.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), vec![], claims)
+	verify {
+		assert!(ClaimsByOwner::<T>::get(&caller).is_empty());
+	}
+
+	propose_transfer {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), dest.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(PendingTransfers::<T>::get(&key), Some(dest));
+	}
+
+	accept_transfer {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		PoeModule::<T>::propose_transfer(
+			RawOrigin::Signed(caller).into(),
+			vec![],
+			claim.clone(),
+			dest.clone(),
+		)?;
+		pass_transfer_lock::<T>();
+	}: _(RawOrigin::Signed(dest.clone()), vec![], claim.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(Proofs::<T>::get(&key).map(|info| info.owners.into_inner()), Some(vec![dest]));
+	}
+
+	cancel_transfer {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		PoeModule::<T>::propose_transfer(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			dest,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(!PendingTransfers::<T>::contains_key(&key));
+	}
+
+	force_revoke {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Root, vec![], claim.clone(), Some(RevokeReason::LEGAL_ORDER))
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(!Proofs::<T>::contains_key(&key));
+	}
+
+	create_hashed_claim {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		let namespace = fresh_namespace::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), namespace.clone(), claim.clone())
+	verify {
+		let owner = PoeModule::<T>::verify_claim(namespace, claim).map(|(owner, _)| owner);
+		assert_eq!(owner, Some(caller));
+	}
+
+	revoke_hashed_claim {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_hashed_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone())
+	verify {
+		assert!(PoeModule::<T>::verify_claim(vec![], claim).is_none());
+	}
+
+	approve {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let operator: T::AccountId = account("operator", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), operator.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(ClaimApprovals::<T>::get(&key), Some(operator));
+	}
+
+	cancel_approval {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let operator: T::AccountId = account("operator", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		PoeModule::<T>::approve(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			operator,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(!ClaimApprovals::<T>::contains_key(&key));
+	}
+
+	renew_claim {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		let now = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		frame_system::Pallet::<T>::set_block_number(now);
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		let expiry = now + T::DefaultClaimExpiry::get();
+		assert_eq!(Proofs::<T>::get(&key).and_then(|info| info.expiry), Some(expiry));
+	}
+
+	renounce_claim {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(Tombstones::<T>::contains_key(&key));
+	}
+
+	set_metadata {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		let metadata = vec![0u8; T::MaxMetadataLength::get() as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), metadata.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(Proofs::<T>::get(&key).map(|info| info.metadata.into_inner()), Some(metadata));
+	}
+
+	set_transferable {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), false)
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(Proofs::<T>::get(&key).map(|info| info.transferable), Some(false));
+	}
+
+	add_owner {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		// 留出最后一个位置，让新所有者的查重覆盖所有已有的所有者
+		for i in 1 .. T::MaxOwners::get().saturating_sub(1) {
+			PoeModule::<T>::add_owner(
+				RawOrigin::Signed(caller.clone()).into(),
+				vec![],
+				claim.clone(),
+				account("owner", i, 0),
+			)?;
+		}
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), new_owner.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(Proofs::<T>::get(&key).unwrap().is_owner(&new_owner));
+	}
+
+	remove_owner {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		for i in 1 .. T::MaxOwners::get() {
+			PoeModule::<T>::add_owner(
+				RawOrigin::Signed(caller.clone()).into(),
+				vec![],
+				claim.clone(),
+				account("owner", i, 0),
+			)?;
+		}
+		let owner: T::AccountId = account("owner", 1, 0);
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), owner.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(!Proofs::<T>::get(&key).unwrap().is_owner(&owner));
+	}
+
+	add_creator {
+		let who: T::AccountId = account("creator", 0, 0);
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(AllowedCreators::<T>::contains_key(&who));
+	}
+
+	remove_creator {
+		let who: T::AccountId = account("creator", 0, 0);
+		AllowedCreators::<T>::insert(&who, ());
+	}: _(RawOrigin::Root, who.clone())
+	verify {
+		assert!(!AllowedCreators::<T>::contains_key(&who));
+	}
+
+	add_revoke_delegate {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		// 留出最后一个位置，让新代理人的查重覆盖所有已有的代理人
+		for i in 0 .. T::MaxDelegates::get().saturating_sub(1) {
+			PoeModule::<T>::add_revoke_delegate(
+				RawOrigin::Signed(caller.clone()).into(),
+				vec![],
+				claim.clone(),
+				account("delegate", i, 0),
+			)?;
+		}
+		let delegate: T::AccountId = account("new_delegate", 0, 0);
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), delegate.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(RevokeDelegates::<T>::get(&key).contains(&delegate));
+	}
+
+	remove_revoke_delegate {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		for i in 0 .. T::MaxDelegates::get() {
+			PoeModule::<T>::add_revoke_delegate(
+				RawOrigin::Signed(caller.clone()).into(),
+				vec![],
+				claim.clone(),
+				account("delegate", i, 0),
+			)?;
+		}
+		// 移除最后一个代理人，查找时需要遍历整个列表
+		let delegate: T::AccountId = account("delegate", T::MaxDelegates::get() - 1, 0);
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), delegate.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(!RevokeDelegates::<T>::get(&key).contains(&delegate));
+	}
+
+	revoke_my_expired {
+		let r in 0 .. T::MaxClaimsPerAccount::get();
+		let caller = funded_caller::<T>();
+		AccountQuota::<T>::insert(&caller, T::MaxClaimsPerAccount::get());
+		let start = frame_system::Pallet::<T>::block_number();
+		for i in 0 .. r {
+			// 每个区块只创建一个存证，避开单个区块的创建数量限制
+			frame_system::Pallet::<T>::set_block_number(start + i.into());
+			PoeModule::<T>::create_claim(
+				RawOrigin::Signed(caller.clone()).into(),
+				vec![],
+				i.encode(),
+				None,
+				None,
+				None,
+				None,
+				None,
+			)?;
+		}
+		let expired = start + r.into() + T::DefaultClaimExpiry::get();
+		frame_system::Pallet::<T>::set_block_number(expired);
+	}: _(RawOrigin::Signed(caller.clone()), r)
+	verify {
+		assert!(ClaimsByOwner::<T>::get(&caller).is_empty());
+	}
+
+	transfer_claim_with_metadata {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let m in 0 .. T::MaxMetadataLength::get();
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		pass_transfer_lock::<T>();
+		let metadata = vec![1u8; m as usize];
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), dest.clone(), metadata.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		let info = Proofs::<T>::get(&key).unwrap();
+		assert_eq!(info.owners.into_inner(), vec![dest]);
+		assert_eq!(info.metadata.into_inner(), metadata);
+	}
+
+	set_account_quota {
+		let who: T::AccountId = account("partner", 0, 0);
+		let quota = T::MaxClaimsPerAccount::get();
+	}: _(RawOrigin::Root, who.clone(), Some(quota))
+	verify {
+		assert_eq!(AccountQuota::<T>::get(&who), Some(quota));
+	}
+
+	revoke_claim_by_hash {
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; T::MaxClaimLength::get() as usize];
+		PoeModule::<T>::create_hashed_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+		)?;
+		let hash = PoeModule::<T>::claim_hash(&claim);
+	}: _(RawOrigin::Signed(caller), vec![], hash)
+	verify {
+		assert!(PoeModule::<T>::verify_claim(vec![], claim).is_none());
+	}
+
+	create_claims_best_effort {
+		let c in 1 .. T::MaxBatchSize::get();
+		let caller = funded_caller::<T>();
+		// 所有存证都创建成功时写入最多
+		let claims: Vec<Vec<u8>> =
+			(0 .. c).map(|i| vec![i as u8; T::MaxClaimLength::get() as usize]).collect();
+	}: _(RawOrigin::Signed(caller.clone()), vec![], claims)
+	verify {
+		assert_eq!(ClaimsByOwner::<T>::get(&caller).len() as u32, c);
+	}
+
+	set_frozen {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Root, vec![], claim.clone(), true)
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(FrozenClaims::<T>::contains_key(&key));
+	}
+
+	create_claim_if_absent {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		let namespace = fresh_namespace::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), namespace.clone(), claim.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&namespace, &claim).unwrap();
+		assert_eq!(Proofs::<T>::get(&key).map(|info| info.owners.into_inner()), Some(vec![caller]));
+	}
+
+	supersede_claim {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let old_claim = vec![0u8; T::MaxClaimLength::get() as usize];
+		let new_claim = vec![1u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			old_claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], old_claim.clone(), new_claim.clone())
+	verify {
+		let old_key = PoeModule::<T>::claim_key(&[], &old_claim).unwrap();
+		let new_key = PoeModule::<T>::claim_key(&[], &new_claim).unwrap();
+		assert!(Proofs::<T>::contains_key(&new_key));
+		assert_eq!(Superseded::<T>::get(&old_key), Some(new_key.1));
+	}
+
+	patch_claim {
+		let l in (T::MinClaimLength::get().max(2)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		// 保留除最后一个字节外的全部内容，再追加一个新字节
+		let patch = patch::encode(&[
+			PatchOp::Copy { offset: 0, len: l - 1 },
+			PatchOp::Insert(vec![1]),
+		]);
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), patch)
+	verify {
+		let mut patched = claim.clone();
+		*patched.last_mut().unwrap() = 1;
+		let old_key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		let new_key = PoeModule::<T>::claim_key(&[], &patched).unwrap();
+		assert!(Proofs::<T>::contains_key(&new_key));
+		assert_eq!(Superseded::<T>::get(&old_key), Some(new_key.1));
+	}
+
+	allow_transfer_to {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		// 留出最后一个位置，让新账户的查重覆盖整个白名单
+		for i in 0 .. T::MaxAllowed::get().saturating_sub(1) {
+			PoeModule::<T>::allow_transfer_to(
+				RawOrigin::Signed(caller.clone()).into(),
+				vec![],
+				claim.clone(),
+				account("allowed", i, 0),
+			)?;
+		}
+		let who: T::AccountId = account("new_allowed", 0, 0);
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), who.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(TransferAllowList::<T>::get(&key).contains(&who));
+	}
+
+	disallow_transfer_to {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		for i in 0 .. T::MaxAllowed::get() {
+			PoeModule::<T>::allow_transfer_to(
+				RawOrigin::Signed(caller.clone()).into(),
+				vec![],
+				claim.clone(),
+				account("allowed", i, 0),
+			)?;
+		}
+		// 移除最后一个账户，查找时需要遍历整个白名单
+		let who: T::AccountId = account("allowed", T::MaxAllowed::get() - 1, 0);
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), who.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(!TransferAllowList::<T>::get(&key).contains(&who));
+	}
+
+	claim_transfer {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		for i in 0 .. T::MaxAllowed::get() {
+			PoeModule::<T>::allow_transfer_to(
+				RawOrigin::Signed(caller.clone()).into(),
+				vec![],
+				claim.clone(),
+				account("allowed", i, 0),
+			)?;
+		}
+		pass_transfer_lock::<T>();
+		// 白名单中的最后一个账户领取存证，查找时需要遍历整个白名单
+		let dest: T::AccountId = account("allowed", T::MaxAllowed::get() - 1, 0);
+	}: _(RawOrigin::Signed(dest.clone()), vec![], claim.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(Proofs::<T>::get(&key).map(|info| info.owners.into_inner()), Some(vec![dest]));
+		assert!(!TransferAllowList::<T>::contains_key(&key));
+	}
+
+	force_revoke_and_slash {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Root, vec![], claim.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(!Proofs::<T>::contains_key(&key));
+		assert!(T::Deposits::reserved_balance(&caller).is_zero());
+	}
+
+	transfer_all_claims {
+		let c in 0 .. T::MaxClaimsPerAccount::get();
+		let caller = funded_caller::<T>();
+		let dest: T::AccountId = account("dest", 0, 0);
+		T::Deposits::fund(&dest, BalanceOf::<T>::max_value() / 2u32.into());
+		AccountQuota::<T>::insert(&caller, T::MaxClaimsPerAccount::get());
+		AccountQuota::<T>::insert(&dest, T::MaxClaimsPerAccount::get());
+		let start = frame_system::Pallet::<T>::block_number();
+		for i in 0 .. c {
+			// 每个区块只创建一个存证，避开单个区块的创建数量限制
+			frame_system::Pallet::<T>::set_block_number(start + i.into());
+			PoeModule::<T>::create_claim(
+				RawOrigin::Signed(caller.clone()).into(),
+				vec![],
+				i.encode(),
+				None,
+				None,
+				None,
+				None,
+				None,
+			)?;
+		}
+		pass_transfer_lock::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), dest.clone(), c)
+	verify {
+		assert!(ClaimsByOwner::<T>::get(&caller).is_empty());
+		assert_eq!(ClaimsByOwner::<T>::get(&dest).len() as u32, c);
+	}
+
+	register_namespace {
+		let caller = funded_caller::<T>();
+		let namespace = fresh_namespace::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), namespace.clone())
+	verify {
+		let namespace = PoeModule::<T>::bounded_namespace(&namespace).unwrap();
+		assert_eq!(NamespaceOwners::<T>::get(&namespace), Some(caller));
+	}
+
+	release_namespace {
+		let caller = funded_caller::<T>();
+		let namespace = fresh_namespace::<T>();
+		PoeModule::<T>::register_namespace(
+			RawOrigin::Signed(caller.clone()).into(),
+			namespace.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), namespace.clone())
+	verify {
+		assert_eq!(NamespacesOwned::<T>::get(&caller), 0);
+	}
+
+	emit_proof_count {
+		let caller = funded_caller::<T>();
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		assert_eq!(ProofCount::<T>::get(), 0);
+	}
+
+	set_content_type {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		let content_type = vec![0u8; 32];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), Some(content_type.clone()))
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(
+			Proofs::<T>::get(&key).and_then(|info| info.content_type).map(|tag| tag.into_inner()),
+			Some(content_type)
+		);
+	}
+
+	set_label {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		let label = vec![0u8; 64];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), label.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(Labels::<T>::get(&key).map(|label| label.into_inner()), Some(label));
+	}
+
+	escrow_claim {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		pass_transfer_lock::<T>();
+		let release_block = frame_system::Pallet::<T>::block_number() + One::one();
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), beneficiary.clone(), release_block)
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(Escrows::<T>::get(&key), Some((beneficiary, release_block)));
+	}
+
+	release_escrow {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		pass_transfer_lock::<T>();
+		let release_block = frame_system::Pallet::<T>::block_number() + One::one();
+		PoeModule::<T>::escrow_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			beneficiary.clone(),
+			release_block,
+		)?;
+		frame_system::Pallet::<T>::set_block_number(release_block);
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(
+			Proofs::<T>::get(&key).map(|info| info.owners.into_inner()),
+			Some(vec![beneficiary.clone()])
+		);
+		assert!(!Escrows::<T>::contains_key(&key));
+	}
+
+	cancel_escrow {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		pass_transfer_lock::<T>();
+		let release_block = frame_system::Pallet::<T>::block_number() + One::one();
+		PoeModule::<T>::escrow_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			beneficiary,
+			release_block,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone())
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert!(!Escrows::<T>::contains_key(&key));
+	}
+
+	offer_swap {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let counterparty: T::AccountId = account("counterparty", 0, 0);
+		T::Deposits::fund(&counterparty, BalanceOf::<T>::max_value() / 2u32.into());
+		AllowedCreators::<T>::insert(&counterparty, ());
+		let mine = vec![0u8; l as usize];
+		let theirs = vec![1u8; l as usize];
+		for (who, claim) in [(&caller, &mine), (&counterparty, &theirs)] {
+			PoeModule::<T>::create_claim(
+				RawOrigin::Signed(who.clone()).into(),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None,
+			)?;
+		}
+		pass_transfer_lock::<T>();
+	}: swap_claims(
+		RawOrigin::Signed(caller.clone()),
+		vec![],
+		mine.clone(),
+		theirs.clone(),
+		counterparty.clone()
+	)
+	verify {
+		let my_key = PoeModule::<T>::claim_key(&[], &mine).unwrap();
+		let their_key = PoeModule::<T>::claim_key(&[], &theirs).unwrap();
+		assert_eq!(
+			SwapOffers::<T>::get((my_key, their_key)),
+			Some((caller, counterparty, T::SwapOfferDeposit::get()))
+		);
+	}
+
+	swap_claims {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let counterparty: T::AccountId = account("counterparty", 0, 0);
+		T::Deposits::fund(&counterparty, BalanceOf::<T>::max_value() / 2u32.into());
+		AllowedCreators::<T>::insert(&counterparty, ());
+		let mine = vec![0u8; l as usize];
+		let theirs = vec![1u8; l as usize];
+		for (who, claim) in [(&caller, &mine), (&counterparty, &theirs)] {
+			PoeModule::<T>::create_claim(
+				RawOrigin::Signed(who.clone()).into(),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None,
+			)?;
+		}
+		pass_transfer_lock::<T>();
+		PoeModule::<T>::swap_claims(
+			RawOrigin::Signed(counterparty.clone()).into(),
+			vec![],
+			theirs.clone(),
+			mine.clone(),
+			caller.clone(),
+		)?;
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		vec![],
+		mine.clone(),
+		theirs.clone(),
+		counterparty.clone()
+	)
+	verify {
+		let my_key = PoeModule::<T>::claim_key(&[], &mine).unwrap();
+		let their_key = PoeModule::<T>::claim_key(&[], &theirs).unwrap();
+		assert_eq!(
+			Proofs::<T>::get(&my_key).map(|info| info.owners.into_inner()),
+			Some(vec![counterparty])
+		);
+		assert_eq!(
+			Proofs::<T>::get(&their_key).map(|info| info.owners.into_inner()),
+			Some(vec![caller])
+		);
+	}
+
+	transfer_many {
+		let c in 1 .. T::MaxBatchSize::get();
+		let caller = funded_caller::<T>();
+		let claims: Vec<Vec<u8>> =
+			(0 .. c).map(|i| vec![i as u8; T::MaxClaimLength::get() as usize]).collect();
+		PoeModule::<T>::create_claims(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claims.clone(),
+		)?;
+		pass_transfer_lock::<T>();
+		// 每个存证转移给不同的接收方
+		let transfers: Vec<(Vec<u8>, T::AccountId)> = claims
+			.into_iter()
+			.enumerate()
+			.map(|(i, claim)| {
+				let dest: T::AccountId = account("dest", i as u32, 0);
+				T::Deposits::fund(&dest, BalanceOf::<T>::max_value() / 2u32.into());
+				(claim, dest)
+			})
+			.collect();
+	}: _(RawOrigin::Signed(caller.clone()), vec![], transfers)
+	verify {
+		assert!(ClaimsByOwner::<T>::get(&caller).is_empty());
+	}
+
+	set_private {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], claim.clone(), true)
+	verify {
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		assert_eq!(Proofs::<T>::get(&key).map(|info| info.private), Some(true));
+	}
+
+	add_viewer {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		// 最坏情况下查看者列表只差一个就满了
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		let viewers: Vec<T::AccountId> =
+			(1 .. T::MaxViewers::get()).map(|i| account("viewer", i, 0)).collect();
+		Viewers::<T>::insert(&key, BoundedVec::truncate_from(viewers));
+		let viewer: T::AccountId = account("viewer", 0, 0);
+	}: _(RawOrigin::Signed(caller), vec![], claim, viewer.clone())
+	verify {
+		assert!(Viewers::<T>::get(&key).contains(&viewer));
+	}
+
+	remove_viewer {
+		let l in (T::MinClaimLength::get().max(1)) .. T::MaxClaimLength::get();
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; l as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		// 最坏情况下要移除的是满列表中的最后一个查看者
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		let viewers: Vec<T::AccountId> =
+			(0 .. T::MaxViewers::get()).map(|i| account("viewer", i, 0)).collect();
+		let viewer = viewers.last().cloned().unwrap();
+		Viewers::<T>::insert(&key, BoundedVec::truncate_from(viewers));
+	}: _(RawOrigin::Signed(caller), vec![], claim, viewer.clone())
+	verify {
+		assert!(!Viewers::<T>::get(&key).contains(&viewer));
+	}
+
+	set_accepts_claims {
+		let caller = funded_caller::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), false)
+	verify {
+		assert_eq!(AcceptsClaims::<T>::get(&caller), Some(false));
+	}
+
+	cancel_swap_offer {
+		let caller = funded_caller::<T>();
+		let counterparty: T::AccountId = account("counterparty", 0, 0);
+		let mine = vec![0u8; T::MaxClaimLength::get() as usize];
+		let theirs = vec![1u8; T::MaxClaimLength::get() as usize];
+		PoeModule::<T>::swap_claims(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![],
+			mine.clone(),
+			theirs.clone(),
+			counterparty,
+		)?;
+	}: _(RawOrigin::Signed(caller), vec![], mine.clone(), theirs.clone())
+	verify {
+		let my_key = PoeModule::<T>::claim_key(&[], &mine).unwrap();
+		let their_key = PoeModule::<T>::claim_key(&[], &theirs).unwrap();
+		assert!(!SwapOffers::<T>::contains_key((my_key, their_key)));
+	}
+
+	set_paused {
+	}: _(RawOrigin::Root, true)
+	verify {
+		assert!(Paused::<T>::get());
+	}
+
+	report_expiring_soon {
+		let caller = funded_caller::<T>();
+		let claim = vec![0u8; T::MaxClaimLength::get() as usize];
+		PoeModule::<T>::create_claim(
+			RawOrigin::Signed(caller).into(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+		let key = PoeModule::<T>::claim_key(&[], &claim).unwrap();
+		let expiry = frame_system::Pallet::<T>::block_number() + T::DefaultClaimExpiry::get();
+		frame_system::Pallet::<T>::set_block_number(expiry - 1u32.into());
+	}: _(RawOrigin::None, key, expiry)
+	verify {
+		let event = Event::<T>::ClaimExpiringSoon(vec![], claim, expiry);
+		let event: <T as Config>::RuntimeEvent = event.into();
+		frame_system::Pallet::<T>::assert_last_event(event.into());
+	}
+
+	on_initialize {
+		let r in 0 .. T::MaxExpiredPerBlock::get();
+		for i in 0 .. r {
+			let owner: T::AccountId = account("owner", i, 0);
+			T::Deposits::fund(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+			AllowedCreators::<T>::insert(&owner, ());
+			PoeModule::<T>::create_claim(
+				RawOrigin::Signed(owner).into(),
+				vec![],
+				i.encode(),
+				None,
+				None,
+				None,
+				None,
+				None,
+			)?;
+		}
+		let expiry = frame_system::Pallet::<T>::block_number() + T::DefaultClaimExpiry::get();
+	}: {
+		PoeModule::<T>::on_initialize(expiry);
+	}
+	verify {
+		assert_eq!(Proofs::<T>::iter().count(), 0);
+	}
+
+	on_idle {
+		let t in 0 .. 64;
+		let buried = frame_system::Pallet::<T>::block_number();
+		for i in 0 .. t {
+			let key = PoeModule::<T>::claim_key(&[], &i.encode()).unwrap();
+			PoeModule::<T>::bury(&key, buried);
+		}
+		let now = buried + T::TombstoneRetention::get();
+	}: {
+		PoeModule::<T>::on_idle(now, T::WeightInfo::on_idle(t));
+	}
+	verify {
+		assert_eq!(Tombstones::<T>::iter().count(), 0);
+	}
+
+	impl_benchmark_test_suite!(PoeModule, crate::mock::new_test_ext(), crate::mock::Test);
+}
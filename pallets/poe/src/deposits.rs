@@ -0,0 +1,199 @@
+//! How claim deposits are reserved and creation fees are paid.
+//!
+//! The pallet only talks to [`ClaimDeposits`], so runtimes without a currency can use
+//! [`NoDeposits`] and leave claims free. [`CurrencyDeposits`], which collects them in a
+//! `ReservableCurrency`, and [`HoldDeposits`], which holds them with `fungible::MutateHold`, are
+//! only built with the `claim-deposits` feature.
+
+use codec::{FullCodec, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize, Zero},
+	DispatchResult,
+};
+use sp_std::{fmt::Debug, marker::PhantomData};
+
+/// Reserves, refunds and slashes claim deposits and pays creation fees.
+pub trait ClaimDeposits<AccountId> {
+	/// The balance deposits and fees are measured in.
+	type Balance: AtLeast32BitUnsigned
+		+ FullCodec
+		+ Copy
+		+ MaybeSerializeDeserialize
+		+ Debug
+		+ Default
+		+ TypeInfo
+		+ MaxEncodedLen;
+
+	/// Reserve `amount` from `who`, failing if it cannot be reserved.
+	fn reserve(who: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Refund up to `amount` of the deposits reserved from `who`.
+	fn unreserve(who: &AccountId, amount: Self::Balance);
+
+	/// Slash up to `amount` of the deposits reserved from `who`, returning how much was slashed.
+	fn slash_reserved(who: &AccountId, amount: Self::Balance) -> Self::Balance;
+
+	/// Pay the fee `amount` from `who` to `dest`, failing if `who` cannot pay it and stay alive.
+	fn pay_fee(who: &AccountId, dest: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// The amount currently reserved from `who`.
+	fn reserved_balance(who: &AccountId) -> Self::Balance;
+
+	/// Make `amount` available to `who`, so that benchmarks can pay deposits and fees.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn fund(who: &AccountId, amount: Self::Balance);
+}
+
+/// Keeps claims free: nothing is reserved, slashed or charged.
+///
+/// Set `ClaimBaseDeposit`, `ClaimByteDeposit` and `ClaimCreationFee` to zero along with it so
+/// that claims do not record deposits that were never taken.
+pub struct NoDeposits<Balance>(PhantomData<Balance>);
+
+impl<AccountId, Balance> ClaimDeposits<AccountId> for NoDeposits<Balance>
+where
+	Balance: AtLeast32BitUnsigned
+		+ FullCodec
+		+ Copy
+		+ MaybeSerializeDeserialize
+		+ Debug
+		+ Default
+		+ TypeInfo
+		+ MaxEncodedLen,
+{
+	type Balance = Balance;
+
+	fn reserve(_who: &AccountId, _amount: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn unreserve(_who: &AccountId, _amount: Balance) {}
+
+	fn slash_reserved(_who: &AccountId, _amount: Balance) -> Balance {
+		Zero::zero()
+	}
+
+	fn pay_fee(_who: &AccountId, _dest: &AccountId, _amount: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn reserved_balance(_who: &AccountId) -> Balance {
+		Zero::zero()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn fund(_who: &AccountId, _amount: Balance) {}
+}
+
+#[cfg(feature = "claim-deposits")]
+pub use currency::CurrencyDeposits;
+
+#[cfg(feature = "claim-deposits")]
+mod currency {
+	use super::ClaimDeposits;
+	use frame_support::traits::{
+		Currency, ExistenceRequirement, Imbalance, OnUnbalanced, ReservableCurrency,
+	};
+	use sp_runtime::DispatchResult;
+	use sp_std::marker::PhantomData;
+
+	/// Reserves deposits and pays fees in the currency `C`, handing slashed deposits to `Slash`.
+	/// `()` as `Slash` burns them.
+	pub struct CurrencyDeposits<C, Slash>(PhantomData<(C, Slash)>);
+
+	impl<AccountId, C, Slash> ClaimDeposits<AccountId> for CurrencyDeposits<C, Slash>
+	where
+		C: ReservableCurrency<AccountId>,
+		Slash: OnUnbalanced<C::NegativeImbalance>,
+	{
+		type Balance = C::Balance;
+
+		fn reserve(who: &AccountId, amount: C::Balance) -> DispatchResult {
+			C::reserve(who, amount)
+		}
+
+		fn unreserve(who: &AccountId, amount: C::Balance) {
+			C::unreserve(who, amount);
+		}
+
+		fn slash_reserved(who: &AccountId, amount: C::Balance) -> C::Balance {
+			let (slashed, _) = C::slash_reserved(who, amount);
+			let amount = slashed.peek();
+			Slash::on_unbalanced(slashed);
+			amount
+		}
+
+		fn pay_fee(who: &AccountId, dest: &AccountId, amount: C::Balance) -> DispatchResult {
+			C::transfer(who, dest, amount, ExistenceRequirement::KeepAlive)
+		}
+
+		fn reserved_balance(who: &AccountId) -> C::Balance {
+			C::reserved_balance(who)
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn fund(who: &AccountId, amount: C::Balance) {
+			C::make_free_balance_be(who, amount);
+		}
+	}
+}
+
+#[cfg(feature = "claim-deposits")]
+pub use hold::HoldDeposits;
+
+#[cfg(feature = "claim-deposits")]
+mod hold {
+	use super::ClaimDeposits;
+	use frame_support::traits::{
+		fungible::{BalancedHold, CreditOf, InspectHold, Mutate, MutateHold, Transfer},
+		Imbalance, OnUnbalanced,
+	};
+	use sp_runtime::{traits::MaybeSerializeDeserialize, DispatchResult};
+	use sp_std::marker::PhantomData;
+
+	/// Holds deposits and pays fees in the fungible `F`, handing slashed deposits to `Slash`.
+	/// `()` as `Slash` burns them.
+	///
+	/// Holds in this FRAME release carry no reason, so the claim deposits of an account are
+	/// counted together with anything else `F` holds from it.
+	pub struct HoldDeposits<F, Slash>(PhantomData<(F, Slash)>);
+
+	impl<AccountId, F, Slash> ClaimDeposits<AccountId> for HoldDeposits<F, Slash>
+	where
+		F: BalancedHold<AccountId> + Mutate<AccountId>,
+		F::Balance: MaybeSerializeDeserialize,
+		Slash: OnUnbalanced<CreditOf<AccountId, F>>,
+	{
+		type Balance = F::Balance;
+
+		fn reserve(who: &AccountId, amount: F::Balance) -> DispatchResult {
+			F::hold(who, amount)
+		}
+
+		fn unreserve(who: &AccountId, amount: F::Balance) {
+			// 尽力释放，实际持有的少于 amount 时释放全部
+			let _ = F::release(who, amount, true);
+		}
+
+		fn slash_reserved(who: &AccountId, amount: F::Balance) -> F::Balance {
+			let (slashed, _) = F::slash_held(who, amount);
+			let amount = slashed.peek();
+			Slash::on_unbalanced(slashed);
+			amount
+		}
+
+		fn pay_fee(who: &AccountId, dest: &AccountId, amount: F::Balance) -> DispatchResult {
+			<F as Transfer<AccountId>>::transfer(who, dest, amount, true).map(|_| ())
+		}
+
+		fn reserved_balance(who: &AccountId) -> F::Balance {
+			F::balance_on_hold(who)
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn fund(who: &AccountId, amount: F::Balance) {
+			let _ = F::mint_into(who, amount);
+		}
+	}
+}
@@ -2,42 +2,672 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod deposits;
+pub mod migrations;
+pub mod patch;
+pub mod signed_extensions;
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Notified whenever a claim changes owner, so that other pallets can react to transfers.
+pub trait OnClaimTransferHandler<AccountId> {
+	/// Called after `claim` was transferred from `from` to `to`.
+	fn on_transfer(claim: &[u8], from: &AccountId, to: &AccountId);
+}
+
+impl<AccountId> OnClaimTransferHandler<AccountId> for () {
+	fn on_transfer(_claim: &[u8], _from: &AccountId, _to: &AccountId) {}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	// 常用的宏
-	use frame_support::{pallet_prelude::*, traits::Hooks};
+	use frame_support::{
+		pallet_prelude::*,
+		storage::{with_transaction, TransactionOutcome},
+		traits::{ConstU32, Hooks, OnKilledAccount, UnixTime},
+		CloneNoBound, EqNoBound, PartialEqNoBound, ReversibleStorageHasher, RuntimeDebugNoBound,
+		StorageHasher,
+	};
 	// 常用工具方法
 	use frame_system::{
-		ensure_signed,
+		ensure_none, ensure_root, ensure_signed,
+		offchain::{SendTransactionTypes, SubmitTransaction},
 		pallet_prelude::{BlockNumberFor, *},
 	};
 	// 引入数据类型
+	use sp_runtime::{
+		offchain::storage::{StorageRetrievalError, StorageValueRef},
+		traits::{
+			BlakeTwo256, Hash, One, SaturatedConversion, Saturating, UniqueSaturatedInto, Zero,
+		},
+		ModuleError, Percent,
+	};
 	use sp_std::prelude::*;
 
+	use crate::{
+		deposits::ClaimDeposits,
+		patch::{self, PatchError},
+		weights::WeightInfo,
+		OnClaimTransferHandler,
+	};
+	pub use pallet_poe_runtime_api::{ClaimStatus, SimulateResult};
+
 	// 通过继承拥有了 frame_system::Config 里定义的数据类型
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
 		// pallet::constant 用于声明这是个链上的常量
 		#[pallet::constant]
 		/// The maximum length of claim that can be added.
 		type MaxClaimLength: Get<u32>;
 
+		/// The type claims are stored as. The claim bytes passed to the calls are converted into
+		/// it; use `BoundedVec<u8, Self::MaxClaimLength>` to store the bytes as they are.
+		type ClaimData: Parameter + MaxEncodedLen + TryFrom<Vec<u8>> + Into<Vec<u8>>;
+
+		/// The hasher of the `Proofs` storage keys. `Blake2_128Concat` is the safe choice;
+		/// `Twox64Concat` is cheaper when callers cannot pick the claims, e.g. genesis-only
+		/// chains, and `Identity` suits claims that are hashes already.
+		///
+		/// The storage migrations up to version 6 expect `Blake2_128Concat`. Use
+		/// [`crate::migrations::MigrateProofsHasher`] to move existing claims when changing it.
+		type ProofsHasher: StorageHasher + ReversibleStorageHasher;
+
+		#[pallet::constant]
+		/// The maximum length of the namespace a claim is stored under.
+		type MaxNamespaceLength: Get<u32>;
+
+		#[pallet::constant]
+		/// The minimum length of claim that can be added.
+		type MinClaimLength: Get<u32>;
+
+		#[pallet::constant]
+		/// How many blocks a newly created claim stays valid for. Must be at least one, or new
+		/// claims would expire in the block that already purged expired claims.
+		type DefaultClaimExpiry: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		/// The maximum number of claims that may share the same expiry block.
+		type MaxClaimsPerExpiryBlock: Get<u32>;
+
+		#[pallet::constant]
+		/// The maximum length of the metadata attached to a claim.
+		type MaxMetadataLength: Get<u32>;
+
+		#[pallet::constant]
+		/// The maximum number of claims a single account can own, whatever its quota.
+		type MaxClaimsPerAccount: Get<u32>;
+
+		#[pallet::constant]
+		/// The maximum number of claims handled by a single batch call.
+		type MaxBatchSize: Get<u32>;
+
+		#[pallet::constant]
+		/// The maximum number of expired claims purged in a single block.
+		type MaxExpiredPerBlock: Get<u32>;
+
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// How claim deposits are reserved and creation fees paid. `CurrencyDeposits` and
+		/// `HoldDeposits`, built with the `claim-deposits` feature, take them from a
+		/// `ReservableCurrency` or hold them with `fungible::MutateHold`;
+		/// [`crate::deposits::NoDeposits`] makes claims free for runtimes without a currency.
+		type Deposits: ClaimDeposits<Self::AccountId>;
+
+		#[pallet::constant]
+		/// The base deposit reserved for every claim.
+		type ClaimBaseDeposit: Get<BalanceOf<Self>>;
+
+		#[pallet::constant]
+		/// The additional deposit reserved per byte of claim.
+		type ClaimByteDeposit: Get<BalanceOf<Self>>;
+
+		/// The origin allowed to pause and unpause the pallet.
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		#[pallet::constant]
+		/// The maximum number of claims, plain and hash-only, stored at any time.
+		type MaxTotalProofs: Get<u32>;
+
+		#[pallet::constant]
+		/// The maximum number of claims, plain and hash-only, one account may create per block.
+		type MaxClaimsPerBlockPerAccount: Get<u32>;
+
+		#[pallet::constant]
+		/// How many blocks ahead of its expiry the offchain worker reports a claim.
+		type ExpiryWarningWindow: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		/// The priority of the unsigned transactions that report soon-to-expire claims.
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		#[pallet::constant]
+		/// The maximum number of joint owners of a claim. Must be at least 1.
+		type MaxOwners: Get<u32>;
+
+		/// The origin allowed to add and remove accounts in `AllowedCreators`.
+		type CreatorAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		#[pallet::constant]
+		/// Whether only accounts in `AllowedCreators` may create claims.
+		type EnforceCreatorWhitelist: Get<bool>;
+
+		#[pallet::constant]
+		/// The maximum number of ownership changes kept in `ClaimHistory` per claim. The oldest
+		/// entry is dropped once a claim has this many.
+		type MaxHistoryLength: Get<u32>;
+
+		/// The account receiving the claim creation fees.
+		type FeeDestination: Get<Self::AccountId>;
+
+		#[pallet::constant]
+		/// The non-refundable fee paid to `FeeDestination` for every claim created, on top of
+		/// the deposit. A fee below the existential deposit cannot be paid until
+		/// `FeeDestination` exists.
+		type ClaimCreationFee: Get<BalanceOf<Self>>;
+
+		#[pallet::constant]
+		/// How many blocks must pass after a claim is created before it can be transferred.
+		type TransferLockPeriod: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		/// The maximum number of revoke delegates of a single claim.
+		type MaxDelegates: Get<u32>;
+
+		#[pallet::constant]
+		/// The number of claims an account can own unless `AccountQuota` overrides it. Capped at
+		/// `MaxClaimsPerAccount`.
+		type DefaultAccountQuota: Get<u32>;
+
+		/// The origin allowed to override the claim quota of an account.
+		type QuotaOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin allowed to freeze and unfreeze individual claims.
+		type FreezeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin allowed to create claims. The account it resolves to owns the claim and
+		/// pays its deposit and fee. `frame_system::EnsureSigned` keeps the behaviour of plain
+		/// signed calls.
+		type CreateOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The wall clock that the creation time of new claims is read from, usually
+		/// `pallet_timestamp`.
+		type UnixTime: UnixTime;
+
+		#[pallet::constant]
+		/// Whether claims may only be transferred to accounts that exist in `frame_system`, so
+		/// that a claim is not stranded at an address nobody uses.
+		type RequireRecipientExists: Get<bool>;
+
+		#[pallet::constant]
+		/// Whether the bytes of new claims must be valid UTF-8. Raw bytes are accepted otherwise.
+		type RequireUtf8: Get<bool>;
+
+		#[pallet::constant]
+		/// The maximum number of accounts allowed to pull-transfer a single claim.
+		type MaxAllowed: Get<u32>;
+
+		/// Notified after every transfer of a claim, once per previous owner. `()` ignores
+		/// transfers.
+		type OnClaimTransfer: OnClaimTransferHandler<Self::AccountId>;
+
+		#[pallet::constant]
+		/// The maximum number of namespaces a single account can have registered.
+		type MaxNamespacesPerAccount: Get<u32>;
+
+		#[pallet::constant]
+		/// How many blocks must pass after a claim is transferred before it can be transferred
+		/// again, so proofs cannot be passed quickly through intermediaries.
+		type TransferCooldown: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		/// The percentage of `MaxTotalProofs` at which [`Event::StorageNearCapacity`] warns that
+		/// the chain is running out of room for claims.
+		type CapacityWarningThresholdPercent: Get<u8>;
+
+		#[pallet::constant]
+		/// The maximum number of plain and hash-only claims stored within a single namespace.
+		type MaxClaimsPerNamespace: Get<u32>;
+
+		#[pallet::constant]
+		/// The longest TTL, in blocks, that a claim can be created with instead of
+		/// `DefaultClaimExpiry`.
+		type MaxClaimTtl: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		/// The number of blocks an [`Pallet::ownership_challenge`] stays the same for.
+		type ChallengeEpochLength: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		/// Whether claims are lower-cased before they are keyed or hashed, so that case variants
+		/// of the same text are the same claim. Only takes effect together with `RequireUtf8`.
+		/// Keep the original spelling in the claim metadata to display it. Claims stored with
+		/// upper-case letters before it is switched on can no longer be looked up.
+		type CaseInsensitiveClaims: Get<bool>;
+
+		#[pallet::constant]
+		/// The maximum number of accounts that may view one private claim.
+		type MaxViewers: Get<u32>;
+
+		#[pallet::constant]
+		/// The maximum number of claims [`migrations::MigrateToV3`] moves into the default
+		/// namespace per block.
+		type MigrationBatchSize: Get<u32>;
+
+		#[pallet::constant]
+		/// Whether accounts that never called [`Pallet::set_accepts_claims`] accept transferred
+		/// claims.
+		type DefaultAcceptsClaims: Get<bool>;
+
+		#[pallet::constant]
+		/// The number of blocks a tombstone is kept before `on_idle` may purge it. Once purged,
+		/// the retired claim can be created again.
+		type TombstoneRetention: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		/// The deposit reserved from the offerer of a swap until the offer is executed, cancelled
+		/// or dropped because one of its claims changed hands.
+		type SwapOfferDeposit: Get<BalanceOf<Self>>;
+
+		#[pallet::constant]
+		/// The maximum number of open swap offers a single claim can be part of.
+		type MaxSwapOffersPerClaim: Get<u32>;
+
+		#[pallet::constant]
+		/// The number of entries kept in `CreationLog`. The oldest entry is dropped once the log
+		/// has this many.
+		type MaxRecentClaims: Get<u32>;
+	}
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Deposits as ClaimDeposits<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// The form of a claim as it is used for storage keys.
+	pub type ClaimOf<T> = <T as Config>::ClaimData;
+
+	/// The bounded form of a namespace. Applications use distinct namespaces so that identical
+	/// claim bytes do not collide.
+	pub type NamespaceOf<T> = BoundedVec<u8, <T as Config>::MaxNamespaceLength>;
+
+	/// The storage key of a claim: the namespace it lives in and the claim itself.
+	pub type ClaimKeyOf<T> = (NamespaceOf<T>, ClaimOf<T>);
+
+	/// A short MIME-like tag such as `application/pdf`, telling clients how to render the
+	/// content behind a claim without fetching it.
+	pub type ContentType = BoundedVec<u8, ConstU32<32>>;
+
+	/// A short human-readable display name for a claim. Not part of the claim's identity, so
+	/// several claims may carry the same label.
+	pub type Label = BoundedVec<u8, ConstU32<64>>;
+
+	/// The standard reason codes recorded when a claim is revoked, for operators that have to
+	/// log why a proof was withdrawn. Codes up to 999 are reserved for this list; operators may
+	/// use higher codes for their own reasons.
+	pub struct RevokeReason;
+
+	impl RevokeReason {
+		/// No reason was given.
+		pub const UNSPECIFIED: u16 = 0;
+		/// The key of an owner was compromised.
+		pub const KEY_COMPROMISE: u16 = 1;
+		/// The claim was replaced by a newer one.
+		pub const SUPERSEDED: u16 = 2;
+		/// The claimed content turned out to be wrong.
+		pub const CONTENT_ERROR: u16 = 3;
+		/// The owner no longer needs the claim.
+		pub const NO_LONGER_NEEDED: u16 = 4;
+		/// Ownership of the content is disputed.
+		pub const DISPUTED: u16 = 5;
+		/// A court or regulator ordered the claim to be withdrawn.
+		pub const LEGAL_ORDER: u16 = 6;
+		/// The data subject asked for the claim to be erased.
+		pub const ERASURE_REQUEST: u16 = 7;
+	}
+
+	/// Information stored for every claim.
+	#[derive(
+		Encode,
+		Decode,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct ClaimInfo<T: Config> {
+		/// The accounts jointly owning the claim. Never empty.
+		pub owners: BoundedVec<T::AccountId, T::MaxOwners>,
+		/// The block in which the claim was created. Transfers keep it.
+		pub created_at: T::BlockNumber,
+		/// The Unix time in milliseconds at which the claim was created, if it is known. Claims
+		/// from the genesis config or from before the time was recorded have none.
+		pub created_moment: Option<u64>,
+		/// The block from which the claim is no longer valid, if it expires at all.
+		pub expiry: Option<T::BlockNumber>,
+		/// Free-form data attached to the claim, reserved for future use.
+		pub metadata: BoundedVec<u8, T::MaxMetadataLength>,
+		/// The account that paid the deposit. It stays with this account across transfers and
+		/// is returned to it when the claim is removed.
+		pub depositor: T::AccountId,
+		/// The amount reserved from `depositor` for this claim.
+		pub deposit: BalanceOf<T>,
+		/// Whether the owners allow the claim to change hands.
+		pub transferable: bool,
+		/// The claim in the same namespace this claim was created as a successor of, if any.
+		pub parent: Option<ClaimOf<T>>,
+		/// The kind of content the claim proves, if its owners tagged it.
+		pub content_type: Option<ContentType>,
+		/// The block in which the claim last changed hands, if it ever did.
+		pub last_transferred_at: Option<T::BlockNumber>,
+		/// Whether the claim is hidden from the runtime APIs for everyone but its owners and
+		/// viewers.
+		pub private: bool,
+	}
+
+	impl<T: Config> ClaimInfo<T> {
+		/// Whether `who` is one of the owners of the claim.
+		pub fn is_owner(&self, who: &T::AccountId) -> bool {
+			self.owners.contains(who)
+		}
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(17);
+
+	/// The most `Superseded` aliases [`Pallet::resolve_claim`] follows before giving up.
+	const MAX_SUPERSEDE_HOPS: u32 = 16;
+
 	#[pallet::pallet]
 	// 因为我们要定义存储项，所以需要 pallet::generate_store 宏
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// 定义存储项
 	#[pallet::storage]
 	pub type Proofs<T: Config> = StorageMap<
 		_,
-		// 密码安全的hash算法
+		// 由运行时选择的 hash 算法
+		T::ProofsHasher,
+		ClaimKeyOf<T>,
+		ClaimInfo<T>,
+	>;
+
+	// 按过期区块索引的存证，用于在 on_initialize 中清理过期存证
+	#[pallet::storage]
+	pub type ExpiringAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<ClaimKeyOf<T>, T::MaxClaimsPerExpiryBlock>,
+	>;
+
+	// 上一个区块中因超出清理上限而未处理完的过期区块
+	#[pallet::storage]
+	pub type ExpiryCursor<T: Config> = StorageValue<_, T::BlockNumber>;
+
+	// 分批迁移到版本 3 时最后移动的存证，之后的区块从这里继续，迁移完成后删除
+	#[pallet::storage]
+	pub type MigrationCursor<T: Config> = StorageValue<_, migrations::BytesClaimOf<T>>;
+
+	// 按所有者索引的存证列表
+	#[pallet::storage]
+	pub type ClaimsByOwner<T: Config> = StorageMap<
+		_,
 		Blake2_128Concat,
-		BoundedVec<u8, T::MaxClaimLength>,
-		(T::AccountId, T::BlockNumber),
+		T::AccountId,
+		BoundedVec<ClaimKeyOf<T>, T::MaxClaimsPerAccount>,
+		ValueQuery,
+	>;
+
+	// 创世区块时预置的存证
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Claims and their owners to store at genesis, in the default namespace. Genesis claims
+		/// never expire.
+		pub initial_claims: Vec<(Vec<u8>, T::AccountId)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { initial_claims: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (claim, owner) in &self.initial_claims {
+				let bounded_claim = Pallet::<T>::bounded_claim(claim)
+					.expect("genesis claim is longer than MaxClaimLength or not a valid ClaimData");
+				let key = (Pallet::<T>::default_namespace(), bounded_claim);
+				assert!(!Proofs::<T>::contains_key(&key), "duplicate claim in genesis config");
+				Pallet::<T>::add_to_owner_index(owner, &key)
+					.expect("too many genesis claims for one account");
+				Pallet::<T>::inc_proof_count().expect("more genesis claims than MaxTotalProofs");
+				Pallet::<T>::inc_namespace_count(&key.0)
+					.expect("more genesis claims than MaxClaimsPerNamespace");
+				Pallet::<T>::record_owner(&key, owner.clone(), T::BlockNumber::zero());
+				ClaimGenerations::<T>::insert(&key, 1);
+				Pallet::<T>::assign_claim_id(&key);
+				Proofs::<T>::insert(
+					&key,
+					ClaimInfo {
+						owners: BoundedVec::truncate_from(vec![owner.clone()]),
+						created_at: T::BlockNumber::zero(),
+						created_moment: None,
+						expiry: None,
+						metadata: Default::default(),
+						depositor: owner.clone(),
+						deposit: Zero::zero(),
+						transferable: true,
+						parent: None,
+						content_type: None,
+						last_transferred_at: None,
+						private: false,
+					},
+				);
+			}
+		}
+	}
+
+	// 等待接收方确认的存证转移
+	#[pallet::storage]
+	pub type PendingTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimKeyOf<T>, T::AccountId>;
+
+	// 被所有者授权代为吊销或转移存证的账户
+	#[pallet::storage]
+	pub type ClaimApprovals<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimKeyOf<T>, T::AccountId>;
+
+	// 只保存哈希的存证，键的大小固定，与原始内容长度无关
+	#[pallet::storage]
+	pub type HashedProofs<T: Config> =
+		StorageMap<_, Blake2_128Concat, (NamespaceOf<T>, T::Hash), ClaimInfo<T>>;
+
+	// 按所有者索引的哈希存证列表，与普通存证共用账户配额
+	#[pallet::storage]
+	pub type HashedClaimsByOwner<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(NamespaceOf<T>, T::Hash), T::MaxClaimsPerAccount>,
+		ValueQuery,
+	>;
+
+	// 暂停开关，打开时拒绝所有用户发起的存证操作
+	#[pallet::storage]
+	pub type Paused<T> = StorageValue<_, bool, ValueQuery>;
+
+	// 被所有者放弃的存证，记录放弃时的区块，墓碑被清理之前不能再被创建
+	#[pallet::storage]
+	pub type Tombstones<T: Config> = StorageMap<_, Blake2_128Concat, ClaimKeyOf<T>, T::BlockNumber>;
+
+	// 按留下的先后顺序排队等待清理的墓碑
+	#[pallet::storage]
+	pub type TombstoneQueue<T: Config> = StorageMap<_, Twox64Concat, u64, ClaimKeyOf<T>>;
+
+	// 墓碑队列中第一个未清理的序号和下一个入队的序号
+	#[pallet::storage]
+	pub type TombstoneQueueBounds<T> = StorageValue<_, (u64, u64), ValueQuery>;
+
+	// 账户在某个区块内创建的存证数量，区块变化后的第一次创建会重新计数
+	#[pallet::storage]
+	pub type ClaimsThisBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (T::BlockNumber, u32), ValueQuery>;
+
+	// 链上存证总数，包括只存储哈希的存证
+	#[pallet::storage]
+	pub type ProofCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	// 存证总数达到告警阈值后置位，回落到阈值以下时清除，避免重复告警
+	#[pallet::storage]
+	pub type CapacityWarned<T> = StorageValue<_, bool, ValueQuery>;
+
+	// 每个命名空间中的存证数量，包括只存储哈希的存证
+	#[pallet::storage]
+	pub type NamespaceCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, NamespaceOf<T>, u32, ValueQuery>;
+
+	// 启用白名单时允许创建存证的账户
+	#[pallet::storage]
+	pub type AllowedCreators<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	// 存证的所有权变更记录，按时间顺序保存每次创建和转移后的所有者及区块
+	#[pallet::storage]
+	pub type ClaimHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ClaimKeyOf<T>,
+		BoundedVec<(T::AccountId, T::BlockNumber), T::MaxHistoryLength>,
+		ValueQuery,
+	>;
+
+	// 覆盖默认配额的账户存证数量上限
+	#[pallet::storage]
+	pub type AccountQuota<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+	// 账户是否接收转移给它的存证，没有记录时使用 DefaultAcceptsClaims
+	#[pallet::storage]
+	pub type AcceptsClaims<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool>;
+
+	// 存证被创建的次数，删除存证时保留，用于区分重新创建的存证
+	#[pallet::storage]
+	pub type ClaimGenerations<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimKeyOf<T>, u32, ValueQuery>;
+
+	// 被所有者授权吊销存证的账户，转移时清空
+	#[pallet::storage]
+	pub type RevokeDelegates<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ClaimKeyOf<T>,
+		BoundedVec<T::AccountId, T::MaxDelegates>,
+		ValueQuery,
+	>;
+
+	// 争议期间被冻结的存证，冻结期间不能转移、吊销或修改
+	#[pallet::storage]
+	pub type FrozenClaims<T: Config> = StorageMap<_, Blake2_128Concat, ClaimKeyOf<T>, ()>;
+
+	// 被替换的存证指向同一命名空间内替换它的新存证，重新创建旧存证时清除
+	#[pallet::storage]
+	pub type Superseded<T: Config> = StorageMap<_, Blake2_128Concat, ClaimKeyOf<T>, ClaimOf<T>>;
+
+	// 被所有者授权自行领取存证的账户，任何转移后清空
+	#[pallet::storage]
+	pub type TransferAllowList<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ClaimKeyOf<T>,
+		BoundedVec<T::AccountId, T::MaxAllowed>,
+		ValueQuery,
+	>;
+
+	// 命名空间的登记者，首次在命名空间中创建存证时自动登记，空命名空间由所有账户共享
+	#[pallet::storage]
+	pub type NamespaceOwners<T: Config> =
+		StorageMap<_, Blake2_128Concat, NamespaceOf<T>, T::AccountId>;
+
+	// 每个账户登记的命名空间数量
+	#[pallet::storage]
+	pub type NamespacesOwned<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	// 存证的显示名称，不参与存储键和重复检测，删除存证时清除
+	#[pallet::storage]
+	pub type Labels<T: Config> = StorageMap<_, Blake2_128Concat, ClaimKeyOf<T>, Label>;
+
+	// 托管中的存证，记录受益人和可以释放的区块，托管期间不能转移、吊销或修改
+	#[pallet::storage]
+	pub type Escrows<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimKeyOf<T>, (T::AccountId, T::BlockNumber)>;
+
+	// 按创建顺序记录的存证（含命名空间）及其创建者，只保留最近的 MaxRecentClaims 条，吊销或转移后的存证仍然保留
+	#[pallet::storage]
+	pub type CreationLog<T: Config> =
+		StorageMap<_, Twox64Concat, u64, (ClaimKeyOf<T>, T::AccountId)>;
+
+	// 下一条创建记录的序号
+	#[pallet::storage]
+	pub type NextSeq<T> = StorageValue<_, u64, ValueQuery>;
+
+	// 下一个存证创建、转移、吊销事件的序号，链下索引可以据此对同一区块内的事件排序
+	#[pallet::storage]
+	pub type EventSeq<T> = StorageValue<_, u64, ValueQuery>;
+
+	// 私有存证的查看者，除所有者外只有这些账户可以通过运行时 API 查到私有存证
+	#[pallet::storage]
+	pub type Viewers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ClaimKeyOf<T>,
+		BoundedVec<T::AccountId, T::MaxViewers>,
+		ValueQuery,
+	>;
+
+	// 存证创建时分配的编号，转移时不变，存证删除后编号也不会再分配给其他存证
+	#[pallet::storage]
+	pub type ClaimIds<T: Config> = StorageMap<_, Blake2_128Concat, ClaimKeyOf<T>, u64>;
+
+	// 下一个存证编号
+	#[pallet::storage]
+	pub type NextClaimId<T> = StorageValue<_, u64, ValueQuery>;
+
+	// 交换存证的报价，按（提供的存证，想要的存证）索引，记录报价人、交易对手和押金
+	#[pallet::storage]
+	pub type SwapOffers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(ClaimKeyOf<T>, ClaimKeyOf<T>),
+		(T::AccountId, T::AccountId, BalanceOf<T>),
+	>;
+
+	// 每个存证参与的交换报价，存证转移或删除时据此撤销报价
+	#[pallet::storage]
+	pub type SwapOffersOf<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ClaimKeyOf<T>,
+		BoundedVec<(ClaimKeyOf<T>, ClaimKeyOf<T>), T::MaxSwapOffersPerClaim>,
+		ValueQuery,
 	>;
 
 	// 定义事件
@@ -45,99 +675,3884 @@ pub mod pallet {
 	// 生成工具函数
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		ClaimCreated(T::AccountId, Vec<u8>),
-		ClaimRevoked(T::AccountId, Vec<u8>),
-		ClaimTransfered(T::AccountId, T::AccountId, Vec<u8>),
+		/// A claim was created. `info` is exactly what was stored under the claim, so clients need
+		/// no follow-up storage read. `claim_id` is the id returned by [`Pallet::claim_id`].
+		/// `event_index` increases by one with every event that adds, removes or moves a claim,
+		/// ordering them even within a block.
+		/// [who, namespace, claim, info, fee, claim_id, event_index]
+		ClaimCreated(T::AccountId, Vec<u8>, Vec<u8>, ClaimInfo<T>, BalanceOf<T>, u64, u64),
+		/// A claim was revoked by one of its owners, its approved operator or a revoke delegate.
+		/// `owner` and `revoked_by` are equal when an owner revoked it. `reason` is one of the
+		/// [`RevokeReason`] codes, [`RevokeReason::UNSPECIFIED`] if none was given.
+		/// [owner, revoked_by, namespace, claim, block, reason, event_index]
+		ClaimRevoked(T::AccountId, T::AccountId, Vec<u8>, Vec<u8>, T::BlockNumber, u16, u64),
+		/// A claim was transferred. [from, to, namespace, claim, block, event_index]
+		ClaimTransfered(T::AccountId, T::AccountId, Vec<u8>, Vec<u8>, T::BlockNumber, u64),
+		/// A claim that was stored before was created again, instead of `ClaimCreated`. It gets
+		/// a fresh `claim_id`. [who, namespace, claim, generation, claim_id, event_index]
+		ClaimRecreated(T::AccountId, Vec<u8>, Vec<u8>, u32, u64, u64),
+		/// An expired claim was removed from storage. [namespace, claim, event_index]
+		ClaimExpired(Vec<u8>, Vec<u8>, u64),
+		/// A transfer was proposed and awaits acceptance. [from, to, namespace, claim]
+		TransferProposed(T::AccountId, T::AccountId, Vec<u8>, Vec<u8>),
+		/// A pending transfer was cancelled by the owner. [owner, namespace, claim]
+		TransferCancelled(T::AccountId, Vec<u8>, Vec<u8>),
+		/// A claim was removed by the root origin. `reason` is one of the [`RevokeReason`]
+		/// codes. [namespace, claim, reason, event_index]
+		ClaimForceRevoked(Vec<u8>, Vec<u8>, u16, u64),
+		/// A claim was stored by its hash only. [who, namespace, hash, deposit, fee, event_index]
+		HashedClaimCreated(T::AccountId, Vec<u8>, T::Hash, BalanceOf<T>, BalanceOf<T>, u64),
+		/// A hash-only claim was revoked. [who, namespace, hash, event_index]
+		HashedClaimRevoked(T::AccountId, Vec<u8>, T::Hash, u64),
+		/// The pallet was paused.
+		Paused,
+		/// The pallet was unpaused.
+		Unpaused,
+		/// An operator was approved to manage a claim. [owner, operator, namespace, claim]
+		ClaimApproved(T::AccountId, T::AccountId, Vec<u8>, Vec<u8>),
+		/// The approval of a claim was withdrawn by its owner. [owner, namespace, claim]
+		ClaimApprovalCancelled(T::AccountId, Vec<u8>, Vec<u8>),
+		/// A claim was renewed by its owner. [who, namespace, claim, expiry]
+		ClaimRenewed(T::AccountId, Vec<u8>, Vec<u8>, T::BlockNumber),
+		/// A claim was renounced and cannot be created again until its tombstone is purged.
+		/// [who, namespace, claim, event_index]
+		ClaimRenounced(T::AccountId, Vec<u8>, Vec<u8>, u64),
+		/// A claim was locked or unlocked against transfers. [who, namespace, claim, transferable]
+		ClaimTransferableSet(T::AccountId, Vec<u8>, Vec<u8>, bool),
+		/// The metadata of a claim was replaced by its owner. [who, namespace, claim]
+		ClaimMetadataSet(T::AccountId, Vec<u8>, Vec<u8>),
+		/// The content type of a claim was set or cleared by its owner.
+		/// [who, namespace, claim, content_type]
+		ClaimContentTypeSet(T::AccountId, Vec<u8>, Vec<u8>, Option<Vec<u8>>),
+		/// An owner added a joint owner to a claim. [who, namespace, claim, owner]
+		OwnerAdded(T::AccountId, Vec<u8>, Vec<u8>, T::AccountId),
+		/// An owner removed a joint owner from a claim. [who, namespace, claim, owner]
+		OwnerRemoved(T::AccountId, Vec<u8>, Vec<u8>, T::AccountId),
+		/// The current state of a claim, as requested by `query_claim`.
+		/// [namespace, claim, owner, created_at]
+		ClaimQueried(Vec<u8>, Vec<u8>, Option<T::AccountId>, Option<T::BlockNumber>),
+		/// An account was allowed to create claims. [who]
+		CreatorAdded(T::AccountId),
+		/// An account is no longer allowed to create claims. [who]
+		CreatorRemoved(T::AccountId),
+		/// An owner allowed an account to revoke a claim. [who, namespace, claim, delegate]
+		RevokeDelegateAdded(T::AccountId, Vec<u8>, Vec<u8>, T::AccountId),
+		/// An owner took back the right of an account to revoke a claim.
+		/// [who, namespace, claim, delegate]
+		RevokeDelegateRemoved(T::AccountId, Vec<u8>, Vec<u8>, T::AccountId),
+		/// An account removed some of its expired claims. [who, count]
+		ExpiredClaimsRevoked(T::AccountId, u32),
+		/// A best-effort batch finished. [who, created, skipped]
+		BatchCompleted(T::AccountId, u32, u32),
+		/// The claim quota of an account was overridden, or reset to the default if `None`.
+		/// [who, quota]
+		AccountQuotaSet(T::AccountId, Option<u32>),
+		/// A claim was frozen or unfrozen. [namespace, claim, frozen]
+		ClaimFrozenSet(Vec<u8>, Vec<u8>, bool),
+		/// `create_claim_if_absent` found the claim already owned by the caller and left it
+		/// untouched. [who, namespace, claim]
+		ClaimAlreadyPresent(T::AccountId, Vec<u8>, Vec<u8>),
+		/// A claim was replaced by a new claim that keeps its ownership and creation time.
+		/// [who, namespace, old_claim, new_claim, event_index]
+		ClaimSuperseded(T::AccountId, Vec<u8>, Vec<u8>, Vec<u8>, u64),
+		/// The deposit of a force-revoked claim was slashed instead of refunded.
+		/// [depositor, amount]
+		ClaimSlashed(T::AccountId, BalanceOf<T>),
+		/// An owner allowed an account to take over a claim with [`Pallet::claim_transfer`].
+		/// [who, namespace, claim, allowed]
+		TransferAllowed(T::AccountId, Vec<u8>, Vec<u8>, T::AccountId),
+		/// An owner took back the right of an account to take over a claim.
+		/// [who, namespace, claim, allowed]
+		TransferDisallowed(T::AccountId, Vec<u8>, Vec<u8>, T::AccountId),
+		/// An account moved its claims to another account in one call, skipping the claims that
+		/// could not be transferred. [who, dest, moved, skipped]
+		ClaimsBulkTransferred(T::AccountId, T::AccountId, u32, u32),
+		/// A namespace was registered to an account. [who, namespace]
+		NamespaceRegistered(T::AccountId, Vec<u8>),
+		/// An account gave up a namespace it had registered. [who, namespace]
+		NamespaceReleased(T::AccountId, Vec<u8>),
+		/// The number of stored claims, reported on request. [count]
+		ProofCountReported(u32),
+		/// A claim was superseded by the claim its owner derived from it with a patch.
+		/// [who, namespace, old_claim, new_claim, event_index]
+		ClaimPatched(T::AccountId, Vec<u8>, Vec<u8>, Vec<u8>, u64),
+		/// An owner set the display label of a claim. [who, namespace, claim, label]
+		ClaimLabelSet(T::AccountId, Vec<u8>, Vec<u8>, Vec<u8>),
+		/// A claim was deleted because its only owner was reaped.
+		/// [owner, namespace, claim, event_index]
+		ClaimReaped(T::AccountId, Vec<u8>, Vec<u8>, u64),
+		/// An owner placed a claim in escrow for a beneficiary.
+		/// [who, namespace, claim, beneficiary, release_block]
+		ClaimEscrowed(T::AccountId, Vec<u8>, Vec<u8>, T::AccountId, T::BlockNumber),
+		/// An escrowed claim was released to its beneficiary. [namespace, claim, beneficiary]
+		EscrowReleased(Vec<u8>, Vec<u8>, T::AccountId),
+		/// An owner took a claim out of escrow before its release block. [who, namespace, claim]
+		EscrowCancelled(T::AccountId, Vec<u8>, Vec<u8>),
+		/// A claim could not be created by [`Pallet::try_create_claim`]. `code` is the stable
+		/// code of the error, see [`Pallet::error_code`]. [who, code]
+		ClaimFailed(T::AccountId, u16),
+		/// The number of stored claims reached `CapacityWarningThresholdPercent` of
+		/// `MaxTotalProofs`. Emitted again only after it fell below the threshold. [used, cap]
+		StorageNearCapacity(u32, u32),
+		/// An owner offered to swap one of its claims for a claim of `counterparty`.
+		/// [who, counterparty, namespace, my_claim, their_claim]
+		SwapOffered(T::AccountId, T::AccountId, Vec<u8>, Vec<u8>, Vec<u8>),
+		/// Two claims changed owners in a swap; `who` accepted the offer of `counterparty`.
+		/// [who, counterparty, namespace, my_claim, their_claim]
+		ClaimsSwapped(T::AccountId, T::AccountId, Vec<u8>, Vec<u8>, Vec<u8>),
+		/// An owner made a claim private or public again. [who, namespace, claim, private]
+		ClaimPrivacySet(T::AccountId, Vec<u8>, Vec<u8>, bool),
+		/// An owner let an account view a private claim. [who, namespace, claim, viewer]
+		ViewerAdded(T::AccountId, Vec<u8>, Vec<u8>, T::AccountId),
+		/// An owner stopped letting an account view a private claim. [who, namespace, claim, viewer]
+		ViewerRemoved(T::AccountId, Vec<u8>, Vec<u8>, T::AccountId),
+		/// An account chose whether it accepts transferred claims. [who, accepts]
+		AcceptsClaimsSet(T::AccountId, bool),
+		/// An offerer withdrew its swap offer. [who, namespace, my_claim, their_claim]
+		SwapOfferCancelled(T::AccountId, Vec<u8>, Vec<u8>, Vec<u8>),
+		/// A hashed claim was deleted because its owner was reaped.
+		/// [owner, namespace, hash, event_index]
+		HashedClaimReaped(T::AccountId, Vec<u8>, T::Hash, u64),
+		/// A claim expires within `ExpiryWarningWindow` blocks. [namespace, claim, expiry]
+		ClaimExpiringSoon(Vec<u8>, Vec<u8>, T::BlockNumber),
 	}
 
 	// 定义错误
 	#[pallet::error]
 	pub enum Error<T> {
-		ProofAlreadyExist,
+		/// The claim is already stored within the namespace and the caller is not one of its
+		/// owners.
+		ProofAlreadyExistOwnedByOther,
+		/// The claim is longer than `MaxClaimLength`.
 		ClaimTooLong,
+		/// The claim bytes cannot be converted into `ClaimData`.
+		InvalidClaim,
+		/// The metadata is longer than `MaxMetadataLength`.
+		MetadataTooLong,
+		/// The namespace is longer than `MaxNamespaceLength`.
+		NamespaceTooLong,
+		/// The claim is shorter than `MinClaimLength`.
+		ClaimTooShort,
+		/// The claim is not stored within the namespace.
 		ClaimNotExist,
+		/// The caller is not one of the owners of the claim.
 		NotClaimOwner,
+		/// The claim has passed its expiry block.
+		ClaimExpired,
+		/// Too many claims already expire at the same block.
+		ExpiryQueueFull,
+		/// The account already owns as many claims as its quota allows.
+		TooManyClaims,
+		/// The batch holds more than `MaxBatchSize` claims.
+		BatchTooLarge,
+		/// The claim has no pending transfer.
+		NoPendingTransfer,
+		/// The caller is not the recipient of the pending transfer.
+		NotPendingRecipient,
+		/// The account cannot afford the claim deposit.
+		InsufficientDeposit,
+		/// The account cannot afford the claim creation fee.
+		InsufficientFee,
+		/// The pallet is paused.
+		Paused,
+		/// The caller is neither the owner nor the approved operator of the claim.
+		NotAuthorized,
+		/// The claim has no approved operator.
+		NoApproval,
+		/// The claim was renounced and cannot be created again.
+		ClaimTombstoned,
+		/// The owner has locked the claim against transfers.
+		ClaimNotTransferable,
+		/// The destination of a transfer already owns the claim.
+		CannotTransferToSelf,
+		/// The account is already one of the owners of the claim.
+		AlreadyOwner,
+		/// The account is not one of the owners of the claim.
+		NotAnOwner,
+		/// The only owner of a claim cannot be removed.
+		LastOwner,
+		/// The claim already has `MaxOwners` owners.
+		TooManyOwners,
+		/// The account already created `MaxClaimsPerBlockPerAccount` claims in this block.
+		RateLimited,
+		/// The chain already holds `MaxTotalProofs` claims.
+		StorageFull,
+		/// The claim does not expire at the reported block, or not within
+		/// `ExpiryWarningWindow` blocks.
+		NotExpiringSoon,
+		/// The account is not in `AllowedCreators`.
+		NotWhitelisted,
+		/// The claim was created less than `TransferLockPeriod` blocks ago.
+		ClaimLocked,
+		/// The account is already a revoke delegate of the claim.
+		DelegateAlreadyExists,
+		/// The account is not a revoke delegate of the claim.
+		DelegateNotFound,
+		/// The claim already has `MaxDelegates` revoke delegates.
+		TooManyDelegates,
+		/// The claim is empty.
+		EmptyClaim,
+		/// The quota is higher than `MaxClaimsPerAccount`.
+		QuotaTooHigh,
+		/// The claim is frozen by `FreezeOrigin`.
+		ClaimFrozen,
+		/// The destination of a transfer does not exist while `RequireRecipientExists` is set.
+		RecipientNotFound,
+		/// The claim is not valid UTF-8 while `RequireUtf8` is set.
+		InvalidUtf8,
+		/// The account is already on the transfer allow-list of the claim.
+		AlreadyAllowed,
+		/// The transfer allow-list of the claim already holds `MaxAllowed` accounts.
+		TooManyAllowed,
+		/// The account is not on the transfer allow-list of the claim.
+		NotAllowed,
+		/// The claim is already stored within the namespace and the caller is one of its owners,
+		/// which idempotent clients may treat as success.
+		ProofAlreadyExistOwnedBySelf,
+		/// The account already has `MaxNamespacesPerAccount` namespaces registered.
+		TooManyNamespaces,
+		/// The namespace is already registered.
+		NamespaceTaken,
+		/// The namespace is not registered to the caller.
+		NamespaceNotOwned,
+		/// The empty namespace is shared by all accounts and cannot be registered.
+		NamespaceReserved,
+		/// The parent claim is not stored within the namespace.
+		ParentNotExist,
+		/// The content type is longer than 32 bytes.
+		ContentTypeTooLong,
+		/// The claim was transferred less than `TransferCooldown` blocks ago.
+		TransferCooldownActive,
+		/// The patch is longer than `MaxClaimLength`.
+		PatchTooLong,
+		/// The patch does not decode, or copies bytes from outside the claim.
+		InvalidPatch,
+		/// The patched claim would be longer than `MaxClaimLength`.
+		PatchResultTooLong,
+		/// The label is longer than 64 bytes.
+		LabelTooLong,
+		/// The claim is held in escrow.
+		ClaimInEscrow,
+		/// The claim is not held in escrow.
+		NotInEscrow,
+		/// The release block of the escrow has not been reached yet.
+		EscrowNotDue,
+		/// The release block of the escrow has been reached, so it can no longer be cancelled.
+		EscrowDue,
+		/// A claim cannot be swapped with itself or with the caller.
+		InvalidSwap,
+		/// The namespace already holds `MaxClaimsPerNamespace` claims.
+		NamespaceFull,
+		/// The same claim appears more than once in a batch.
+		DuplicateClaim,
+		/// The requested TTL is longer than `MaxClaimTtl`.
+		TtlTooLong,
+		/// The account may already view the claim.
+		AlreadyViewer,
+		/// The account is not a viewer of the claim.
+		NotViewer,
+		/// The claim already has `MaxViewers` viewers.
+		TooManyViewers,
+		/// The recipient does not accept transferred claims.
+		RecipientRefusesClaims,
+		/// A claim cannot be created with a TTL of zero blocks.
+		ZeroTtl,
+		/// The caller has not offered this swap.
+		NoSwapOffer,
+		/// One of the claims is already part of `MaxSwapOffersPerClaim` swap offers.
+		TooManySwapOffers,
+		/// The release block of an escrow is not after the current block.
+		EscrowReleaseNotInFuture,
 	}
 
 	// 用于定义回调函数，在区块的不同时期执行
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			// 存证迁移完成之前过期索引可能还是旧布局，先继续迁移，不清理过期存证
+			if MigrationCursor::<T>::exists() {
+				return migrations::MigrateToV3::<T>::resume(T::MigrationBatchSize::get())
+			}
+			Self::purge_expired(n)
+		}
+
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::purge_tombstones(n, remaining_weight)
+		}
+
+		fn offchain_worker(n: BlockNumberFor<T>) {
+			Self::report_expiring_claims(n);
+		}
+
+		fn integrity_test() {
+			// 长度上限为零时所有存证都会以 ClaimTooLong 失败，启动时就报错
+			assert!(
+				T::MaxClaimLength::get() > 0,
+				"MaxClaimLength must be greater than zero, or no claim can ever be created"
+			);
+			assert!(
+				T::MinClaimLength::get() <= T::MaxClaimLength::get(),
+				"MinClaimLength must not exceed MaxClaimLength, or no claim can ever be created"
+			);
+			// 存活期为零的存证在本区块的过期清理之后才加入过期队列，永远不会被清理
+			assert!(
+				!T::DefaultClaimExpiry::get().is_zero(),
+				"DefaultClaimExpiry must be at least one block, or new claims are never purged"
+			);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+	}
 
 	// 定义可调用函数
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(0)]
-		pub fn create_claim(origin: OriginFor<T>, claim: Vec<u8>) -> DispatchResultWithPostInfo {
-			// 验证签名
-			let sender = ensure_signed(origin)?;
+		/// Charged for a claim of `MaxClaimLength` and a creator owning `MaxClaimsPerAccount`
+		/// claims up front; the difference is refunded for a shorter claim and fewer owned
+		/// claims. The claim is transferable unless `transferable` is `Some(false)`. A `parent`
+		/// claim, which must be stored in the same namespace, makes the new claim its successor;
+		/// see [`Pallet::ancestors`]. A `content_type` tags the kind of content being proven. A
+		/// nonzero `ttl` of at most `MaxClaimTtl` blocks replaces `DefaultClaimExpiry` for this
+		/// claim.
+		#[pallet::weight(T::WeightInfo::create_claim(
+			T::MaxClaimLength::get(),
+			T::MaxClaimsPerAccount::get(),
+		))]
+		pub fn create_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			metadata: Option<Vec<u8>>,
+			transferable: Option<bool>,
+			parent: Option<Vec<u8>>,
+			content_type: Option<Vec<u8>>,
+			ttl: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			// 验证创建权限，解析出的账户成为存证的所有者
+			let sender = T::CreateOrigin::ensure_origin(origin)?;
 
-			// 尝试从 Vec<u8> 转换为 BoundedVec<u8, T::MaxClaimLength>
-			let bounded_claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim.clone())
-				.map_err(|_| Error::<T>::ClaimTooLong)?;
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
 
-			// 验证是否已经存储过
-			ensure!(!Proofs::<T>::contains_key(&bounded_claim), Error::<T>::ProofAlreadyExist);
+			// 启用白名单时只有白名单中的账户可以创建存证
+			Self::ensure_allowed_creator(&sender)?;
 
-			Proofs::<T>::insert(
-				&bounded_claim,
-				(sender.clone(), frame_system::Pallet::<T>::block_number()),
-			);
+			// 自定义的存活期不能为零，也不能超过上限
+			if let Some(ttl) = ttl {
+				ensure!(!ttl.is_zero(), Error::<T>::ZeroTtl);
+				ensure!(ttl <= T::MaxClaimTtl::get(), Error::<T>::TtlTooLong);
+			}
 
-			Self::deposit_event(Event::ClaimCreated(sender, claim));
+			let namespace = Self::bounded_namespace(&namespace)?;
+			let metadata = Self::bounded_metadata(metadata.unwrap_or_default())?;
+			let content_type = content_type.map(Self::bounded_content_type).transpose()?;
 
-			Ok(().into())
+			// 父存证必须已经存在于同一命名空间中
+			let parent = parent
+				.map(|parent| {
+					let parent =
+						Self::bounded_claim(&parent).map_err(|_| Error::<T>::ParentNotExist)?;
+					ensure!(
+						Proofs::<T>::contains_key((namespace.clone(), parent.clone())),
+						Error::<T>::ParentNotExist
+					);
+					Ok::<_, Error<T>>(parent)
+				})
+				.transpose()?;
+
+			// 所有者索引越长，追加新存证的开销越大
+			let owned = ClaimsByOwner::<T>::decode_len(&sender).unwrap_or(0) as u32;
+			let actual_weight = T::WeightInfo::create_claim(claim.len() as u32, owned);
+			Self::do_create_claim(
+				&sender,
+				namespace,
+				claim,
+				metadata,
+				transferable.unwrap_or(true),
+				parent,
+				content_type,
+				ttl,
+			)?;
+
+			// 按实际的存证长度和已拥有的存证数量返还多收取的权重
+			Ok(Some(actual_weight).into())
 		}
 
-		#[pallet::weight(0)]
-		pub fn revoke_claim(origin: OriginFor<T>, claim: Vec<u8>) -> DispatchResultWithPostInfo {
+		/// Charged for a claim of `MaxClaimLength` up front; the difference is refunded for a
+		/// shorter claim. `reason` is one of the [`RevokeReason`] codes and is recorded in the
+		/// [`Event::ClaimRevoked`] event; it defaults to [`RevokeReason::UNSPECIFIED`].
+		#[pallet::weight(T::WeightInfo::revoke_claim(T::MaxClaimLength::get()))]
+		pub fn revoke_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			reason: Option<u16>,
+		) -> DispatchResultWithPostInfo {
 			// 验证签名
 			let sender = ensure_signed(origin)?;
 
-			// 尝试从 Vec<u8> 转换为 BoundedVec<u8, T::MaxClaimLength>
-			let bounded_claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim.clone())
-				.map_err(|_| Error::<T>::ClaimTooLong)?;
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
 
-			// 校验是否已经存在存证
-			let (owner, _) = Proofs::<T>::get(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?;
-
-			// 验证存证的所有者是否是当前用户
-			ensure!(owner == sender, Error::<T>::NotClaimOwner);
-
-			// 从存储里删除存证
-			Proofs::<T>::remove(&bounded_claim);
+			let actual_weight = T::WeightInfo::revoke_claim(claim.len() as u32);
+			let reason = reason.unwrap_or(RevokeReason::UNSPECIFIED);
+			Self::do_revoke_claim(&sender, namespace, claim, reason)?;
 
-			// 发送存证吊销事件
-			Self::deposit_event(Event::ClaimRevoked(sender, claim));
-
-			Ok(().into())
+			// 按实际的存证长度返还多收取的权重
+			Ok(Some(actual_weight).into())
 		}
 
-		#[pallet::weight(0)]
+		/// Charged for a claim of `MaxClaimLength` up front; the difference is refunded for a
+		/// shorter claim.
+		#[pallet::weight(T::WeightInfo::transfer_claim(T::MaxClaimLength::get()))]
 		pub fn transfer_claim(
 			origin: OriginFor<T>,
+			namespace: Vec<u8>,
 			claim: Vec<u8>,
 			dest: T::AccountId,
 		) -> DispatchResultWithPostInfo {
 			// 验证签名
 			let sender = ensure_signed(origin)?;
 
-			// 尝试从 Vec<u8> 转换为 BoundedVec<u8, T::MaxClaimLength>
-			let bounded_claim = BoundedVec::<u8, T::MaxClaimLength>::try_from(claim.clone())
-				.map_err(|_| Error::<T>::ClaimTooLong)?;
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
 
-			// 校验是否已经存在存证
-			let (owner, _) = Proofs::<T>::get(&bounded_claim).ok_or(Error::<T>::ClaimNotExist)?;
+			let (key, info) = Self::claim_to_transfer(&sender, &namespace, &claim, &dest)?;
+			Self::do_transfer(&key, info, dest)?;
 
-			// 验证存证的所有者是否是当前用户
-			ensure!(owner == sender, Error::<T>::NotClaimOwner);
+			// 按实际的存证长度返还多收取的权重
+			Ok(Some(T::WeightInfo::transfer_claim(claim.len() as u32)).into())
+		}
+
+		/// Create several claims at once. Either all of them are stored or none is.
+		#[pallet::weight(T::WeightInfo::create_claims(claims.len() as u32))]
+		pub fn create_claims(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claims: Vec<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			// 验证创建权限，解析出的账户成为存证的所有者
+			let sender = T::CreateOrigin::ensure_origin(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 启用白名单时只有白名单中的账户可以创建存证
+			Self::ensure_allowed_creator(&sender)?;
+
+			// 验证批量数量上限
+			ensure!(claims.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			// 任意一个存证失败时，整个调用的存储修改都会被回滚
+			let namespace = Self::bounded_namespace(&namespace)?;
+			for claim in claims {
+				Self::do_create_claim(
+					&sender,
+					namespace.clone(),
+					claim,
+					Default::default(),
+					true,
+					None,
+					None,
+					None,
+				)?;
+			}
+
+			Ok(().into())
+		}
+
+		/// Create several claims at once, skipping every claim that cannot be created, e.g. one
+		/// that already exists or is too long, instead of failing the whole batch. Reports the
+		/// tallies in a [`Event::BatchCompleted`] event.
+		#[pallet::weight(T::WeightInfo::create_claims_best_effort(claims.len() as u32))]
+		pub fn create_claims_best_effort(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claims: Vec<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			// 验证创建权限，解析出的账户成为存证的所有者
+			let sender = T::CreateOrigin::ensure_origin(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 启用白名单时只有白名单中的账户可以创建存证
+			Self::ensure_allowed_creator(&sender)?;
+
+			// 验证批量数量上限
+			ensure!(claims.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			// 每个存证在单独的存储事务中创建，失败时只回滚这一个存证
+			let namespace = Self::bounded_namespace(&namespace)?;
+			let (mut created, mut skipped) = (0u32, 0u32);
+			for claim in claims {
+				let result = with_transaction(|| {
+					match Self::do_create_claim(
+						&sender,
+						namespace.clone(),
+						claim,
+						Default::default(),
+						true,
+						None,
+						None,
+						None,
+					) {
+						Ok(()) => TransactionOutcome::Commit(Ok(())),
+						Err(error) => TransactionOutcome::Rollback(Err(error)),
+					}
+				});
+				if result.is_ok() {
+					created += 1;
+				} else {
+					skipped += 1;
+				}
+			}
+
+			Self::deposit_event(Event::BatchCompleted(sender, created, skipped));
+
+			Ok(().into())
+		}
+
+		/// Revoke several claims at once. Either all of them are removed or none is.
+		#[pallet::weight(T::WeightInfo::revoke_claims(claims.len() as u32))]
+		pub fn revoke_claims(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claims: Vec<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证批量数量上限
+			ensure!(claims.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			// 任意一个存证失败时，整个调用的存储修改都会被回滚
+			for claim in claims {
+				Self::do_revoke_claim(
+					&sender,
+					namespace.clone(),
+					claim,
+					RevokeReason::UNSPECIFIED,
+				)?;
+			}
+
+			Ok(().into())
+		}
+
+		/// Propose to transfer a claim to `dest`, who has to accept it before ownership moves.
+		#[pallet::weight(T::WeightInfo::propose_transfer(claim.len() as u32))]
+		pub fn propose_transfer(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			dest: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, info) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			// 验证存证允许转移
+			ensure!(info.transferable, Error::<T>::ClaimNotTransferable);
+
+			// 和直接转移一样，不能转移给自己或其他共同所有者
+			ensure!(!info.is_owner(&dest), Error::<T>::CannotTransferToSelf);
+
+			// 记录待确认的转移，新的提议会覆盖旧的提议
+			PendingTransfers::<T>::insert(&key, dest.clone());
+
+			Self::deposit_event(Event::TransferProposed(sender, dest, namespace, claim));
+
+			Ok(().into())
+		}
+
+		/// Accept a transfer proposed to the caller, taking ownership of the claim.
+		#[pallet::weight(T::WeightInfo::accept_transfer(claim.len() as u32))]
+		pub fn accept_transfer(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(&namespace, &claim)?;
+
+			// 验证当前用户是待确认转移的接收方
+			let dest = PendingTransfers::<T>::get(&key).ok_or(Error::<T>::NoPendingTransfer)?;
+			ensure!(dest == sender, Error::<T>::NotPendingRecipient);
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 验证存证是否已经过期
+			ensure!(!Self::is_expired(info.expiry), Error::<T>::ClaimExpired);
+
+			// 冻结期间不允许接受转移
+			Self::ensure_not_frozen(&key)?;
+
+			Self::do_transfer(&key, info, dest)?;
+
+			Ok(().into())
+		}
+
+		/// Withdraw a pending transfer of one of the caller's claims.
+		#[pallet::weight(T::WeightInfo::cancel_transfer(claim.len() as u32))]
+		pub fn cancel_transfer(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(&namespace, &claim)?;
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 验证当前用户是否是存证的所有者之一
+			ensure!(info.is_owner(&sender), Error::<T>::NotClaimOwner);
+
+			// 删除待确认的转移
+			ensure!(PendingTransfers::<T>::take(&key).is_some(), Error::<T>::NoPendingTransfer);
+
+			Self::deposit_event(Event::TransferCancelled(sender, namespace, claim));
+
+			Ok(().into())
+		}
+
+		/// Remove a claim regardless of its owner. Only callable by root. Operational, so it
+		/// still fits in a block full of normal transactions. `reason` is one of the
+		/// [`RevokeReason`] codes and defaults to [`RevokeReason::UNSPECIFIED`].
+		#[pallet::weight((
+			T::WeightInfo::force_revoke(claim.len() as u32),
+			DispatchClass::Operational
+		))]
+		pub fn force_revoke(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			reason: Option<u16>,
+		) -> DispatchResultWithPostInfo {
+			// 验证是否为 root 权限
+			ensure_root(origin)?;
+
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(&namespace, &claim)?;
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 从存储里删除存证，不校验所有者
+			Self::remove_claim(&key, &info);
+
+			let reason = reason.unwrap_or(RevokeReason::UNSPECIFIED);
+			Self::deposit_event(Event::ClaimForceRevoked(
+				namespace,
+				claim,
+				reason,
+				Self::next_event_index(),
+			));
+
+			Ok(().into())
+		}
+
+		/// Create a claim that only stores `T::Hashing` of the payload on chain.
+		///
+		/// The payload is still bounded by `MaxClaimLength` in transit, but storage grows by the
+		/// hash size and the namespace only. Hash-only claims never expire and live next to the
+		/// raw-bytes claims of [`Pallet::create_claim`], so the same bytes can be claimed both
+		/// ways. They count against the same account quota, and a tombstoned claim cannot be
+		/// created as a hash either.
+		#[pallet::weight(T::WeightInfo::create_hashed_claim(claim.len() as u32))]
+		pub fn create_hashed_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证创建权限，解析出的账户成为存证的所有者
+			let sender = T::CreateOrigin::ensure_origin(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 启用白名单时只有白名单中的账户可以创建存证
+			Self::ensure_allowed_creator(&sender)?;
+
+			// 校验存证长度
+			let bounded_namespace = Self::bounded_namespace(&namespace)?;
+			let bounded_claim = Self::bounded_claim(&claim)?;
+			ensure!(
+				Self::normalized_claim(&claim).len() as u32 >= T::MinClaimLength::get(),
+				Error::<T>::ClaimTooShort
+			);
+			Self::ensure_utf8(&claim)?;
+
+			// 被放弃的存证在墓碑清理之前也不能以哈希的形式重新创建
+			ensure!(
+				!Tombstones::<T>::contains_key((bounded_namespace.clone(), bounded_claim)),
+				Error::<T>::ClaimTombstoned
+			);
+
+			// 校验是否已经存在存证
+			let hash = Self::claim_hash(&claim);
+			let key = (bounded_namespace, hash);
+			if let Some(info) = HashedProofs::<T>::get(&key) {
+				return Err(Self::already_exist_error(&sender, &info).into())
+			}
+
+			// 首次在命名空间中创建存证时为创建者登记该命名空间
+			Self::note_namespace_used(&sender, &key.0)?;
+
+			// 先收取不退还的手续费，余额不足时不写入任何存储
+			let fee = Self::charge_creation_fee(&sender)?;
+			Self::note_claim_created(&sender)?;
+			Self::inc_proof_count()?;
+			Self::inc_namespace_count(&key.0)?;
+
+			// 押金按哈希的长度计算
+			let deposit = Self::claim_deposit(hash.as_ref().len());
+			T::Deposits::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+			// 哈希存证同样占用所有者的存证配额
+			Self::add_to_hashed_owner_index(&sender, &key)?;
+
+			HashedProofs::<T>::insert(
+				&key,
+				ClaimInfo {
+					owners: BoundedVec::truncate_from(vec![sender.clone()]),
+					created_at: frame_system::Pallet::<T>::block_number(),
+					created_moment: Some(Self::now_moment()),
+					expiry: None,
+					metadata: Default::default(),
+					depositor: sender.clone(),
+					deposit,
+					transferable: true,
+					parent: None,
+					content_type: None,
+					last_transferred_at: None,
+					private: false,
+				},
+			);
+
+			Self::deposit_event(Event::HashedClaimCreated(
+				sender,
+				namespace,
+				hash,
+				deposit,
+				fee,
+				Self::next_event_index(),
+			));
+
+			Ok(().into())
+		}
+
+		/// Revoke a claim created with [`Pallet::create_hashed_claim`], refunding its deposit.
+		#[pallet::weight(T::WeightInfo::revoke_hashed_claim(claim.len() as u32))]
+		pub fn revoke_hashed_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			Self::do_revoke_hashed_claim(&sender, namespace, Self::claim_hash(&claim))?;
+
+			Ok(().into())
+		}
+
+		/// Allow `operator` to revoke or transfer one of the caller's claims. Replaces any
+		/// previous approval of the claim.
+		#[pallet::weight(T::WeightInfo::approve(claim.len() as u32))]
+		pub fn approve(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			operator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, _) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			ClaimApprovals::<T>::insert(&key, operator.clone());
+
+			Self::deposit_event(Event::ClaimApproved(sender, operator, namespace, claim));
+
+			Ok(().into())
+		}
+
+		/// Withdraw the approval of one of the caller's claims.
+		#[pallet::weight(T::WeightInfo::cancel_approval(claim.len() as u32))]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(&namespace, &claim)?;
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 验证当前用户是否是存证的所有者之一
+			ensure!(info.is_owner(&sender), Error::<T>::NotClaimOwner);
+
+			// 删除授权
+			ensure!(ClaimApprovals::<T>::take(&key).is_some(), Error::<T>::NoApproval);
+
+			Self::deposit_event(Event::ClaimApprovalCancelled(sender, namespace, claim));
+
+			Ok(().into())
+		}
+
+		/// Push the expiry of one of the caller's claims `DefaultClaimExpiry` blocks past the
+		/// current block, keeping its creation block.
+		#[pallet::weight(T::WeightInfo::renew_claim(claim.len() as u32))]
+		pub fn renew_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, mut info) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			// 从原过期区块的索引中移除，再记录到新的过期区块
+			let expiry = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::DefaultClaimExpiry::get());
+			Self::remove_from_expiry_queue(&key, info.expiry);
+			ExpiringAt::<T>::try_mutate(expiry, |queue| {
+				queue
+					.get_or_insert_with(Default::default)
+					.try_push(key.clone())
+					.map_err(|_| Error::<T>::ExpiryQueueFull)
+			})?;
+
+			info.expiry = Some(expiry);
+			Proofs::<T>::insert(&key, info);
+
+			Self::deposit_event(Event::ClaimRenewed(sender, namespace, claim, expiry));
+
+			Ok(().into())
+		}
+
+		/// Retire one of the caller's claims. The deposit is returned, and nobody can create the
+		/// claim again until `on_idle` purges its tombstone after `TombstoneRetention` blocks.
+		#[pallet::weight(T::WeightInfo::renounce_claim(claim.len() as u32))]
+		pub fn renounce_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, info) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			// 删除存证并留下墓碑
+			Self::remove_claim(&key, &info);
+			Self::bury(&key, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::ClaimRenounced(
+				sender,
+				namespace,
+				claim,
+				Self::next_event_index(),
+			));
+
+			Ok(().into())
+		}
+
+		/// Replace the metadata attached to one of the caller's claims.
+		#[pallet::weight(T::WeightInfo::set_metadata(claim.len() as u32))]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			metadata: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			let metadata = Self::bounded_metadata(metadata)?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, mut info) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			info.metadata = metadata;
+			Proofs::<T>::insert(&key, info);
+
+			Self::deposit_event(Event::ClaimMetadataSet(sender, namespace, claim));
+
+			Ok(().into())
+		}
+
+		/// Tag one of the caller's claims with the kind of content it proves, or clear the tag
+		/// with `None`.
+		#[pallet::weight(T::WeightInfo::set_content_type(claim.len() as u32))]
+		pub fn set_content_type(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			content_type: Option<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			let bounded = content_type.clone().map(Self::bounded_content_type).transpose()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, mut info) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			info.content_type = bounded;
+			Proofs::<T>::insert(&key, info);
+
+			Self::deposit_event(Event::ClaimContentTypeSet(sender, namespace, claim, content_type));
+
+			Ok(().into())
+		}
+
+		/// Lock one of the caller's claims against transfers, or unlock it again. Locked claims
+		/// can still be revoked.
+		#[pallet::weight(T::WeightInfo::set_transferable(claim.len() as u32))]
+		pub fn set_transferable(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			transferable: bool,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, mut info) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			info.transferable = transferable;
+			Proofs::<T>::insert(&key, info);
+
+			Self::deposit_event(Event::ClaimTransferableSet(
+				sender,
+				namespace,
+				claim,
+				transferable,
+			));
+
+			Ok(().into())
+		}
+
+		/// Make `new_owner` a joint owner of a claim the caller owns. Every owner can revoke,
+		/// transfer and manage the claim.
+		#[pallet::weight(T::WeightInfo::add_owner(claim.len() as u32))]
+		pub fn add_owner(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, mut info) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			// 验证新所有者还不是所有者
+			ensure!(!info.is_owner(&new_owner), Error::<T>::AlreadyOwner);
+			info.owners.try_push(new_owner.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
+
+			// 记录到新所有者的存证列表中
+			Self::add_to_owner_index(&new_owner, &key)?;
+			Proofs::<T>::insert(&key, info);
+
+			Self::deposit_event(Event::OwnerAdded(sender, namespace, claim, new_owner));
+
+			Ok(().into())
+		}
+
+		/// Remove `owner` from the joint owners of a claim the caller owns. Owners may remove
+		/// themselves; the last owner cannot be removed.
+		#[pallet::weight(T::WeightInfo::remove_owner(claim.len() as u32))]
+		pub fn remove_owner(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, mut info) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			// 验证被移除的账户是所有者，并且不是最后一个所有者
+			ensure!(info.is_owner(&owner), Error::<T>::NotAnOwner);
+			ensure!(info.owners.len() > 1, Error::<T>::LastOwner);
+			info.owners.retain(|existing| existing != &owner);
+
+			// 从被移除所有者的存证列表中删除
+			Self::remove_from_owner_index(&owner, &key);
+			Proofs::<T>::insert(&key, info);
+
+			Self::deposit_event(Event::OwnerRemoved(sender, namespace, claim, owner));
+
+			Ok(().into())
+		}
+
+		/// Emit the first owner and the creation block of a claim in a [`Event::ClaimQueried`]
+		/// event, for front-ends without access to the runtime API. Both are `None` for a missing
+		/// or expired claim, and for a private one since the event is public. Reads the claim
+		/// from `Proofs` and writes nothing but the event; works while paused.
+		#[pallet::weight(Weight::zero())]
+		pub fn query_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			ensure_signed(origin)?;
+
+			let (owner, created_at) = match Self::get_claim(namespace.clone(), claim.clone(), None)
+			{
+				Some((owner, created_at)) => (Some(owner), Some(created_at)),
+				None => (None, None),
+			};
+
+			Self::deposit_event(Event::ClaimQueried(namespace, claim, owner, created_at));
+
+			Ok(().into())
+		}
+
+		/// Allow `who` to create claims while `EnforceCreatorWhitelist` is on.
+		#[pallet::weight(T::WeightInfo::add_creator())]
+		pub fn add_creator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+			// 验证白名单管理权限
+			T::CreatorAdminOrigin::ensure_origin(origin)?;
+
+			AllowedCreators::<T>::insert(&who, ());
+
+			Self::deposit_event(Event::CreatorAdded(who));
+
+			Ok(().into())
+		}
+
+		/// Take `who` off the creator whitelist. Claims it already created are kept.
+		#[pallet::weight(T::WeightInfo::remove_creator())]
+		pub fn remove_creator(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证白名单管理权限
+			T::CreatorAdminOrigin::ensure_origin(origin)?;
+
+			ensure!(AllowedCreators::<T>::take(&who).is_some(), Error::<T>::NotWhitelisted);
+
+			Self::deposit_event(Event::CreatorRemoved(who));
+
+			Ok(().into())
+		}
+
+		/// Allow `delegate` to revoke a claim the caller owns, next to its owners and operator.
+		/// The delegates are cleared when the claim is transferred.
+		#[pallet::weight(T::WeightInfo::add_revoke_delegate(claim.len() as u32))]
+		pub fn add_revoke_delegate(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			delegate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, _) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			RevokeDelegates::<T>::try_mutate(&key, |delegates| {
+				ensure!(!delegates.contains(&delegate), Error::<T>::DelegateAlreadyExists);
+				delegates.try_push(delegate.clone()).map_err(|_| Error::<T>::TooManyDelegates)
+			})?;
+
+			Self::deposit_event(Event::RevokeDelegateAdded(sender, namespace, claim, delegate));
+
+			Ok(().into())
+		}
+
+		/// Take back the right of `delegate` to revoke a claim the caller owns.
+		#[pallet::weight(T::WeightInfo::remove_revoke_delegate(claim.len() as u32))]
+		pub fn remove_revoke_delegate(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			delegate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, _) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			RevokeDelegates::<T>::try_mutate_exists(&key, |maybe_delegates| {
+				let delegates = maybe_delegates.as_mut().ok_or(Error::<T>::DelegateNotFound)?;
+				let index = delegates
+					.iter()
+					.position(|existing| existing == &delegate)
+					.ok_or(Error::<T>::DelegateNotFound)?;
+				delegates.remove(index);
+				// 列表为空时删除整个存储项
+				if delegates.is_empty() {
+					*maybe_delegates = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::RevokeDelegateRemoved(sender, namespace, claim, delegate));
+
+			Ok(().into())
+		}
+
+		/// Remove up to `limit` of the caller's claims that have expired but are still waiting
+		/// for `on_initialize`, refunding their deposits. Charged for `limit` removals and a scan
+		/// of the whole `ClaimsByOwner` entry up front; the difference is refunded.
+		#[pallet::weight(
+			T::WeightInfo::revoke_my_expired((*limit).min(T::MaxClaimsPerAccount::get()))
+				.saturating_add(T::DbWeight::get().reads(T::MaxClaimsPerAccount::get() as u64))
+		)]
+		pub fn revoke_my_expired(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 通过所有者索引查找过期的存证，跳过被冻结的存证，达到数量上限时停止
+			let mut removed = 0u32;
+			let mut scanned = 0u64;
+			for key in ClaimsByOwner::<T>::get(&sender) {
+				if removed >= limit {
+					break
+				}
+				scanned += 1;
+				if let Some(info) = Proofs::<T>::get(&key).filter(|info| {
+					Self::is_expired(info.expiry) && !FrozenClaims::<T>::contains_key(&key)
+				}) {
+					Self::remove_claim(&key, &info);
+					removed += 1;
+					let (namespace, claim) = key;
+					Self::deposit_event(Event::ClaimExpired(
+						namespace.into_inner(),
+						claim.into(),
+						Self::next_event_index(),
+					));
+				}
+			}
+
+			Self::deposit_event(Event::ExpiredClaimsRevoked(sender, removed));
+
+			// 按实际删除和读取的存证数量返还多收取的权重
+			let actual_weight = T::WeightInfo::revoke_my_expired(removed)
+				.saturating_add(T::DbWeight::get().reads(scanned.saturating_sub(removed as u64)));
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Transfer a claim to `dest` and replace its metadata in the same call, so the new owner
+		/// never holds the claim with the old metadata. Charged for a claim of `MaxClaimLength`
+		/// and metadata of `MaxMetadataLength` up front; the difference is refunded.
+		#[pallet::weight(T::WeightInfo::transfer_claim_with_metadata(
+			T::MaxClaimLength::get(),
+			T::MaxMetadataLength::get(),
+		))]
+		pub fn transfer_claim_with_metadata(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			dest: T::AccountId,
+			metadata: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			let actual_weight = T::WeightInfo::transfer_claim_with_metadata(
+				claim.len() as u32,
+				metadata.len() as u32,
+			);
+			let metadata = Self::bounded_metadata(metadata)?;
+			let (key, mut info) = Self::claim_to_transfer(&sender, &namespace, &claim, &dest)?;
+
+			// 元数据随转移一起写入，转移检查失败时整个调用回滚
+			info.metadata = metadata;
+			Self::do_transfer(&key, info, dest)?;
+
+			Self::deposit_event(Event::ClaimMetadataSet(sender, namespace, claim));
+
+			// 按实际的存证和元数据长度返还多收取的权重
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Let `who` own up to `quota` claims instead of `DefaultAccountQuota`, or go back to the
+		/// default with `None`. Claims it already owns beyond a lowered quota are kept.
+		#[pallet::weight(T::WeightInfo::set_account_quota())]
+		pub fn set_account_quota(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			quota: Option<u32>,
+		) -> DispatchResultWithPostInfo {
+			// 验证配额管理权限
+			T::QuotaOrigin::ensure_origin(origin)?;
+
+			// 配额不能超过所有者索引的存储上限
+			ensure!(
+				quota.map_or(true, |quota| quota <= T::MaxClaimsPerAccount::get()),
+				Error::<T>::QuotaTooHigh
+			);
+			AccountQuota::<T>::set(&who, quota);
+
+			Self::deposit_event(Event::AccountQuotaSet(who, quota));
+
+			Ok(().into())
+		}
+
+		/// Revoke a claim created with [`Pallet::create_hashed_claim`] by its stored hash, so the
+		/// full claim does not have to be sent again.
+		#[pallet::weight(T::WeightInfo::revoke_claim_by_hash())]
+		pub fn revoke_claim_by_hash(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			Self::do_revoke_hashed_claim(&sender, namespace, hash)?;
+
+			Ok(().into())
+		}
+
+		/// Freeze a claim while a dispute is ongoing, or unfreeze it. A frozen claim cannot be
+		/// transferred, revoked, renewed or renounced, and neither its metadata, owners,
+		/// approval nor delegates can change. Only `FreezeOrigin` may freeze a claim, as an
+		/// owner that is party to the dispute would otherwise lift the freeze itself. Expiry and
+		/// [`Pallet::force_revoke`] still remove a frozen claim.
+		#[pallet::weight(T::WeightInfo::set_frozen(claim.len() as u32))]
+		pub fn set_frozen(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			frozen: bool,
+		) -> DispatchResultWithPostInfo {
+			// 验证冻结权限
+			T::FreezeOrigin::ensure_origin(origin)?;
+
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(&namespace, &claim)?;
+
+			// 校验是否已经存在存证
+			ensure!(Proofs::<T>::contains_key(&key), Error::<T>::ClaimNotExist);
+
+			if frozen {
+				FrozenClaims::<T>::insert(&key, ());
+			} else {
+				FrozenClaims::<T>::remove(&key);
+			}
+
+			Self::deposit_event(Event::ClaimFrozenSet(namespace, claim, frozen));
+
+			Ok(().into())
+		}
+
+		/// Create a claim unless the caller already owns it, so a client can safely retry a
+		/// creation whose outcome it did not see. An existing claim of the caller is left
+		/// untouched and reported with [`Event::ClaimAlreadyPresent`]; an existing claim of
+		/// another account fails with [`Error::NotClaimOwner`]. Charged for a claim of
+		/// `MaxClaimLength` up front; the difference is refunded for a shorter claim.
+		#[pallet::weight(T::WeightInfo::create_claim_if_absent(T::MaxClaimLength::get()))]
+		pub fn create_claim_if_absent(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证创建权限，解析出的账户成为存证的所有者
+			let sender = T::CreateOrigin::ensure_origin(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 启用白名单时只有白名单中的账户可以创建存证
+			Self::ensure_allowed_creator(&sender)?;
+
+			let actual_weight = T::WeightInfo::create_claim_if_absent(claim.len() as u32);
+			let bounded_namespace = Self::bounded_namespace(&namespace)?;
+
+			// 存证已经存在时，属于当前用户则不做任何修改，否则拒绝
+			if let Ok(bounded_claim) = Self::bounded_claim(&claim) {
+				if let Some(info) = Proofs::<T>::get((bounded_namespace.clone(), bounded_claim)) {
+					ensure!(info.is_owner(&sender), Error::<T>::NotClaimOwner);
+					Self::deposit_event(Event::ClaimAlreadyPresent(sender, namespace, claim));
+					return Ok(Some(actual_weight).into())
+				}
+			}
+
+			Self::do_create_claim(
+				&sender,
+				bounded_namespace,
+				claim,
+				Default::default(),
+				true,
+				None,
+				None,
+				None,
+			)?;
+
+			// 按实际的存证长度返还多收取的权重
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Replace one of the caller's claims with `new_claim` in the same namespace, e.g. the
+		/// hash of a corrected document. The new claim keeps the owners, creation block and time,
+		/// expiry, metadata and ownership history of the old one. The old claim is removed, its
+		/// deposit refunded, and it is left as an alias that [`Pallet::resolve_claim`] follows.
+		/// The caller pays the deposit of the new claim. Fails with
+		/// [`Error::ProofAlreadyExistOwnedBySelf`] or [`Error::ProofAlreadyExistOwnedByOther`]
+		/// if `new_claim` is already stored. Charged for a new claim
+		/// of `MaxClaimLength` up front; the difference is refunded.
+		#[pallet::weight(T::WeightInfo::supersede_claim(T::MaxClaimLength::get()))]
+		pub fn supersede_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			old_claim: Vec<u8>,
+			new_claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证旧存证存在且未过期，并且当前用户是存证的所有者之一
+			let (old_key, info) = Self::ensure_owner(&sender, &namespace, &old_claim)?;
+			Self::do_supersede(&sender, old_key, info, &new_claim)?;
+
+			let actual_weight = T::WeightInfo::supersede_claim(new_claim.len() as u32);
+			Self::deposit_event(Event::ClaimSuperseded(
+				sender,
+				namespace,
+				old_claim,
+				new_claim,
+				Self::next_event_index(),
+			));
+
+			// 按实际的新存证长度返还多收取的权重
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Supersede one of the caller's claims like [`Pallet::supersede_claim`], with the new
+		/// claim derived from the old one by `patch` instead of uploaded in full; see
+		/// [`crate::patch`] for the format. The patch is at most `MaxClaimLength` bytes, and so
+		/// is the claim it produces. Charged for a new claim of `MaxClaimLength` up front; the
+		/// difference is refunded.
+		#[pallet::weight(T::WeightInfo::patch_claim(T::MaxClaimLength::get()))]
+		pub fn patch_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			patch: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			ensure!(patch.len() as u32 <= T::MaxClaimLength::get(), Error::<T>::PatchTooLong);
+
+			// 验证旧存证存在且未过期，并且当前用户是存证的所有者之一
+			let (old_key, info) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			// 由旧存证和补丁计算出新存证
+			let new_claim = patch::apply(&claim, &patch, T::MaxClaimLength::get()).map_err(
+				|error| match error {
+					PatchError::Invalid => Error::<T>::InvalidPatch,
+					PatchError::ResultTooLong => Error::<T>::PatchResultTooLong,
+				},
+			)?;
+			Self::do_supersede(&sender, old_key, info, &new_claim)?;
+
+			let actual_weight = T::WeightInfo::patch_claim(new_claim.len() as u32);
+			Self::deposit_event(Event::ClaimPatched(
+				sender,
+				namespace,
+				claim,
+				new_claim,
+				Self::next_event_index(),
+			));
+
+			// 按实际的新存证长度返还多收取的权重
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Allow `who` to take over a claim the caller owns with [`Pallet::claim_transfer`],
+		/// until the claim is transferred.
+		#[pallet::weight(T::WeightInfo::allow_transfer_to(claim.len() as u32))]
+		pub fn allow_transfer_to(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, _) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			TransferAllowList::<T>::try_mutate(&key, |allowed| {
+				ensure!(!allowed.contains(&who), Error::<T>::AlreadyAllowed);
+				allowed.try_push(who.clone()).map_err(|_| Error::<T>::TooManyAllowed)
+			})?;
+
+			Self::deposit_event(Event::TransferAllowed(sender, namespace, claim, who));
+
+			Ok(().into())
+		}
+
+		/// Take `who` off the transfer allow-list of a claim the caller owns.
+		#[pallet::weight(T::WeightInfo::disallow_transfer_to(claim.len() as u32))]
+		pub fn disallow_transfer_to(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, _) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			TransferAllowList::<T>::try_mutate_exists(&key, |maybe_allowed| {
+				let allowed = maybe_allowed.as_mut().ok_or(Error::<T>::NotAllowed)?;
+				let index = allowed
+					.iter()
+					.position(|existing| existing == &who)
+					.ok_or(Error::<T>::NotAllowed)?;
+				allowed.remove(index);
+				// 列表为空时删除整个存储项
+				if allowed.is_empty() {
+					*maybe_allowed = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::TransferDisallowed(sender, namespace, claim, who));
+
+			Ok(().into())
+		}
+
+		/// Take ownership of a claim whose owner put the caller on its transfer allow-list.
+		#[pallet::weight(T::WeightInfo::claim_transfer(claim.len() as u32))]
+		pub fn claim_transfer(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(&namespace, &claim)?;
+
+			// 验证当前用户在存证的转移白名单中
+			ensure!(TransferAllowList::<T>::get(&key).contains(&sender), Error::<T>::NotAllowed);
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 验证存证是否已经过期
+			ensure!(!Self::is_expired(info.expiry), Error::<T>::ClaimExpired);
+
+			// 冻结期间不允许领取存证
+			Self::ensure_not_frozen(&key)?;
+
+			// 共同所有者领取存证不会增加新的所有者
+			ensure!(!info.is_owner(&sender), Error::<T>::CannotTransferToSelf);
+
+			Self::do_transfer(&key, info, sender)?;
+
+			Ok(().into())
+		}
+
+		/// Remove a claim regardless of its owner like [`Pallet::force_revoke`], but slash its
+		/// deposit through `Deposits` instead of refunding it. Only callable by root. Operational like
+		/// [`Pallet::force_revoke`].
+		#[pallet::weight((
+			T::WeightInfo::force_revoke_and_slash(claim.len() as u32),
+			DispatchClass::Operational
+		))]
+		pub fn force_revoke_and_slash(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证是否为 root 权限
+			ensure_root(origin)?;
+
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(&namespace, &claim)?;
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 从存储里删除存证，押金保持保留状态以便罚没
+			Self::clear_claim(&key, &info);
+
+			// 罚没押金，不退还给存入者
+			let amount = T::Deposits::slash_reserved(&info.depositor, info.deposit);
+
+			Self::deposit_event(Event::ClaimForceRevoked(
+				namespace,
+				claim,
+				RevokeReason::UNSPECIFIED,
+				Self::next_event_index(),
+			));
+			Self::deposit_event(Event::ClaimSlashed(info.depositor, amount));
+
+			Ok(().into())
+		}
+
+		/// Transfer up to `limit` of the claims the caller owns to `dest`, for example when moving
+		/// to a new key. Claims that are expired, frozen, not transferable, still locked or
+		/// already owned by `dest` are skipped rather than failing the call. Charged for `limit`
+		/// transfers and a scan of the whole `ClaimsByOwner` entry up front; the difference is
+		/// refunded.
+		#[pallet::weight(
+			T::WeightInfo::transfer_all_claims((*limit).min(T::MaxClaimsPerAccount::get()))
+				.saturating_add(T::DbWeight::get().reads(T::MaxClaimsPerAccount::get() as u64))
+		)]
+		pub fn transfer_all_claims(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 转移给自己不会改变所有者
+			ensure!(dest != sender, Error::<T>::CannotTransferToSelf);
+
+			// 开启检查时不允许转移给不存在的账户
+			ensure!(
+				!T::RequireRecipientExists::get()
+					|| frame_system::Pallet::<T>::account_exists(&dest),
+				Error::<T>::RecipientNotFound
+			);
+
+			// 接收方明确拒绝接收存证时不允许转移
+			ensure!(Pallet::<T>::accepts_claims(&dest), Error::<T>::RecipientRefusesClaims);
+
+			// 通过所有者索引逐个转移存证，不能转移的存证被跳过，达到数量上限时停止
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut moved = 0u32;
+			let mut skipped = 0u32;
+			let mut scanned = 0u64;
+			for key in ClaimsByOwner::<T>::get(&sender) {
+				if moved >= limit {
+					break
+				}
+				scanned += 1;
+				let eligible = Proofs::<T>::get(&key).filter(|info| {
+					!Self::is_expired(info.expiry)
+						&& !FrozenClaims::<T>::contains_key(&key)
+						&& !Escrows::<T>::contains_key(&key)
+						&& info.transferable
+						&& now >= info.created_at.saturating_add(T::TransferLockPeriod::get())
+						&& Self::cooldown_over(info, now)
+						&& !info.is_owner(&dest)
+				});
+				let info = match eligible {
+					Some(info) => info,
+					None => {
+						skipped += 1;
+						continue
+					},
+				};
+
+				// 接收方的配额用完时回滚这一次转移并跳过
+				let result =
+					with_transaction(|| match Self::do_transfer(&key, info, dest.clone()) {
+						Ok(()) => TransactionOutcome::Commit(Ok(())),
+						Err(error) => TransactionOutcome::Rollback(Err(error)),
+					});
+				if result.is_ok() {
+					moved += 1;
+				} else {
+					skipped += 1;
+				}
+			}
+
+			Self::deposit_event(Event::ClaimsBulkTransferred(sender, dest, moved, skipped));
+
+			// 按实际转移和读取的存证数量返还多收取的权重
+			let actual_weight = T::WeightInfo::transfer_all_claims(moved)
+				.saturating_add(T::DbWeight::get().reads(scanned.saturating_sub(moved as u64)));
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Register `namespace` to the caller, counting against `MaxNamespacesPerAccount`.
+		///
+		/// Creating the first claim in an unregistered namespace registers it to the creator the
+		/// same way, and fails with [`Error::TooManyNamespaces`] once the creator is at the cap.
+		/// Registration only bounds how many namespaces an account can take: other accounts can
+		/// still create claims in a registered namespace. The empty namespace is never
+		/// registered.
+		#[pallet::weight(T::WeightInfo::register_namespace())]
+		pub fn register_namespace(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 空命名空间由所有账户共享，不能被登记
+			let bounded_namespace = Self::bounded_namespace(&namespace)?;
+			ensure!(!bounded_namespace.is_empty(), Error::<T>::NamespaceReserved);
+			ensure!(
+				!NamespaceOwners::<T>::contains_key(&bounded_namespace),
+				Error::<T>::NamespaceTaken
+			);
+
+			Self::do_register_namespace(&sender, &bounded_namespace)?;
+
+			Self::deposit_event(Event::NamespaceRegistered(sender, namespace));
+
+			Ok(().into())
+		}
+
+		/// Give up a namespace registered to the caller, freeing a slot of
+		/// `MaxNamespacesPerAccount`. The claims in the namespace are kept, and the next account
+		/// creating a claim in it registers it.
+		#[pallet::weight(T::WeightInfo::release_namespace())]
+		pub fn release_namespace(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 只有登记者可以释放命名空间
+			let bounded_namespace = Self::bounded_namespace(&namespace)?;
+			ensure!(
+				NamespaceOwners::<T>::get(&bounded_namespace).as_ref() == Some(&sender),
+				Error::<T>::NamespaceNotOwned
+			);
+
+			NamespaceOwners::<T>::remove(&bounded_namespace);
+			NamespacesOwned::<T>::mutate_exists(&sender, |maybe_count| {
+				let count = maybe_count.unwrap_or(0).saturating_sub(1);
+				// 数量为零时删除整个存储项
+				*maybe_count = if count == 0 { None } else { Some(count) };
+			});
+
+			Self::deposit_event(Event::NamespaceReleased(sender, namespace));
+
+			Ok(().into())
+		}
+
+		/// Report `ProofCount` in a [`Event::ProofCountReported`] event, for chains without access
+		/// to the runtime API. Reads a single value, so it is charged only that read, and works
+		/// while paused.
+		#[pallet::weight(T::WeightInfo::emit_proof_count())]
+		pub fn emit_proof_count(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			// 验证签名
+			ensure_signed(origin)?;
+
+			Self::deposit_event(Event::ProofCountReported(ProofCount::<T>::get()));
+
+			Ok(().into())
+		}
+
+		/// Set the display label of one of the caller's claims. The label is not part of the
+		/// claim's identity and is removed together with the claim.
+		#[pallet::weight(T::WeightInfo::set_label(claim.len() as u32))]
+		pub fn set_label(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			label: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			let bounded: Label =
+				BoundedVec::try_from(label.clone()).map_err(|_| Error::<T>::LabelTooLong)?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, _) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			Labels::<T>::insert(&key, bounded);
+
+			Self::deposit_event(Event::ClaimLabelSet(sender, namespace, claim, label));
+
+			Ok(().into())
+		}
+
+		/// Place one of the caller's claims in escrow for `beneficiary`. Until it is released or
+		/// cancelled the claim cannot be transferred, revoked or modified. From `release_block`
+		/// on, anyone may release it to the beneficiary with [`Pallet::release_escrow`]; before
+		/// that, or once the release can no longer succeed, an owner may take it back with
+		/// [`Pallet::cancel_escrow`]. Only owners may escrow a claim, not approved operators.
+		/// `release_block` must be after the current block.
+		#[pallet::weight(T::WeightInfo::escrow_claim(claim.len() as u32))]
+		pub fn escrow_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			beneficiary: T::AccountId,
+			release_block: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 释放区块已经到达的托管可以立即被释放，等同于直接转移
+			ensure!(
+				release_block > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::EscrowReleaseNotInFuture
+			);
+
+			// 与转移相同的检查，确保受益人可以接收存证
+			let (key, info) = Self::claim_to_transfer(&sender, &namespace, &claim, &beneficiary)?;
+			ensure!(info.transferable, Error::<T>::ClaimNotTransferable);
+
+			// 托管会长期限制存证，只有所有者可以发起，被授权的操作者不行
+			ensure!(info.is_owner(&sender), Error::<T>::NotClaimOwner);
+
+			Escrows::<T>::insert(&key, (beneficiary.clone(), release_block));
+
+			Self::deposit_event(Event::ClaimEscrowed(
+				sender,
+				namespace,
+				claim,
+				beneficiary,
+				release_block,
+			));
+
+			Ok(().into())
+		}
+
+		/// Transfer an escrowed claim to its beneficiary once its release block is reached. May be
+		/// called by anyone.
+		#[pallet::weight(T::WeightInfo::release_escrow(claim.len() as u32))]
+		pub fn release_escrow(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(&namespace, &claim)?;
+
+			// 验证存证在托管中，并且已经到达释放区块
+			let (beneficiary, release_block) =
+				Escrows::<T>::get(&key).ok_or(Error::<T>::NotInEscrow)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= release_block,
+				Error::<T>::EscrowNotDue
+			);
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 验证存证是否已经过期
+			ensure!(!Self::is_expired(info.expiry), Error::<T>::ClaimExpired);
+
+			// 争议冻结优先于托管
+			ensure!(!FrozenClaims::<T>::contains_key(&key), Error::<T>::ClaimFrozen);
+
+			Escrows::<T>::remove(&key);
+			Self::do_transfer(&key, info, beneficiary.clone())?;
+
+			Self::deposit_event(Event::EscrowReleased(namespace, claim, beneficiary));
+
+			Ok(().into())
+		}
+
+		/// Take one of the caller's claims out of escrow before its release block, or after it if
+		/// [`Pallet::release_escrow`] would fail, e.g. because the beneficiary has no room for
+		/// the claim. A frozen claim stays in escrow.
+		#[pallet::weight(
+			T::WeightInfo::cancel_escrow(claim.len() as u32)
+				.max(T::WeightInfo::release_escrow(claim.len() as u32))
+		)]
+		pub fn cancel_escrow(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(&namespace, &claim)?;
+
+			// 验证存证在托管中
+			let (beneficiary, release_block) =
+				Escrows::<T>::get(&key).ok_or(Error::<T>::NotInEscrow)?;
+
+			// 验证当前用户是存证的所有者之一
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+			ensure!(info.is_owner(&sender), Error::<T>::NotClaimOwner);
+
+			// 到达释放区块后，只有释放无法成功时才能取消，否则存证会一直留在托管中
+			if frame_system::Pallet::<T>::block_number() >= release_block {
+				ensure!(!FrozenClaims::<T>::contains_key(&key), Error::<T>::ClaimFrozen);
+				ensure!(!Self::escrow_releasable(&key, info, beneficiary), Error::<T>::EscrowDue);
+			}
+
+			Escrows::<T>::remove(&key);
+
+			Self::deposit_event(Event::EscrowCancelled(sender, namespace, claim));
+
+			Ok(().into())
+		}
+
+		/// Create a claim with the defaults of [`Pallet::create_claim`], reporting a failure in a
+		/// [`Event::ClaimFailed`] event with a stable error code instead of failing the call.
+		/// Meant for callers on other chains over XCM, which only learn the outcome from events
+		/// and cannot decode the pallet's error indices. Only the origin check can still fail.
+		#[pallet::weight(T::WeightInfo::create_claim(
+			T::MaxClaimLength::get(),
+			T::MaxClaimsPerAccount::get(),
+		))]
+		pub fn try_create_claim(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证创建权限，解析出的账户成为存证的所有者
+			let sender = T::CreateOrigin::ensure_origin(origin)?;
+
+			// 失败时回滚所有写入，只发送带有错误编码的事件
+			let result = with_transaction(|| {
+				let create = || -> DispatchResult {
+					Self::ensure_not_paused()?;
+					Self::ensure_allowed_creator(&sender)?;
+					let namespace = Self::bounded_namespace(&namespace)?;
+					Self::do_create_claim(
+						&sender,
+						namespace,
+						claim,
+						Default::default(),
+						true,
+						None,
+						None,
+						None,
+					)
+				};
+				match create() {
+					Ok(()) => TransactionOutcome::Commit(Ok(())),
+					Err(error) => TransactionOutcome::Rollback(Err(error)),
+				}
+			});
+			if let Err(error) = result {
+				let code = Self::dispatch_error_code(error);
+				Self::deposit_event(Event::ClaimFailed(sender, code));
+			}
+
+			Ok(().into())
+		}
+
+		/// Swap `my_claim` for `their_claim` of `counterparty`, both within `namespace`. The
+		/// first call records an offer; the matching call of `counterparty` executes it, moving
+		/// `my_claim` of the offer to `counterparty` and `their_claim` to the offerer in one
+		/// transaction. The offerer must be able to transfer `my_claim` when offering; ownership
+		/// and transferability of both claims are checked again when the swap executes. An offer reserves `SwapOfferDeposit` from the
+		/// offerer until it executes, is withdrawn with [`Pallet::cancel_swap_offer`], or is
+		/// dropped because either claim changed hands or was removed. Charged for swapping claims
+		/// of `MaxClaimLength` up front; the difference is refunded for an offer or shorter
+		/// claims.
+		#[pallet::weight(T::WeightInfo::swap_claims(T::MaxClaimLength::get()))]
+		pub fn swap_claims(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			my_claim: Vec<u8>,
+			their_claim: Vec<u8>,
+			counterparty: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 将命名空间和存证转换为存储键，按规范化后的键判断是否是同一个存证
+			let my_key = Self::claim_key(&namespace, &my_claim)?;
+			let their_key = Self::claim_key(&namespace, &their_claim)?;
+			ensure!(sender != counterparty && my_key != their_key, Error::<T>::InvalidSwap);
+
+			// 交易对手没有对应的报价时记录报价，等待对方确认
+			let offer = (their_key.clone(), my_key.clone());
+			let matches = SwapOffers::<T>::get(&offer).map_or(false, |(offerer, expected, _)| {
+				offerer == counterparty && expected == sender
+			});
+			if !matches {
+				let actual_weight = T::WeightInfo::offer_swap(my_claim.len() as u32);
+				// 只能用自己可以转移的存证报价，对方的存证在执行时检查
+				Self::claim_to_transfer(&sender, &namespace, &my_claim, &counterparty)?;
+				Self::add_swap_offer((my_key, their_key), &sender, &counterparty)?;
+				Self::deposit_event(Event::SwapOffered(
+					sender,
+					counterparty,
+					namespace,
+					my_claim,
+					their_claim,
+				));
+				return Ok(Some(actual_weight).into())
+			}
+
+			// 执行时再验证双方仍然持有并可以转移各自的存证
+			let (_, my_info) =
+				Self::claim_to_transfer(&sender, &namespace, &my_claim, &counterparty)?;
+			let (_, their_info) =
+				Self::claim_to_transfer(&counterparty, &namespace, &their_claim, &sender)?;
+
+			Self::remove_swap_offer(&offer);
+			Self::do_transfer(&offer.1, my_info, counterparty.clone())?;
+			Self::do_transfer(&offer.0, their_info, sender.clone())?;
+
+			// 按实际的存证长度返还多收取的权重
+			let actual_weight =
+				T::WeightInfo::swap_claims(my_claim.len().max(their_claim.len()) as u32);
+
+			Self::deposit_event(Event::ClaimsSwapped(
+				sender,
+				counterparty,
+				namespace,
+				my_claim,
+				their_claim,
+			));
+
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Transfer several claims, each to its own recipient. Every claim is checked before any
+		/// of them moves, so either all of them are transferred or none is. A claim may appear
+		/// only once in the batch.
+		#[pallet::weight(T::WeightInfo::transfer_many(transfers.len() as u32))]
+		pub fn transfer_many(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			transfers: Vec<(Vec<u8>, T::AccountId)>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证批量数量上限
+			ensure!(transfers.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+			// 先验证全部存证，再统一转移
+			let mut moves: Vec<(ClaimKeyOf<T>, ClaimInfo<T>, T::AccountId)> =
+				Vec::with_capacity(transfers.len());
+			for (claim, dest) in transfers {
+				let (key, info) = Self::claim_to_transfer(&sender, &namespace, &claim, &dest)?;
+				ensure!(info.transferable, Error::<T>::ClaimNotTransferable);
+				ensure!(
+					!moves.iter().any(|(moved, _, _)| *moved == key),
+					Error::<T>::DuplicateClaim
+				);
+				moves.push((key, info, dest));
+			}
+
+			// 锁定期等检查在转移时进行，失败时整个调用的存储修改都会被回滚
+			for (key, info, dest) in moves {
+				Self::do_transfer(&key, info, dest)?;
+			}
+
+			Ok(().into())
+		}
+
+		/// Make one of the caller's claims private, or public again. A private claim is only
+		/// returned by the `get_claim` and `claim_status` runtime APIs when they are queried on
+		/// behalf of one of its owners or viewers, and is left out of claim listings. This only
+		/// hides the claim from the APIs: its storage can still be read by anyone.
+		#[pallet::weight(T::WeightInfo::set_private(claim.len() as u32))]
+		pub fn set_private(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			private: bool,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, mut info) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			info.private = private;
+			Proofs::<T>::insert(&key, info);
+
+			Self::deposit_event(Event::ClaimPrivacySet(sender, namespace, claim, private));
+
+			Ok(().into())
+		}
+
+		/// Let `viewer` see one of the caller's claims through the runtime APIs while it is
+		/// private. Viewers are removed when the claim changes hands or is removed.
+		#[pallet::weight(T::WeightInfo::add_viewer(claim.len() as u32))]
+		pub fn add_viewer(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, _) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			Viewers::<T>::try_mutate(&key, |viewers| -> DispatchResult {
+				ensure!(!viewers.contains(&viewer), Error::<T>::AlreadyViewer);
+				viewers.try_push(viewer.clone()).map_err(|_| Error::<T>::TooManyViewers)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ViewerAdded(sender, namespace, claim, viewer));
+
+			Ok(().into())
+		}
+
+		/// Stop letting `viewer` see one of the caller's claims while it is private.
+		#[pallet::weight(T::WeightInfo::remove_viewer(claim.len() as u32))]
+		pub fn remove_viewer(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 验证存证存在且未过期，并且当前用户是存证的所有者之一
+			let (key, _) = Self::ensure_owner(&sender, &namespace, &claim)?;
+
+			Viewers::<T>::try_mutate_exists(&key, |viewers| -> DispatchResult {
+				let list = viewers.as_mut().ok_or(Error::<T>::NotViewer)?;
+				let index = list.iter().position(|v| *v == viewer).ok_or(Error::<T>::NotViewer)?;
+				list.remove(index);
+				// 最后一个查看者被移除时删除整个条目
+				if list.is_empty() {
+					*viewers = None;
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ViewerRemoved(sender, namespace, claim, viewer));
+
+			Ok(().into())
+		}
+
+		/// Choose whether the caller accepts claims transferred to it. Transfers to an account
+		/// that refuses claims fail; claims it asked for, with [`Pallet::accept_transfer`] or
+		/// [`Pallet::claim_transfer`], still reach it.
+		#[pallet::weight(T::WeightInfo::set_accepts_claims())]
+		pub fn set_accepts_claims(
+			origin: OriginFor<T>,
+			accepts: bool,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			AcceptsClaims::<T>::insert(&sender, accepts);
+
+			Self::deposit_event(Event::AcceptsClaimsSet(sender, accepts));
+
+			Ok(().into())
+		}
+
+		/// Withdraw the caller's offer to swap `my_claim` for `their_claim`, both within
+		/// `namespace`, and get its deposit back.
+		#[pallet::weight(T::WeightInfo::cancel_swap_offer())]
+		pub fn cancel_swap_offer(
+			origin: OriginFor<T>,
+			namespace: Vec<u8>,
+			my_claim: Vec<u8>,
+			their_claim: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// 验证签名
+			let sender = ensure_signed(origin)?;
+
+			// 暂停期间拒绝操作，放在所有存储读取之前
+			Self::ensure_not_paused()?;
+
+			// 只有报价人可以撤销自己的报价
+			let offer = (
+				Self::claim_key(&namespace, &my_claim)?,
+				Self::claim_key(&namespace, &their_claim)?,
+			);
+			ensure!(
+				SwapOffers::<T>::get(&offer).map_or(false, |(offerer, _, _)| offerer == sender),
+				Error::<T>::NoSwapOffer
+			);
+			Self::remove_swap_offer(&offer);
+
+			Self::deposit_event(Event::SwapOfferCancelled(
+				sender,
+				namespace,
+				my_claim,
+				their_claim,
+			));
+
+			Ok(().into())
+		}
+
+		/// Pause or unpause every signed call of the pallet. Works while paused. Operational, so
+		/// an incident can be contained even while blocks are full of normal transactions.
+		#[pallet::weight((T::WeightInfo::set_paused(), DispatchClass::Operational))]
+		pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResultWithPostInfo {
+			// 验证暂停权限
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			Paused::<T>::put(paused);
+
+			Self::deposit_event(if paused { Event::Paused } else { Event::Unpaused });
+
+			Ok(().into())
+		}
+
+		/// Record that a claim expires within `ExpiryWarningWindow` blocks. Submitted by the
+		/// offchain worker as an unsigned transaction.
+		#[pallet::weight(T::WeightInfo::report_expiring_soon())]
+		pub fn report_expiring_soon(
+			origin: OriginFor<T>,
+			key: ClaimKeyOf<T>,
+			expiry: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			// 只接受无签名交易
+			ensure_none(origin)?;
+
+			Self::ensure_expiring_soon(&key, expiry)?;
+
+			Self::deposit_event(Event::ClaimExpiringSoon(key.0.to_vec(), key.1.into(), expiry));
+
+			Ok(().into())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (key, expiry) = match call {
+				Call::report_expiring_soon { key, expiry } => (key, *expiry),
+				_ => return InvalidTransaction::Call.into(),
+			};
+
+			// 只接受本节点 offchain worker 提交的交易，以及区块中已包含的交易
+			if !matches!(source, TransactionSource::Local | TransactionSource::InBlock) {
+				return InvalidTransaction::Call.into()
+			}
+
+			Self::ensure_expiring_soon(key, expiry).map_err(|_| InvalidTransaction::Stale)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ValidTransaction::with_tag_prefix("PoeExpiringSoon")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((key, expiry))
+				.longevity(expiry.saturating_sub(now).unique_saturated_into())
+				.propagate(false)
+				.build()
+		}
+	}
+
+	/// Wired as `frame_system::Config::OnKilledAccount`, so that the claims of a reaped account
+	/// do not stay owned by a dead key. The account is dropped from claims it owns jointly;
+	/// claims it owns alone are deleted, leaving a tombstone and refunding the deposit to its
+	/// depositor. Its hashed claims are deleted the same way, without a tombstone as only their
+	/// hash is known. Bounded by `MaxClaimsPerAccount`, the size of both owner indexes.
+	impl<T: Config> OnKilledAccount<T::AccountId> for Pallet<T> {
+		fn on_killed_account(who: &T::AccountId) {
+			let now = frame_system::Pallet::<T>::block_number();
+			for key in ClaimsByOwner::<T>::take(who) {
+				let mut info = match Proofs::<T>::get(&key) {
+					Some(info) => info,
+					None => continue,
+				};
+
+				// 共同持有的存证只移除该所有者
+				if info.owners.len() > 1 {
+					info.owners.retain(|owner| owner != who);
+					Proofs::<T>::insert(&key, info);
+					continue
+				}
+
+				// 独自持有的存证删除并留下墓碑
+				Self::remove_claim(&key, &info);
+				Self::bury(&key, now);
+				Self::deposit_event(Event::ClaimReaped(
+					who.clone(),
+					key.0.into_inner(),
+					key.1.into(),
+					Self::next_event_index(),
+				));
+			}
+
+			// 哈希存证只有一个所有者，直接删除并退还押金
+			for key in HashedClaimsByOwner::<T>::take(who) {
+				if let Some(info) = HashedProofs::<T>::get(&key) {
+					Self::remove_hashed_claim(&key, &info);
+					Self::deposit_event(Event::HashedClaimReaped(
+						who.clone(),
+						key.0.into_inner(),
+						key.1,
+						Self::next_event_index(),
+					));
+				}
+			}
+		}
+	}
+
+	/// The prefix of the offchain storage entries that remember which claims were reported.
+	const EXPIRY_WARNING_PREFIX: &[u8] = b"pallet-poe::expiring-soon";
+
+	/// The domain separator hashed into every [`Pallet::ownership_challenge`].
+	pub const OWNERSHIP_CHALLENGE_CONTEXT: &[u8] = b"pallet-poe::ownership";
+
+	impl<T: Config> Pallet<T> {
+		/// Fail with [`Error::NotExpiringSoon`] unless the claim expires at `expiry` and within
+		/// `ExpiryWarningWindow` blocks from now.
+		fn ensure_expiring_soon(key: &ClaimKeyOf<T>, expiry: T::BlockNumber) -> DispatchResult {
+			let info = Proofs::<T>::get(key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			ensure!(info.expiry == Some(expiry), Error::<T>::NotExpiringSoon);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				expiry > now && expiry <= now.saturating_add(T::ExpiryWarningWindow::get()),
+				Error::<T>::NotExpiringSoon
+			);
+			Ok(())
+		}
+
+		/// Submit a `report_expiring_soon` transaction for every claim that expires within
+		/// `ExpiryWarningWindow` blocks after `now` and was not reported by this node yet.
+		fn report_expiring_claims(now: T::BlockNumber) {
+			let window_end = now.saturating_add(T::ExpiryWarningWindow::get());
+			let mut at = now;
+			while at < window_end {
+				at = at.saturating_add(One::one());
+
+				for key in ExpiringAt::<T>::get(at).unwrap_or_default() {
+					// 在本地存储中记录已上报的到期区块，避免重复提交同一告警
+					let storage_key = (EXPIRY_WARNING_PREFIX, &key).encode();
+					let mut reported = StorageValueRef::persistent(&storage_key);
+					let fresh = reported.mutate(
+						|last: Result<Option<T::BlockNumber>, StorageRetrievalError>| match last {
+							Ok(Some(expiry)) if expiry == at => Err(()),
+							_ => Ok(at),
+						},
+					);
+					if fresh.is_err() {
+						continue
+					}
+
+					let call = Call::report_expiring_soon { key, expiry: at };
+					if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+						.is_err()
+					{
+						// 提交失败时清除记录，下一个区块重试
+						reported.clear();
+					}
+				}
+			}
+		}
+
+		/// Fail with [`Error::NotAuthorized`] unless `who` owns the claim or is its approved
+		/// operator.
+		fn ensure_owner_or_operator(
+			who: &T::AccountId,
+			key: &ClaimKeyOf<T>,
+			info: &ClaimInfo<T>,
+		) -> DispatchResult {
+			ensure!(
+				info.is_owner(who) || ClaimApprovals::<T>::get(key).as_ref() == Some(who),
+				Error::<T>::NotAuthorized
+			);
+			Ok(())
+		}
+
+		/// Fail with [`Error::NotWhitelisted`] if the whitelist is enforced and `who` is not on it.
+		fn ensure_allowed_creator(who: &T::AccountId) -> DispatchResult {
+			ensure!(
+				!T::EnforceCreatorWhitelist::get() || AllowedCreators::<T>::contains_key(who),
+				Error::<T>::NotWhitelisted
+			);
+			Ok(())
+		}
+
+		/// Fail if `key` is already stored, telling apart whether `who` is one of its owners.
+		fn ensure_not_stored(who: &T::AccountId, key: &ClaimKeyOf<T>) -> Result<(), Error<T>> {
+			match Proofs::<T>::get(key) {
+				Some(info) => Err(Self::already_exist_error(who, &info)),
+				None => Ok(()),
+			}
+		}
+
+		/// The error for `who` creating a claim that is already stored with `info`.
+		fn already_exist_error(who: &T::AccountId, info: &ClaimInfo<T>) -> Error<T> {
+			if info.is_owner(who) {
+				Error::<T>::ProofAlreadyExistOwnedBySelf
+			} else {
+				Error::<T>::ProofAlreadyExistOwnedByOther
+			}
+		}
+
+		/// Fail with [`Error::InvalidUtf8`] if `RequireUtf8` is set and `claim` is not UTF-8.
+		fn ensure_utf8(claim: &[u8]) -> Result<(), Error<T>> {
+			ensure!(
+				!T::RequireUtf8::get() || core::str::from_utf8(claim).is_ok(),
+				Error::<T>::InvalidUtf8
+			);
+			Ok(())
+		}
+
+		/// Fail with [`Error::Paused`] while the pallet is paused, or while
+		/// [`migrations::MigrateToV3`] is still moving claims.
+		fn ensure_not_paused() -> DispatchResult {
+			ensure!(!Paused::<T>::get(), Error::<T>::Paused);
+			ensure!(!MigrationCursor::<T>::exists(), Error::<T>::Paused);
+			Ok(())
+		}
+
+		/// The longest claim, in bytes, the pallet accepts. Same as the `MaxClaimLength`
+		/// constant in the metadata.
+		pub fn max_claim_length() -> u32 {
+			T::MaxClaimLength::get()
+		}
+
+		/// The namespace used by applications that do not pick one. Claims stored before
+		/// namespaces were introduced live here.
+		pub fn default_namespace() -> NamespaceOf<T> {
+			Default::default()
+		}
+
+		/// Bound a namespace to `MaxNamespaceLength`.
+		pub fn bounded_namespace(namespace: &[u8]) -> Result<NamespaceOf<T>, Error<T>> {
+			NamespaceOf::<T>::try_from(namespace.to_vec()).map_err(|_| Error::<T>::NamespaceTooLong)
+		}
+
+		/// Bound claim metadata to `MaxMetadataLength`.
+		fn bounded_metadata(
+			metadata: Vec<u8>,
+		) -> Result<BoundedVec<u8, T::MaxMetadataLength>, Error<T>> {
+			BoundedVec::try_from(metadata).map_err(|_| Error::<T>::MetadataTooLong)
+		}
+
+		/// Convert a content type into its bounded form.
+		fn bounded_content_type(content_type: Vec<u8>) -> Result<ContentType, Error<T>> {
+			BoundedVec::try_from(content_type).map_err(|_| Error::<T>::ContentTypeTooLong)
+		}
+
+		/// Convert claim bytes of at most `MaxClaimLength` into `ClaimData`, normalised like
+		/// [`Pallet::normalized_claim`].
+		pub fn bounded_claim(claim: &[u8]) -> Result<ClaimOf<T>, Error<T>> {
+			let claim = Self::normalized_claim(claim);
+			ensure!(claim.len() as u32 <= T::MaxClaimLength::get(), Error::<T>::ClaimTooLong);
+			ClaimOf::<T>::try_from(claim).map_err(|_| Error::<T>::InvalidClaim)
+		}
+
+		/// The bytes `claim` is keyed and hashed by: lower-cased if `CaseInsensitiveClaims` and
+		/// `RequireUtf8` are both set and it is UTF-8, unchanged otherwise.
+		pub fn normalized_claim(claim: &[u8]) -> Vec<u8> {
+			if T::CaseInsensitiveClaims::get() && T::RequireUtf8::get() {
+				if let Ok(text) = core::str::from_utf8(claim) {
+					return text.to_lowercase().into_bytes()
+				}
+			}
+			claim.to_vec()
+		}
+
+		/// The key and the stored state of the unexpired `claim` within `namespace`, failing
+		/// unless `who` is one of its owners and the claim is not frozen.
+		pub(crate) fn ensure_owner(
+			who: &T::AccountId,
+			namespace: &[u8],
+			claim: &[u8],
+		) -> Result<(ClaimKeyOf<T>, ClaimInfo<T>), Error<T>> {
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(namespace, claim)?;
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 验证存证是否已经过期
+			ensure!(!Self::is_expired(info.expiry), Error::<T>::ClaimExpired);
+
+			// 验证当前用户是否是存证的所有者之一
+			ensure!(info.is_owner(who), Error::<T>::NotClaimOwner);
+
+			// 冻结期间不允许修改存证
+			Self::ensure_not_frozen(&key)?;
+
+			Ok((key, info))
+		}
+
+		/// Fail with [`Error::ClaimFrozen`] while the claim is frozen, and with
+		/// [`Error::ClaimInEscrow`] while it is held in escrow.
+		fn ensure_not_frozen(key: &ClaimKeyOf<T>) -> Result<(), Error<T>> {
+			ensure!(!FrozenClaims::<T>::contains_key(key), Error::<T>::ClaimFrozen);
+			ensure!(!Escrows::<T>::contains_key(key), Error::<T>::ClaimInEscrow);
+			Ok(())
+		}
+
+		/// The storage key of `claim` within `namespace`.
+		pub fn claim_key(namespace: &[u8], claim: &[u8]) -> Result<ClaimKeyOf<T>, Error<T>> {
+			Ok((Self::bounded_namespace(namespace)?, Self::bounded_claim(claim)?))
+		}
+
+		/// The hash under which [`Pallet::create_hashed_claim`] stores `claim`, taken of its
+		/// [`Pallet::normalized_claim`] bytes.
+		pub fn claim_hash(claim: &[u8]) -> T::Hash {
+			T::Hashing::hash(&Self::normalized_claim(claim))
+		}
+
+		/// The key and the stored state of `claim` within `namespace`, unless it is missing or
+		/// expired. Private claims are included.
+		fn unexpired_claim(
+			namespace: &[u8],
+			claim: &[u8],
+		) -> Option<(ClaimKeyOf<T>, ClaimInfo<T>)> {
+			let key = Self::claim_key(namespace, claim).ok()?;
+			Proofs::<T>::get(&key)
+				.filter(|info| !Self::is_expired(info.expiry))
+				.map(|info| (key, info))
+		}
+
+		/// Whether `viewer` may see the claim stored under `key` through the runtime APIs: anyone
+		/// may see a public claim, only its owners and viewers a private one.
+		pub fn can_view(
+			key: &ClaimKeyOf<T>,
+			info: &ClaimInfo<T>,
+			viewer: Option<&T::AccountId>,
+		) -> bool {
+			!info.private
+				|| viewer.map_or(false, |viewer| {
+					info.is_owner(viewer) || Viewers::<T>::get(key).contains(viewer)
+				})
+		}
+
+		/// The first owner and the creation block of `claim` within `namespace`, unless it is
+		/// missing, expired, or private and `viewer` may not see it. Backs the `PoeApi` runtime
+		/// API.
+		pub fn get_claim(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<T::AccountId>,
+		) -> Option<(T::AccountId, T::BlockNumber)> {
+			let (key, info) = Self::unexpired_claim(&namespace, &claim)?;
+			if !Self::can_view(&key, &info, viewer.as_ref()) {
+				return None
+			}
+			Some((info.owners.first()?.clone(), info.created_at))
+		}
+
+		/// Whether `claim` is stored within `namespace` and has not expired. A private claim
+		/// `viewer` may not see is reported as missing.
+		pub fn claim_exists(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<T::AccountId>,
+		) -> bool {
+			Self::unexpired_claim(&namespace, &claim)
+				.map_or(false, |(key, info)| Self::can_view(&key, &info, viewer.as_ref()))
+		}
+
+		/// The owner after the creation and each transfer of `claim` within `namespace`, with
+		/// the block of the change, oldest first. Only the last `MaxHistoryLength` owners are
+		/// kept, so it stays a single bounded read for provenance displays however often the
+		/// claim changed hands. Empty for a private claim `viewer` may not see. Backs the
+		/// `PoeApi` runtime API.
+		pub fn claim_history(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<T::AccountId>,
+		) -> Vec<(T::AccountId, T::BlockNumber)> {
+			let key = match Self::claim_key(&namespace, &claim) {
+				Ok(key) => key,
+				Err(_) => return Vec::new(),
+			};
+			// 私有存证的历史只对所有者和查看者可见
+			if let Some(info) = Proofs::<T>::get(&key) {
+				if !Self::can_view(&key, &info, viewer.as_ref()) {
+					return Vec::new()
+				}
+			}
+			ClaimHistory::<T>::get(key).into_inner()
+		}
+
+		/// The unexpired claim that `claim` within `namespace` resolves to, with its first owner
+		/// and creation block. A stored claim resolves to itself; a claim replaced with
+		/// [`Pallet::supersede_claim`] resolves to its replacement, following up to
+		/// `MAX_SUPERSEDE_HOPS` aliases.
+		pub fn resolve_claim(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> Option<(Vec<u8>, T::AccountId, T::BlockNumber)> {
+			let mut key = Self::claim_key(&namespace, &claim).ok()?;
+			for _ in 0..=MAX_SUPERSEDE_HOPS {
+				if let Some(info) = Proofs::<T>::get(&key) {
+					if Self::is_expired(info.expiry) {
+						return None
+					}
+					return Some((key.1.into(), info.owners.first()?.clone(), info.created_at))
+				}
+				key.1 = Superseded::<T>::get(&key)?;
+			}
+			None
+		}
+
+		/// The chain of parents of `claim` within `namespace`, nearest first, up to `limit`
+		/// claims. The walk stops at a claim without a parent or that is no longer stored; a
+		/// removed parent is still listed, but its own parents are not.
+		pub fn ancestors(namespace: Vec<u8>, claim: Vec<u8>, limit: u32) -> Vec<Vec<u8>> {
+			let mut ancestors = Vec::new();
+			let mut key = match Self::claim_key(&namespace, &claim) {
+				Ok(key) => key,
+				Err(_) => return ancestors,
+			};
+			while (ancestors.len() as u32) < limit {
+				match Proofs::<T>::get(&key).and_then(|info| info.parent) {
+					Some(parent) => {
+						ancestors.push(parent.clone().into());
+						key.1 = parent;
+					},
+					None => break,
+				}
+			}
+			ancestors
+		}
+
+		/// The id `claim` within `namespace` was given when it was created, if it is stored and was
+		/// created after ids were introduced. Ids increase with every claim created, do not
+		/// change when the claim changes hands and are never given to another claim, not even
+		/// after the claim is removed. A claim created again gets a new id.
+		pub fn claim_id(namespace: Vec<u8>, claim: Vec<u8>) -> Option<u64> {
+			let key = Self::claim_key(&namespace, &claim).ok()?;
+			ClaimIds::<T>::get(key)
+		}
+
+		/// The Unix time in milliseconds at which the unexpired `claim` within `namespace` was
+		/// created, if it is stored and the time is known.
+		pub fn claim_moment(namespace: Vec<u8>, claim: Vec<u8>) -> Option<u64> {
+			let key = Self::claim_key(&namespace, &claim).ok()?;
+			Proofs::<T>::get(key)
+				.filter(|info| !Self::is_expired(info.expiry))
+				.and_then(|info| info.created_moment)
+		}
+
+		/// Up to `limit` public claims of any namespace, expired or not, with their first owner
+		/// and creation block, in storage order starting after the raw storage key `start_key`.
+		/// Private claims use up the page without being listed.
+		/// Also returns the cursor to pass as `start_key` for the next page, or `None` once all
+		/// claims were listed. It iterates `Proofs`, so it backs the `PoeApi` runtime API and
+		/// must never be called from a dispatchable.
+		pub fn list_claims(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(ClaimOf<T>, T::AccountId, T::BlockNumber)>, Option<Vec<u8>>) {
+			let mut iter = match start_key {
+				Some(start_key) => Proofs::<T>::iter_from(start_key),
+				None => Proofs::<T>::iter(),
+			};
+			let claims: Vec<_> = iter
+				.by_ref()
+				.take(limit as usize)
+				.filter(|(_, info)| !info.private)
+				.filter_map(|((_, claim), info)| {
+					Some((claim, info.owners.first()?.clone(), info.created_at))
+				})
+				.collect();
+
+			// 只有后面还有存证时才返回下一页的起点
+			let last_key = iter.last_raw_key().to_vec();
+			let cursor = iter.next().map(|_| last_key);
+			(claims, cursor)
+		}
+
+		/// Whether `claim` within `namespace` is missing, active or expired-but-not-purged, with
+		/// its first owner and the blocks left until its expiry. A private claim `viewer` may not
+		/// see is reported as missing. Backs the `PoeApi` runtime API.
+		pub fn claim_status(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<T::AccountId>,
+		) -> ClaimStatus<T::AccountId, T::BlockNumber> {
+			let key = match Self::claim_key(&namespace, &claim) {
+				Ok(key) => key,
+				Err(_) => return ClaimStatus::NotFound,
+			};
+			let info = match Proofs::<T>::get(&key) {
+				Some(info) if Self::can_view(&key, &info, viewer.as_ref()) => info,
+				_ => return ClaimStatus::NotFound,
+			};
+			let owner = match info.owners.first() {
+				Some(owner) => owner.clone(),
+				None => return ClaimStatus::NotFound,
+			};
+			if Self::is_expired(info.expiry) {
+				return ClaimStatus::Expired { owner }
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			ClaimStatus::Active {
+				owner,
+				expires_in: info.expiry.map(|expiry| expiry.saturating_sub(now)),
+			}
+		}
+
+		/// Up to `limit` unexpired public claims of any namespace whose bytes start with `prefix`,
+		/// with their first owner. It iterates `Proofs`, so it backs the `PoeApi` runtime API and
+		/// must never be called from a dispatchable.
+		pub fn claims_with_prefix(prefix: &[u8], limit: u32) -> Vec<(ClaimOf<T>, T::AccountId)> {
+			Proofs::<T>::iter()
+				.filter(|(_, info)| !Self::is_expired(info.expiry) && !info.private)
+				.filter_map(|((_, claim), info)| {
+					let bytes: Vec<u8> = claim.clone().into();
+					if !bytes.starts_with(prefix) {
+						return None
+					}
+					Some((claim, info.owners.first()?.clone()))
+				})
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// A challenge the first owner of `claim` within `namespace` can sign off-chain to prove
+		/// that it owns the claim, unless the claim is missing, expired, or private and `viewer`
+		/// may not see it.
+		///
+		/// The challenge is the `blake2_256` hash of the SCALE encoding of the tuple
+		/// `(OWNERSHIP_CHALLENGE_CONTEXT, namespace, claim_hash, owner, epoch)`, where the
+		/// context and `namespace` are encoded as byte vectors, `claim_hash` is
+		/// [`Pallet::claim_hash`] of the claim bytes, `owner` is the first owner and `epoch` is
+		/// the current block number divided by `ChallengeEpochLength`. It changes with every
+		/// epoch, so a signature over it cannot be replayed later.
+		pub fn ownership_challenge(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<T::AccountId>,
+		) -> Option<[u8; 32]> {
+			let (key, info) = Self::unexpired_claim(&namespace, &claim)?;
+			if !Self::can_view(&key, &info, viewer.as_ref()) {
+				return None
+			}
+			let owner = info.owners.first()?.clone();
+			let epoch = frame_system::Pallet::<T>::block_number()
+				/ T::ChallengeEpochLength::get().max(One::one());
+			let challenge =
+				(OWNERSHIP_CHALLENGE_CONTEXT, namespace, Self::claim_hash(&claim), owner, epoch);
+			Some(BlakeTwo256::hash_of(&challenge).0)
+		}
+
+		/// The last `count` entries of `CreationLog`, newest first, with their sequence number and
+		/// creator. Only the last `MaxRecentClaims` entries are kept, and a claim that was revoked,
+		/// transferred or expired since is still reported as it was created; look it up to learn
+		/// its current state. Entries of claims that are currently private use up `count` without
+		/// being listed. `count` is capped at `MaxRecentClaims`.
+		pub fn recent_claims(count: u32) -> Vec<(u64, ClaimOf<T>, T::AccountId)> {
+			// 更早的记录已经被删除，不必为它们逐个读取存储
+			let count = count.min(T::MaxRecentClaims::get());
+			let next = NextSeq::<T>::get();
+			(next.saturating_sub(count.into())..next)
+				.rev()
+				.filter_map(|seq| {
+					let (key, creator) = CreationLog::<T>::get(seq)?;
+					// 像 list_claims 一样不列出私有存证
+					if Proofs::<T>::get(&key).map_or(false, |info| info.private) {
+						return None
+					}
+					Some((seq, key.1, creator))
+				})
+				.collect()
+		}
+
+		/// The first owner and the creation block of the hash-only claim for `claim` within
+		/// `namespace`, if there is one.
+		pub fn verify_claim(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> Option<(T::AccountId, T::BlockNumber)> {
+			let namespace = Self::bounded_namespace(&namespace).ok()?;
+			HashedProofs::<T>::get((namespace, Self::claim_hash(&claim)))
+				.and_then(|info| Some((info.owners.first()?.clone(), info.created_at)))
+		}
+
+		/// The current Unix time in milliseconds.
+		fn now_moment() -> u64 {
+			T::UnixTime::now().as_millis().saturated_into()
+		}
+
+		/// Whether a claim with the given expiry block is no longer valid at the current block.
+		pub fn is_expired(expiry: Option<T::BlockNumber>) -> bool {
+			matches!(expiry, Some(expiry) if frame_system::Pallet::<T>::block_number() >= expiry)
+		}
+
+		/// Check `claim` within `namespace` the way [`Pallet::create_claim`] called by `who`
+		/// does before it touches any account, failing with the same error, without changing
+		/// storage. The whitelist, quota, rate limit and deposit of `who` are not checked.
+		pub fn validate_claim(
+			who: &T::AccountId,
+			namespace: &[u8],
+			claim: &[u8],
+		) -> Result<(), Error<T>> {
+			Self::new_claim_key(who, Self::bounded_namespace(namespace)?, claim).map(|_| ())
+		}
+
+		/// The deposit and fee [`Pallet::create_claim`] called by `who` would take for `claim`
+		/// within `namespace`, and whether it would succeed. The call is run in full and every
+		/// write is rolled back. `error` is the [`Pallet::error_code`] of the pallet error it
+		/// would fail with, and `None` if it fails for a reason outside the pallet. Backs the
+		/// `PoeApi` runtime API.
+		pub fn simulate_create(
+			who: T::AccountId,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> SimulateResult<BalanceOf<T>> {
+			let deposit = Self::claim_deposit(Self::normalized_claim(&claim).len());
+			let fee = T::ClaimCreationFee::get();
+
+			// 按 create_claim 的流程完整执行一遍，再回滚所有写入
+			let result = with_transaction(|| {
+				let create = || -> DispatchResult {
+					Self::ensure_not_paused()?;
+					Self::ensure_allowed_creator(&who)?;
+					let namespace = Self::bounded_namespace(&namespace)?;
+					Self::do_create_claim(
+						&who,
+						namespace,
+						claim,
+						Default::default(),
+						true,
+						None,
+						None,
+						None,
+					)
+				};
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>(create()))
+			})
+			.and_then(|result| result);
+			let would_succeed = result.is_ok();
+
+			// 只报告本模块的错误，使用稳定的错误编码而不是声明顺序
+			let error = match result {
+				Err(DispatchError::Module(ModuleError { index, error, .. }))
+					if index as usize == <Self as PalletInfoAccess>::index() =>
+				{
+					Error::<T>::decode(&mut &error[..]).ok().map(|err| Self::error_code(&err))
+				},
+				_ => None,
+			};
+			SimulateResult { deposit, fee, would_succeed, error }
+		}
+
+		/// The stable code of `err`, for callers such as other chains over XCM that cannot rely on
+		/// the position of a variant in [`Error`]. Codes are never reassigned: a new variant
+		/// takes the next unused code, and `0` stands for an error raised outside this pallet.
+		pub fn error_code(err: &Error<T>) -> u16 {
+			match err {
+				Error::ProofAlreadyExistOwnedByOther => 1,
+				Error::ClaimTooLong => 2,
+				Error::InvalidClaim => 3,
+				Error::MetadataTooLong => 4,
+				Error::NamespaceTooLong => 5,
+				Error::ClaimTooShort => 6,
+				Error::ClaimNotExist => 7,
+				Error::NotClaimOwner => 8,
+				Error::ClaimExpired => 9,
+				Error::ExpiryQueueFull => 10,
+				Error::TooManyClaims => 11,
+				Error::BatchTooLarge => 12,
+				Error::NoPendingTransfer => 13,
+				Error::NotPendingRecipient => 14,
+				Error::InsufficientDeposit => 15,
+				Error::InsufficientFee => 16,
+				Error::Paused => 17,
+				Error::NotAuthorized => 18,
+				Error::NoApproval => 19,
+				Error::ClaimTombstoned => 20,
+				Error::ClaimNotTransferable => 21,
+				Error::CannotTransferToSelf => 22,
+				Error::AlreadyOwner => 23,
+				Error::NotAnOwner => 24,
+				Error::LastOwner => 25,
+				Error::TooManyOwners => 26,
+				Error::RateLimited => 27,
+				Error::StorageFull => 28,
+				Error::NotExpiringSoon => 29,
+				Error::NotWhitelisted => 30,
+				Error::ClaimLocked => 31,
+				Error::DelegateAlreadyExists => 32,
+				Error::DelegateNotFound => 33,
+				Error::TooManyDelegates => 34,
+				Error::EmptyClaim => 35,
+				Error::QuotaTooHigh => 36,
+				Error::ClaimFrozen => 37,
+				Error::RecipientNotFound => 38,
+				Error::InvalidUtf8 => 39,
+				Error::AlreadyAllowed => 40,
+				Error::TooManyAllowed => 41,
+				Error::NotAllowed => 42,
+				Error::ProofAlreadyExistOwnedBySelf => 43,
+				Error::TooManyNamespaces => 44,
+				Error::NamespaceTaken => 45,
+				Error::NamespaceNotOwned => 46,
+				Error::NamespaceReserved => 47,
+				Error::ParentNotExist => 48,
+				Error::ContentTypeTooLong => 49,
+				Error::TransferCooldownActive => 50,
+				Error::PatchTooLong => 51,
+				Error::InvalidPatch => 52,
+				Error::PatchResultTooLong => 53,
+				Error::LabelTooLong => 54,
+				Error::ClaimInEscrow => 55,
+				Error::NotInEscrow => 56,
+				Error::EscrowNotDue => 57,
+				Error::EscrowDue => 58,
+				Error::InvalidSwap => 59,
+				Error::NamespaceFull => 60,
+				Error::DuplicateClaim => 61,
+				Error::TtlTooLong => 62,
+				Error::AlreadyViewer => 63,
+				Error::NotViewer => 64,
+				Error::TooManyViewers => 65,
+				Error::RecipientRefusesClaims => 66,
+				Error::ZeroTtl => 67,
+				Error::NoSwapOffer => 68,
+				Error::TooManySwapOffers => 69,
+				Error::EscrowReleaseNotInFuture => 70,
+				Error::__Ignore(_, never) => match *never {},
+			}
+		}
+
+		/// The stable code of a dispatch error, `0` unless it is an [`Error`] of this pallet.
+		fn dispatch_error_code(err: DispatchError) -> u16 {
+			match err {
+				DispatchError::Module(ModuleError { index, error, .. })
+					if index as usize == <Self as PalletInfoAccess>::index() =>
+				{
+					Error::<T>::decode(&mut &error[..]).map_or(0, |err| Self::error_code(&err))
+				},
+				_ => 0,
+			}
+		}
+
+		/// The key of `claim` within `namespace`, failing unless it is a valid claim that is
+		/// neither stored nor tombstoned.
+		fn new_claim_key(
+			who: &T::AccountId,
+			namespace: NamespaceOf<T>,
+			claim: &[u8],
+		) -> Result<ClaimKeyOf<T>, Error<T>> {
+			// 空存证总是客户端错误，即使 MinClaimLength 为零也拒绝
+			ensure!(!claim.is_empty(), Error::<T>::EmptyClaim);
+
+			// 尝试从 Vec<u8> 转换为 T::ClaimData
+			let bounded_claim = Self::bounded_claim(claim)?;
+
+			// 验证规范化后的存证长度不小于最小长度，与最大长度的检查一致
+			ensure!(
+				Self::normalized_claim(claim).len() as u32 >= T::MinClaimLength::get(),
+				Error::<T>::ClaimTooShort
+			);
+
+			// 要求 UTF-8 时拒绝无效的字节序列
+			Self::ensure_utf8(claim)?;
+
+			// 验证同一命名空间下是否已经存储过
+			let key = (namespace, bounded_claim);
+			Self::ensure_not_stored(who, &key)?;
+			ensure!(!Tombstones::<T>::contains_key(&key), Error::<T>::ClaimTombstoned);
+			Ok(key)
+		}
+
+		fn do_create_claim(
+			sender: &T::AccountId,
+			namespace: NamespaceOf<T>,
+			claim: Vec<u8>,
+			metadata: BoundedVec<u8, T::MaxMetadataLength>,
+			transferable: bool,
+			parent: Option<ClaimOf<T>>,
+			content_type: Option<ContentType>,
+			ttl: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			// 校验存证本身，并且同一命名空间下没有存储过
+			let key = Self::new_claim_key(sender, namespace, &claim)?;
+			let deposit = Self::claim_deposit(Self::normalized_claim(&claim).len());
+
+			// 首次在命名空间中创建存证时为创建者登记该命名空间
+			Self::note_namespace_used(sender, &key.0)?;
+
+			// 先收取不退还的手续费，余额不足时不写入任何存储
+			let fee = Self::charge_creation_fee(sender)?;
+
+			// 验证当前区块内的创建次数未达到上限
+			Self::note_claim_created(sender)?;
+
+			// 验证链上存证总数和命名空间中的存证数量未达到上限
+			Self::inc_proof_count()?;
+			Self::inc_namespace_count(&key.0)?;
+
+			// 预留存证押金
+			T::Deposits::reserve(sender, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+			// 计算存证的过期区块，未指定存活期时使用默认值
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let expiry =
+				current_block.saturating_add(ttl.unwrap_or_else(T::DefaultClaimExpiry::get));
+
+			// 记录到过期索引中
+			ExpiringAt::<T>::try_mutate(expiry, |queue| {
+				queue
+					.get_or_insert_with(Default::default)
+					.try_push(key.clone())
+					.map_err(|_| Error::<T>::ExpiryQueueFull)
+			})?;
+			let expiry = Some(expiry);
+
+			// 记录到所有者的存证列表中
+			Self::add_to_owner_index(sender, &key)?;
+			Self::record_owner(&key, sender.clone(), current_block);
+
+			// 重新创建被替换过的存证时，不再指向替换它的存证
+			Superseded::<T>::remove(&key);
+
+			let info = ClaimInfo {
+				owners: BoundedVec::truncate_from(vec![sender.clone()]),
+				created_at: current_block,
+				created_moment: Some(Self::now_moment()),
+				expiry,
+				metadata,
+				depositor: sender.clone(),
+				deposit,
+				transferable,
+				parent,
+				content_type,
+				last_transferred_at: None,
+				private: false,
+			};
+			Proofs::<T>::insert(&key, &info);
+
+			// 追加到创建记录中
+			Self::log_creation(&key, sender);
+			let claim_id = Self::assign_claim_id(&key);
+
+			// 记录创建次数，此前存在过的存证发送重新创建事件
+			let generation = ClaimGenerations::<T>::mutate(&key, |generation| {
+				*generation = generation.saturating_add(1);
+				*generation
+			});
+			Self::deposit_event(if generation > 1 {
+				Event::ClaimRecreated(
+					sender.clone(),
+					key.0.into_inner(),
+					claim,
+					generation,
+					claim_id,
+					Self::next_event_index(),
+				)
+			} else {
+				Event::ClaimCreated(
+					sender.clone(),
+					key.0.into_inner(),
+					claim,
+					info,
+					fee,
+					claim_id,
+					Self::next_event_index(),
+				)
+			});
+
+			Ok(())
+		}
+
+		fn do_revoke_claim(
+			sender: &T::AccountId,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			reason: u16,
+		) -> DispatchResult {
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(&namespace, &claim)?;
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 验证存证是否已经过期
+			ensure!(!Self::is_expired(info.expiry), Error::<T>::ClaimExpired);
+
+			// 验证当前用户是存证的所有者、被授权的操作者或吊销代理人
+			if Self::ensure_owner_or_operator(sender, &key, &info).is_err() {
+				ensure!(
+					RevokeDelegates::<T>::get(&key).contains(sender),
+					Error::<T>::NotAuthorized
+				);
+			}
+
+			// 冻结期间不允许吊销存证
+			Self::ensure_not_frozen(&key)?;
 
 			// 从存储里删除存证
-			Proofs::<T>::insert(&bounded_claim, (dest, frame_system::Pallet::<T>::block_number()));
+			Self::remove_claim(&key, &info);
 
-			// 发送存证转移事件
-			Self::deposit_event(Event::ClaimTransfered(owner, sender, claim));
+			// 所有者自行吊销时记录该所有者，否则记录第一个所有者
+			let owner =
+				if info.is_owner(sender) { sender } else { info.owners.first().unwrap_or(sender) };
 
-			Ok(().into())
+			// 发送存证吊销事件，同时记录所有者和实际的操作者
+			Self::deposit_event(Event::ClaimRevoked(
+				owner.clone(),
+				sender.clone(),
+				namespace,
+				claim,
+				frame_system::Pallet::<T>::block_number(),
+				reason,
+				Self::next_event_index(),
+			));
+
+			Ok(())
+		}
+
+		fn do_revoke_hashed_claim(
+			sender: &T::AccountId,
+			namespace: Vec<u8>,
+			hash: T::Hash,
+		) -> DispatchResult {
+			// 校验是否已经存在存证
+			let key = (Self::bounded_namespace(&namespace)?, hash);
+			let info = HashedProofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 验证当前用户是否是存证的所有者之一
+			ensure!(info.is_owner(sender), Error::<T>::NotClaimOwner);
+
+			// 从存储里删除存证并退还押金
+			Self::remove_hashed_claim(&key, &info);
+
+			Self::deposit_event(Event::HashedClaimRevoked(
+				sender.clone(),
+				namespace,
+				hash,
+				Self::next_event_index(),
+			));
+
+			Ok(())
+		}
+
+		/// The key and the stored state of a claim `sender` may transfer to `dest`.
+		fn claim_to_transfer(
+			sender: &T::AccountId,
+			namespace: &[u8],
+			claim: &[u8],
+			dest: &T::AccountId,
+		) -> Result<(ClaimKeyOf<T>, ClaimInfo<T>), DispatchError> {
+			// 转移给自己不会改变所有者，只会多记录一条所有权历史
+			ensure!(dest != sender, Error::<T>::CannotTransferToSelf);
+
+			// 将命名空间和存证转换为存储键
+			let key = Self::claim_key(namespace, claim)?;
+
+			// 校验是否已经存在存证
+			let info = Proofs::<T>::get(&key).ok_or(Error::<T>::ClaimNotExist)?;
+
+			// 验证存证是否已经过期
+			ensure!(!Self::is_expired(info.expiry), Error::<T>::ClaimExpired);
+
+			// 验证当前用户是存证的所有者或被授权的操作者
+			Self::ensure_owner_or_operator(sender, &key, &info)?;
+
+			// 冻结期间不允许转移存证
+			Self::ensure_not_frozen(&key)?;
+
+			// 操作者也不能把存证转移给当前所有者
+			ensure!(!info.is_owner(dest), Error::<T>::CannotTransferToSelf);
+
+			// 开启检查时不允许转移给不存在的账户
+			ensure!(
+				!T::RequireRecipientExists::get()
+					|| frame_system::Pallet::<T>::account_exists(dest),
+				Error::<T>::RecipientNotFound
+			);
+
+			// 接收方明确拒绝接收存证时不允许转移
+			ensure!(Pallet::<T>::accepts_claims(dest), Error::<T>::RecipientRefusesClaims);
+
+			Ok((key, info))
+		}
+
+		/// Whether [`Pallet::release_escrow`] would transfer the claim under `key` to
+		/// `beneficiary`, found by transferring it in a transaction that is rolled back.
+		fn escrow_releasable(
+			key: &ClaimKeyOf<T>,
+			info: ClaimInfo<T>,
+			beneficiary: T::AccountId,
+		) -> bool {
+			if Self::is_expired(info.expiry) {
+				return false
+			}
+			with_transaction(|| {
+				let released = Self::do_transfer(key, info, beneficiary).is_ok();
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>(released))
+			})
+			.unwrap_or(false)
+		}
+
+		fn do_transfer(
+			key: &ClaimKeyOf<T>,
+			mut info: ClaimInfo<T>,
+			dest: T::AccountId,
+		) -> DispatchResult {
+			// 验证存证允许转移，接受转移时也需要再次检查
+			ensure!(info.transferable, Error::<T>::ClaimNotTransferable);
+
+			// 接收方已是所有者时转移只会移除其他共同所有者，提议之后才成为所有者的也一样
+			ensure!(!info.is_owner(&dest), Error::<T>::CannotTransferToSelf);
+
+			let now = frame_system::Pallet::<T>::block_number();
+
+			// 创建后的锁定期内不允许转移，创建区块取自存储且转移时不会重置
+			ensure!(
+				now >= info.created_at.saturating_add(T::TransferLockPeriod::get()),
+				Error::<T>::ClaimLocked
+			);
+
+			// 上次转移后的冷却期内不允许再次转移
+			ensure!(Self::cooldown_over(&info, now), Error::<T>::TransferCooldownActive);
+
+			// 在所有者索引中将存证从所有共同所有者移动到接收方
+			for from in &info.owners {
+				Self::remove_from_owner_index(from, key);
+			}
+			Self::add_to_owner_index(&dest, key)?;
+			PendingTransfers::<T>::remove(key);
+			ClaimApprovals::<T>::remove(key);
+			RevokeDelegates::<T>::remove(key);
+			TransferAllowList::<T>::remove(key);
+			Viewers::<T>::remove(key);
+			Self::clear_swap_offers(key);
+
+			// 接收方成为唯一的所有者，过期区块保持不变
+			let from = sp_std::mem::replace(
+				&mut info.owners,
+				BoundedVec::truncate_from(vec![dest.clone()]),
+			);
+			info.last_transferred_at = Some(now);
+			Proofs::<T>::insert(key, info);
+			Self::record_owner(key, dest.clone(), now);
+
+			// 为每个原所有者发送存证转移事件并通知其他模块
+			let claim: Vec<u8> = key.1.clone().into();
+			for from in from {
+				T::OnClaimTransfer::on_transfer(&claim, &from, &dest);
+				Self::deposit_event(Event::ClaimTransfered(
+					from,
+					dest.clone(),
+					key.0.to_vec(),
+					claim.clone(),
+					now,
+					Self::next_event_index(),
+				));
+			}
+
+			Ok(())
+		}
+
+		/// Append the claim stored under `key` by `who` to `CreationLog`, dropping the oldest
+		/// entry once the log holds `MaxRecentClaims`.
+		fn log_creation(key: &ClaimKeyOf<T>, who: &T::AccountId) {
+			let seq = NextSeq::<T>::mutate(|seq| {
+				let current = *seq;
+				*seq = seq.saturating_add(1);
+				current
+			});
+			CreationLog::<T>::insert(seq, (key.clone(), who.clone()));
+
+			// 像 ClaimHistory 一样只保留最近的记录
+			if let Some(oldest) = seq.checked_sub(T::MaxRecentClaims::get().into()) {
+				CreationLog::<T>::remove(oldest);
+			}
+		}
+
+		/// Give the claim stored under `key` the next id from `NextClaimId`.
+		fn assign_claim_id(key: &ClaimKeyOf<T>) -> u64 {
+			let id = NextClaimId::<T>::mutate(|next| {
+				let current = *next;
+				*next = next.saturating_add(1);
+				current
+			});
+			ClaimIds::<T>::insert(key, id);
+			id
+		}
+
+		/// Take the next index from `EventSeq` for a claim lifecycle event.
+		fn next_event_index() -> u64 {
+			EventSeq::<T>::mutate(|seq| {
+				let current = *seq;
+				*seq = seq.saturating_add(1);
+				current
+			})
+		}
+
+		/// Whether `TransferCooldown` blocks have passed since the claim was last transferred.
+		/// Claims that never changed hands have no cooldown.
+		fn cooldown_over(info: &ClaimInfo<T>, now: T::BlockNumber) -> bool {
+			info.last_transferred_at
+				.map_or(true, |at| now >= at.saturating_add(T::TransferCooldown::get()))
+		}
+
+		/// Replace the claim under `old_key` with `new_claim` in the same namespace for
+		/// [`Pallet::supersede_claim`] and [`Pallet::patch_claim`], once `sender` is known to own
+		/// it.
+		fn do_supersede(
+			sender: &T::AccountId,
+			old_key: ClaimKeyOf<T>,
+			info: ClaimInfo<T>,
+			new_claim: &[u8],
+		) -> DispatchResult {
+			// 新存证和新建的存证一样校验长度，并且不能已经存在
+			ensure!(!new_claim.is_empty(), Error::<T>::EmptyClaim);
+			let new_key = (old_key.0.clone(), Self::bounded_claim(new_claim)?);
+			ensure!(
+				Self::normalized_claim(new_claim).len() as u32 >= T::MinClaimLength::get(),
+				Error::<T>::ClaimTooShort
+			);
+			Self::ensure_utf8(new_claim)?;
+			Self::ensure_not_stored(sender, &new_key)?;
+			ensure!(!Tombstones::<T>::contains_key(&new_key), Error::<T>::ClaimTombstoned);
+
+			// 删除旧存证并退还押金，所有权记录和私有存证的查看者转给新存证
+			let history = ClaimHistory::<T>::get(&old_key);
+			let viewers = Viewers::<T>::get(&old_key);
+			Self::remove_claim(&old_key, &info);
+			Self::inc_proof_count()?;
+			Self::inc_namespace_count(&new_key.0)?;
+
+			// 为新存证预留押金
+			let deposit = Self::claim_deposit(Self::normalized_claim(new_claim).len());
+			T::Deposits::reserve(sender, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+			// 新存证沿用旧存证的过期区块和所有者
+			if let Some(expiry) = info.expiry {
+				ExpiringAt::<T>::try_mutate(expiry, |queue| {
+					queue
+						.get_or_insert_with(Default::default)
+						.try_push(new_key.clone())
+						.map_err(|_| Error::<T>::ExpiryQueueFull)
+				})?;
+			}
+			for owner in &info.owners {
+				Self::add_to_owner_index(owner, &new_key)?;
+			}
+			ClaimHistory::<T>::insert(&new_key, history);
+			if !viewers.is_empty() {
+				Viewers::<T>::insert(&new_key, viewers);
+			}
+			ClaimGenerations::<T>::mutate(&new_key, |generation| {
+				*generation = generation.saturating_add(1)
+			});
+			Self::log_creation(&new_key, sender);
+			Self::assign_claim_id(&new_key);
+
+			// 记录旧存证到新存证的别名
+			Superseded::<T>::remove(&new_key);
+			Superseded::<T>::insert(&old_key, new_key.1.clone());
+			Proofs::<T>::insert(&new_key, ClaimInfo { depositor: sender.clone(), deposit, ..info });
+
+			Ok(())
+		}
+
+		/// Register `namespace` to `who` unless it is the empty namespace or already registered.
+		fn note_namespace_used(who: &T::AccountId, namespace: &NamespaceOf<T>) -> DispatchResult {
+			if namespace.is_empty() || NamespaceOwners::<T>::contains_key(namespace) {
+				return Ok(())
+			}
+			Self::do_register_namespace(who, namespace)?;
+			Self::deposit_event(Event::NamespaceRegistered(who.clone(), namespace.to_vec()));
+			Ok(())
+		}
+
+		/// Register `namespace` to `who`, failing with [`Error::TooManyNamespaces`] once `who`
+		/// has `MaxNamespacesPerAccount` namespaces.
+		fn do_register_namespace(
+			who: &T::AccountId,
+			namespace: &NamespaceOf<T>,
+		) -> Result<(), Error<T>> {
+			NamespacesOwned::<T>::try_mutate(who, |count| {
+				ensure!(*count < T::MaxNamespacesPerAccount::get(), Error::<T>::TooManyNamespaces);
+				*count += 1;
+				Ok::<_, Error<T>>(())
+			})?;
+			NamespaceOwners::<T>::insert(namespace, who);
+			Ok(())
+		}
+
+		/// Count one more claim created by `who` in the current block, failing with
+		/// [`Error::RateLimited`] beyond `MaxClaimsPerBlockPerAccount`.
+		fn note_claim_created(who: &T::AccountId) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			ClaimsThisBlock::<T>::try_mutate(who, |(block, count)| {
+				// 计数属于更早的区块时重新开始
+				if *block != now {
+					*block = now;
+					*count = 0;
+				}
+				ensure!(*count < T::MaxClaimsPerBlockPerAccount::get(), Error::<T>::RateLimited);
+				*count += 1;
+				Ok(())
+			})
+		}
+
+		/// Pay `ClaimCreationFee` from `who` to `FeeDestination`, failing with
+		/// [`Error::InsufficientFee`] if `who` cannot pay it and stay alive.
+		fn charge_creation_fee(who: &T::AccountId) -> Result<BalanceOf<T>, Error<T>> {
+			let fee = T::ClaimCreationFee::get();
+			if !fee.is_zero() {
+				T::Deposits::pay_fee(who, &T::FeeDestination::get(), fee)
+					.map_err(|_| Error::<T>::InsufficientFee)?;
+			}
+			Ok(fee)
+		}
+
+		/// Count one more stored claim, failing with [`Error::StorageFull`] at `MaxTotalProofs`.
+		/// Warns with [`Event::StorageNearCapacity`] when the count reaches the warning threshold.
+		pub(crate) fn inc_proof_count() -> DispatchResult {
+			let cap = T::MaxTotalProofs::get();
+			let used = ProofCount::<T>::try_mutate(|count| {
+				ensure!(*count < cap, Error::<T>::StorageFull);
+				*count += 1;
+				Ok::<_, DispatchError>(*count)
+			})?;
+
+			// 只在首次达到阈值时告警，标记读取只发生在阈值以上
+			if used >= Self::capacity_threshold() && !CapacityWarned::<T>::get() {
+				CapacityWarned::<T>::put(true);
+				Self::deposit_event(Event::StorageNearCapacity(used, cap));
+			}
+			Ok(())
+		}
+
+		fn dec_proof_count() {
+			let used = ProofCount::<T>::mutate(|count| {
+				*count = count.saturating_sub(1);
+				*count
+			});
+
+			// 刚好回落到阈值以下时清除告警标记，之后再次达到阈值会重新告警
+			if used.saturating_add(1) == Self::capacity_threshold() {
+				CapacityWarned::<T>::kill();
+			}
+		}
+
+		/// Count one more claim within `namespace`, failing with [`Error::NamespaceFull`] at
+		/// `MaxClaimsPerNamespace`.
+		pub(crate) fn inc_namespace_count(namespace: &NamespaceOf<T>) -> DispatchResult {
+			NamespaceCount::<T>::try_mutate(namespace, |count| {
+				ensure!(*count < T::MaxClaimsPerNamespace::get(), Error::<T>::NamespaceFull);
+				*count += 1;
+				Ok(())
+			})
+		}
+
+		fn dec_namespace_count(namespace: &NamespaceOf<T>) {
+			NamespaceCount::<T>::mutate_exists(namespace, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+		}
+
+		/// The number of stored claims at which [`Event::StorageNearCapacity`] is emitted.
+		fn capacity_threshold() -> u32 {
+			Percent::from_percent(T::CapacityWarningThresholdPercent::get())
+				.mul_ceil(T::MaxTotalProofs::get())
+		}
+
+		/// Delete a claim together with every index entry that refers to it, refunding its
+		/// deposit.
+		fn remove_claim(key: &ClaimKeyOf<T>, info: &ClaimInfo<T>) {
+			Self::clear_claim(key, info);
+			T::Deposits::unreserve(&info.depositor, info.deposit);
+		}
+
+		/// Delete a claim together with every index entry that refers to it, leaving its deposit
+		/// reserved.
+		fn clear_claim(key: &ClaimKeyOf<T>, info: &ClaimInfo<T>) {
+			Proofs::<T>::remove(key);
+			Self::dec_proof_count();
+			Self::dec_namespace_count(&key.0);
+			Self::remove_from_expiry_queue(key, info.expiry);
+			for owner in &info.owners {
+				Self::remove_from_owner_index(owner, key);
+			}
+			PendingTransfers::<T>::remove(key);
+			ClaimApprovals::<T>::remove(key);
+			ClaimHistory::<T>::remove(key);
+			RevokeDelegates::<T>::remove(key);
+			TransferAllowList::<T>::remove(key);
+			FrozenClaims::<T>::remove(key);
+			Labels::<T>::remove(key);
+			Escrows::<T>::remove(key);
+			Viewers::<T>::remove(key);
+			ClaimIds::<T>::remove(key);
+			Self::clear_swap_offers(key);
+		}
+
+		/// Record the offer of `offerer` to swap the first claim of `offer` for the second one of
+		/// `counterparty`, replacing an earlier offer for the same pair of claims, and reserve
+		/// `SwapOfferDeposit` for it.
+		fn add_swap_offer(
+			offer: (ClaimKeyOf<T>, ClaimKeyOf<T>),
+			offerer: &T::AccountId,
+			counterparty: &T::AccountId,
+		) -> DispatchResult {
+			// 同一对存证的旧报价先撤销并退还押金
+			Self::remove_swap_offer(&offer);
+			for claim in [&offer.0, &offer.1] {
+				SwapOffersOf::<T>::try_mutate(claim, |offers| offers.try_push(offer.clone()))
+					.map_err(|_| Error::<T>::TooManySwapOffers)?;
+			}
+
+			let deposit = T::SwapOfferDeposit::get();
+			T::Deposits::reserve(offerer, deposit)?;
+			SwapOffers::<T>::insert(&offer, (offerer.clone(), counterparty.clone(), deposit));
+			Ok(())
+		}
+
+		/// Remove a swap offer, if there is one, and refund its deposit to the offerer.
+		fn remove_swap_offer(offer: &(ClaimKeyOf<T>, ClaimKeyOf<T>)) {
+			if let Some((offerer, _, deposit)) = SwapOffers::<T>::take(offer) {
+				T::Deposits::unreserve(&offerer, deposit);
+			}
+			for claim in [&offer.0, &offer.1] {
+				SwapOffersOf::<T>::mutate_exists(claim, |offers| {
+					if let Some(list) = offers {
+						list.retain(|other| other != offer);
+						if list.is_empty() {
+							*offers = None;
+						}
+					}
+				});
+			}
+		}
+
+		/// Remove every swap offer `key` is part of, as the owners it was offered by or to no
+		/// longer hold it.
+		fn clear_swap_offers(key: &ClaimKeyOf<T>) {
+			for offer in SwapOffersOf::<T>::take(key) {
+				Self::remove_swap_offer(&offer);
+			}
+		}
+
+		/// Append `owner` at block `at` to the history of a claim, dropping the oldest entry when
+		/// `MaxHistoryLength` is reached.
+		fn record_owner(key: &ClaimKeyOf<T>, owner: T::AccountId, at: T::BlockNumber) {
+			ClaimHistory::<T>::mutate(key, |history| {
+				// 记录已满时丢弃最早的一条，不阻止转移
+				if history.len() as u32 >= T::MaxHistoryLength::get() && !history.is_empty() {
+					history.remove(0);
+				}
+				let _ = history.try_push((owner, at));
+			});
+		}
+
+		/// The deposit reserved for a claim of `len` bytes, measured after normalization.
+		pub fn claim_deposit(len: usize) -> BalanceOf<T> {
+			T::ClaimByteDeposit::get()
+				.saturating_mul((len as u32).into())
+				.saturating_add(T::ClaimBaseDeposit::get())
+		}
+
+		/// Whether claims may be transferred to `who`: its own choice if it made one with
+		/// [`Pallet::set_accepts_claims`], `DefaultAcceptsClaims` otherwise.
+		pub fn accepts_claims(who: &T::AccountId) -> bool {
+			AcceptsClaims::<T>::get(who).unwrap_or_else(T::DefaultAcceptsClaims::get)
+		}
+
+		/// The number of claims `who` may own: its `AccountQuota` override if it has one,
+		/// `DefaultAccountQuota` otherwise, and never more than `MaxClaimsPerAccount`.
+		pub fn account_quota(who: &T::AccountId) -> u32 {
+			AccountQuota::<T>::get(who)
+				.unwrap_or_else(T::DefaultAccountQuota::get)
+				.min(T::MaxClaimsPerAccount::get())
+		}
+
+		pub(crate) fn add_to_owner_index(
+			who: &T::AccountId,
+			key: &ClaimKeyOf<T>,
+		) -> Result<(), Error<T>> {
+			let quota = Self::account_quota(who);
+			let hashed = HashedClaimsByOwner::<T>::decode_len(who).unwrap_or(0);
+			ClaimsByOwner::<T>::try_mutate(who, |claims| {
+				// 先按账户配额检查，哈希存证也计入配额，再受存储上限约束
+				ensure!(
+					(claims.len().saturating_add(hashed) as u32) < quota,
+					Error::<T>::TooManyClaims
+				);
+				claims.try_push(key.clone()).map_err(|_| Error::<T>::TooManyClaims)
+			})
+		}
+
+		fn add_to_hashed_owner_index(
+			who: &T::AccountId,
+			key: &(NamespaceOf<T>, T::Hash),
+		) -> Result<(), Error<T>> {
+			let quota = Self::account_quota(who);
+			let owned = ClaimsByOwner::<T>::decode_len(who).unwrap_or(0);
+			HashedClaimsByOwner::<T>::try_mutate(who, |claims| {
+				ensure!(
+					(claims.len().saturating_add(owned) as u32) < quota,
+					Error::<T>::TooManyClaims
+				);
+				claims.try_push(key.clone()).map_err(|_| Error::<T>::TooManyClaims)
+			})
+		}
+
+		/// Delete a claim created with [`Pallet::create_hashed_claim`], dropping it from its
+		/// owners' index and refunding the deposit to its depositor.
+		fn remove_hashed_claim(key: &(NamespaceOf<T>, T::Hash), info: &ClaimInfo<T>) {
+			HashedProofs::<T>::remove(key);
+			Self::dec_proof_count();
+			Self::dec_namespace_count(&key.0);
+			for owner in &info.owners {
+				HashedClaimsByOwner::<T>::mutate_exists(owner, |maybe_claims| {
+					if let Some(claims) = maybe_claims {
+						claims.retain(|owned| owned != key);
+						if claims.is_empty() {
+							*maybe_claims = None;
+						}
+					}
+				});
+			}
+			T::Deposits::unreserve(&info.depositor, info.deposit);
+		}
+
+		fn remove_from_owner_index(who: &T::AccountId, key: &ClaimKeyOf<T>) {
+			ClaimsByOwner::<T>::mutate_exists(who, |maybe_claims| {
+				if let Some(claims) = maybe_claims {
+					claims.retain(|owned| owned != key);
+					if claims.is_empty() {
+						*maybe_claims = None;
+					}
+				}
+			});
+		}
+
+		fn remove_from_expiry_queue(key: &ClaimKeyOf<T>, expiry: Option<T::BlockNumber>) {
+			if let Some(expiry) = expiry {
+				ExpiringAt::<T>::mutate_exists(expiry, |maybe_queue| {
+					if let Some(queue) = maybe_queue {
+						queue.retain(|queued| queued != key);
+						if queue.is_empty() {
+							*maybe_queue = None;
+						}
+					}
+				});
+			}
+		}
+
+		/// Remove claims whose expiry block has been reached, at most `MaxExpiredPerBlock` of them.
+		///
+		/// Claims left over once the limit is hit stay queued and are handled first in the next
+		/// block.
+		pub(crate) fn purge_expired(now: T::BlockNumber) -> Weight {
+			let limit = T::MaxExpiredPerBlock::get() as usize;
+			let mut processed = 0usize;
+			let mut blocks_scanned = 0u64;
+
+			let mut block = ExpiryCursor::<T>::take().unwrap_or(now);
+			while block <= now {
+				blocks_scanned += 1;
+				if let Some(queue) = ExpiringAt::<T>::take(block) {
+					let mut due = queue.into_inner();
+					let budget = limit - processed;
+					let remaining =
+						if due.len() > budget { due.split_off(budget) } else { Vec::new() };
+
+					for key in due {
+						processed += 1;
+						// 存证可能已被吊销后重新创建，只清理过期区块一致的存证
+						if let Some(info) =
+							Proofs::<T>::get(&key).filter(|info| info.expiry == Some(block))
+						{
+							Self::remove_claim(&key, &info);
+							let (namespace, claim) = key;
+							Self::deposit_event(Event::ClaimExpired(
+								namespace.into_inner(),
+								claim.into(),
+								Self::next_event_index(),
+							));
+						}
+					}
+
+					if !remaining.is_empty() {
+						if let Ok(remaining) = BoundedVec::try_from(remaining) {
+							ExpiringAt::<T>::insert(block, remaining);
+						}
+						ExpiryCursor::<T>::put(block);
+						break
+					}
+				}
+				block = block.saturating_add(One::one());
+			}
+
+			T::WeightInfo::on_initialize(processed as u32)
+				.saturating_add(T::DbWeight::get().reads(blocks_scanned.saturating_sub(1)))
+		}
+
+		/// Leave a tombstone for `key` and queue it for [`Pallet::purge_tombstones`].
+		pub(crate) fn bury(key: &ClaimKeyOf<T>, now: T::BlockNumber) {
+			Tombstones::<T>::insert(key, now);
+			TombstoneQueueBounds::<T>::mutate(|(_, tail)| {
+				TombstoneQueue::<T>::insert(*tail, key);
+				*tail = tail.saturating_add(1);
+			});
+		}
+
+		/// Remove queued tombstones older than `TombstoneRetention`, oldest first, for as long as
+		/// `remaining_weight` covers another one. Never returns more than `remaining_weight`;
+		/// whatever is left over is picked up by later idle calls.
+		pub(crate) fn purge_tombstones(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			// 连读取队列边界的开销都不够时什么也不做
+			if T::WeightInfo::on_idle(0).ref_time() > remaining_weight.ref_time() {
+				return Weight::zero()
+			}
+
+			// 清理了墓碑时还要写回队列边界，预留这次写入的开销
+			let bounds_write = T::DbWeight::get().writes(1);
+			let (start, tail) = TombstoneQueueBounds::<T>::get();
+			let retention = T::TombstoneRetention::get();
+			let mut head = start;
+			let mut inspected = 0u32;
+			while head < tail
+				&& T::WeightInfo::on_idle(inspected + 1).saturating_add(bounds_write).ref_time()
+					<= remaining_weight.ref_time()
+			{
+				inspected += 1;
+				if let Some(key) = TombstoneQueue::<T>::get(head) {
+					// 队列按时间排序，队首还在保留期内时后面的也都在
+					if let Some(buried) = Tombstones::<T>::get(&key) {
+						if buried.saturating_add(retention) > now {
+							break
+						}
+						Tombstones::<T>::remove(&key);
+					}
+					TombstoneQueue::<T>::remove(head);
+				}
+				head += 1;
+			}
+
+			let weight = T::WeightInfo::on_idle(inspected);
+			if head == start {
+				return weight
+			}
+			TombstoneQueueBounds::<T>::put((head, tail));
+			weight.saturating_add(bounds_write)
+		}
+	}
+
+	#[cfg(any(feature = "try-runtime", test))]
+	impl<T: Config> Pallet<T> {
+		/// Check the storage invariants of the pallet:
+		///
+		/// - every stored claim is at most `MaxClaimLength` bytes long;
+		/// - `ProofCount` equals the number of plain and hashed claims;
+		/// - every key in `ClaimsByOwner` points at a claim held by that owner, and every claim is
+		///   indexed under each of its owners unless the owner's index is full;
+		/// - every frozen or escrowed claim exists;
+		/// - `NamespacesOwned` equals the number of namespaces registered to each account;
+		/// - `NamespaceCount` equals the number of plain and hashed claims in each namespace.
+		pub(crate) fn do_try_state() -> Result<(), &'static str> {
+			let mut count = 0u32;
+			for (key, info) in Proofs::<T>::iter() {
+				let claim: Vec<u8> = key.1.clone().into();
+				ensure!(
+					claim.len() as u32 <= T::MaxClaimLength::get(),
+					"a claim is longer than MaxClaimLength"
+				);
+
+				for owner in &info.owners {
+					let owned = ClaimsByOwner::<T>::get(owner);
+					ensure!(
+						owned.contains(&key) || owned.len() as u32 == T::MaxClaimsPerAccount::get(),
+						"a claim is missing from its owner's index"
+					);
+				}
+				count = count.saturating_add(1);
+			}
+			count = count.saturating_add(HashedProofs::<T>::iter_keys().count() as u32);
+			ensure!(ProofCount::<T>::get() == count, "ProofCount does not match the stored claims");
+
+			for (owner, keys) in ClaimsByOwner::<T>::iter() {
+				for key in keys {
+					let info = Proofs::<T>::get(&key).ok_or("an indexed claim does not exist")?;
+					ensure!(info.is_owner(&owner), "a claim is indexed under the wrong owner");
+				}
+			}
+
+			for (owner, keys) in HashedClaimsByOwner::<T>::iter() {
+				for key in keys {
+					let info = HashedProofs::<T>::get(&key)
+						.ok_or("an indexed hashed claim does not exist")?;
+					ensure!(
+						info.is_owner(&owner),
+						"a hashed claim is indexed under the wrong owner"
+					);
+				}
+			}
+
+			for key in FrozenClaims::<T>::iter_keys() {
+				ensure!(Proofs::<T>::contains_key(&key), "a frozen claim does not exist");
+			}
+
+			for key in Escrows::<T>::iter_keys() {
+				ensure!(Proofs::<T>::contains_key(&key), "an escrowed claim does not exist");
+			}
+
+			let mut registered =
+				sp_std::collections::btree_map::BTreeMap::<T::AccountId, u32>::new();
+			for owner in NamespaceOwners::<T>::iter_values() {
+				*registered.entry(owner).or_default() += 1;
+			}
+			ensure!(
+				NamespacesOwned::<T>::iter()
+					.all(|(who, count)| registered.remove(&who) == Some(count))
+					&& registered.is_empty(),
+				"NamespacesOwned does not match the registered namespaces"
+			);
+
+			let mut stored = sp_std::collections::btree_map::BTreeMap::<NamespaceOf<T>, u32>::new();
+			for namespace in Proofs::<T>::iter_keys()
+				.map(|(namespace, _)| namespace)
+				.chain(HashedProofs::<T>::iter_keys().map(|(namespace, _)| namespace))
+			{
+				*stored.entry(namespace).or_default() += 1;
+			}
+			ensure!(
+				NamespaceCount::<T>::iter()
+					.all(|(namespace, count)| stored.remove(&namespace) == Some(count))
+					&& stored.is_empty(),
+				"NamespaceCount does not match the stored claims"
+			);
+
+			Ok(())
 		}
 	}
 }
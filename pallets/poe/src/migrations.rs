@@ -0,0 +1,1412 @@
+//! Storage migrations for pallet-poe.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::{
+		migration::{storage_iter, storage_key_iter},
+		storage_prefix, unhashed,
+	},
+	traits::{GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
+	CloneNoBound, EqNoBound, PartialEqNoBound, ReversibleStorageHasher, RuntimeDebugNoBound,
+	StorageHasher,
+};
+use sp_runtime::traits::Zero;
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// Claims as they were stored before `ClaimData` was introduced.
+pub type BytesClaimOf<T> = BoundedVec<u8, <T as Config>::MaxClaimLength>;
+
+/// [`ClaimInfo`] as it was stored before the `transferable` flag was added.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+pub struct OldClaimInfo<T: Config> {
+	pub owner: T::AccountId,
+	pub created_at: T::BlockNumber,
+	pub expiry: Option<T::BlockNumber>,
+	pub metadata: BoundedVec<u8, T::MaxMetadataLength>,
+	pub depositor: T::AccountId,
+	pub deposit: BalanceOf<T>,
+}
+
+impl<T: Config> OldClaimInfo<T> {
+	/// The claim with the `transferable` flag set, as claims were always transferable before.
+	fn upgrade(self) -> SingleOwnerClaimInfo<T> {
+		SingleOwnerClaimInfo {
+			owner: self.owner,
+			created_at: self.created_at,
+			expiry: self.expiry,
+			metadata: self.metadata,
+			depositor: self.depositor,
+			deposit: self.deposit,
+			transferable: true,
+		}
+	}
+}
+
+/// [`ClaimInfo`] as it was stored before claims could have several owners.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+pub struct SingleOwnerClaimInfo<T: Config> {
+	pub owner: T::AccountId,
+	pub created_at: T::BlockNumber,
+	pub expiry: Option<T::BlockNumber>,
+	pub metadata: BoundedVec<u8, T::MaxMetadataLength>,
+	pub depositor: T::AccountId,
+	pub deposit: BalanceOf<T>,
+	pub transferable: bool,
+}
+
+impl<T: Config> SingleOwnerClaimInfo<T> {
+	/// The claim with its owner as the only entry of `owners`.
+	fn upgrade(self) -> UntimedClaimInfo<T> {
+		UntimedClaimInfo {
+			owners: BoundedVec::truncate_from(vec![self.owner]),
+			created_at: self.created_at,
+			expiry: self.expiry,
+			metadata: self.metadata,
+			depositor: self.depositor,
+			deposit: self.deposit,
+			transferable: self.transferable,
+		}
+	}
+}
+
+/// [`ClaimInfo`] as it was stored before the creation time was recorded.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+pub struct UntimedClaimInfo<T: Config> {
+	pub owners: BoundedVec<T::AccountId, T::MaxOwners>,
+	pub created_at: T::BlockNumber,
+	pub expiry: Option<T::BlockNumber>,
+	pub metadata: BoundedVec<u8, T::MaxMetadataLength>,
+	pub depositor: T::AccountId,
+	pub deposit: BalanceOf<T>,
+	pub transferable: bool,
+}
+
+impl<T: Config> UntimedClaimInfo<T> {
+	/// The claim without a creation time, as it is not known for existing claims.
+	fn upgrade(self) -> UnlinkedClaimInfo<T> {
+		UnlinkedClaimInfo {
+			owners: self.owners,
+			created_at: self.created_at,
+			created_moment: None,
+			expiry: self.expiry,
+			metadata: self.metadata,
+			depositor: self.depositor,
+			deposit: self.deposit,
+			transferable: self.transferable,
+		}
+	}
+}
+
+/// [`ClaimInfo`] as it was stored before claims could reference a parent.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+pub struct UnlinkedClaimInfo<T: Config> {
+	pub owners: BoundedVec<T::AccountId, T::MaxOwners>,
+	pub created_at: T::BlockNumber,
+	pub created_moment: Option<u64>,
+	pub expiry: Option<T::BlockNumber>,
+	pub metadata: BoundedVec<u8, T::MaxMetadataLength>,
+	pub depositor: T::AccountId,
+	pub deposit: BalanceOf<T>,
+	pub transferable: bool,
+}
+
+impl<T: Config> UnlinkedClaimInfo<T> {
+	/// The claim without a parent, as existing claims were created without one.
+	fn upgrade(self) -> UntaggedClaimInfo<T> {
+		UntaggedClaimInfo {
+			owners: self.owners,
+			created_at: self.created_at,
+			created_moment: self.created_moment,
+			expiry: self.expiry,
+			metadata: self.metadata,
+			depositor: self.depositor,
+			deposit: self.deposit,
+			transferable: self.transferable,
+			parent: None,
+		}
+	}
+}
+
+/// [`ClaimInfo`] as it was stored before claims could be tagged with a content type.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+pub struct UntaggedClaimInfo<T: Config> {
+	pub owners: BoundedVec<T::AccountId, T::MaxOwners>,
+	pub created_at: T::BlockNumber,
+	pub created_moment: Option<u64>,
+	pub expiry: Option<T::BlockNumber>,
+	pub metadata: BoundedVec<u8, T::MaxMetadataLength>,
+	pub depositor: T::AccountId,
+	pub deposit: BalanceOf<T>,
+	pub transferable: bool,
+	pub parent: Option<ClaimOf<T>>,
+}
+
+impl<T: Config> UntaggedClaimInfo<T> {
+	/// The claim without a content type, as existing claims were never tagged.
+	fn upgrade(self) -> UntrackedClaimInfo<T> {
+		UntrackedClaimInfo {
+			owners: self.owners,
+			created_at: self.created_at,
+			created_moment: self.created_moment,
+			expiry: self.expiry,
+			metadata: self.metadata,
+			depositor: self.depositor,
+			deposit: self.deposit,
+			transferable: self.transferable,
+			parent: self.parent,
+			content_type: None,
+		}
+	}
+}
+
+/// [`ClaimInfo`] as it was stored before the block of the last transfer was recorded.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+pub struct UntrackedClaimInfo<T: Config> {
+	pub owners: BoundedVec<T::AccountId, T::MaxOwners>,
+	pub created_at: T::BlockNumber,
+	pub created_moment: Option<u64>,
+	pub expiry: Option<T::BlockNumber>,
+	pub metadata: BoundedVec<u8, T::MaxMetadataLength>,
+	pub depositor: T::AccountId,
+	pub deposit: BalanceOf<T>,
+	pub transferable: bool,
+	pub parent: Option<ClaimOf<T>>,
+	pub content_type: Option<ContentType>,
+}
+
+impl<T: Config> UntrackedClaimInfo<T> {
+	/// The claim without a last transfer, so existing claims are not held back by the cooldown.
+	fn upgrade(self) -> PublicClaimInfo<T> {
+		PublicClaimInfo {
+			owners: self.owners,
+			created_at: self.created_at,
+			created_moment: self.created_moment,
+			expiry: self.expiry,
+			metadata: self.metadata,
+			depositor: self.depositor,
+			deposit: self.deposit,
+			transferable: self.transferable,
+			parent: self.parent,
+			content_type: self.content_type,
+			last_transferred_at: None,
+		}
+	}
+}
+
+/// [`ClaimInfo`] as it was stored before claims could be made private.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound)]
+pub struct PublicClaimInfo<T: Config> {
+	pub owners: BoundedVec<T::AccountId, T::MaxOwners>,
+	pub created_at: T::BlockNumber,
+	pub created_moment: Option<u64>,
+	pub expiry: Option<T::BlockNumber>,
+	pub metadata: BoundedVec<u8, T::MaxMetadataLength>,
+	pub depositor: T::AccountId,
+	pub deposit: BalanceOf<T>,
+	pub transferable: bool,
+	pub parent: Option<ClaimOf<T>>,
+	pub content_type: Option<ContentType>,
+	pub last_transferred_at: Option<T::BlockNumber>,
+}
+
+impl<T: Config> PublicClaimInfo<T> {
+	/// The claim as a public one, so existing claims stay visible to everyone.
+	fn upgrade(self) -> ClaimInfo<T> {
+		ClaimInfo {
+			owners: self.owners,
+			created_at: self.created_at,
+			created_moment: self.created_moment,
+			expiry: self.expiry,
+			metadata: self.metadata,
+			depositor: self.depositor,
+			deposit: self.deposit,
+			transferable: self.transferable,
+			parent: self.parent,
+			content_type: self.content_type,
+			last_transferred_at: self.last_transferred_at,
+			private: false,
+		}
+	}
+}
+
+/// The storage layout before [`ClaimInfo`] was introduced.
+pub mod v1 {
+	use super::*;
+
+	/// Claims stored as an `(owner, block_number)` tuple.
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		BytesClaimOf<T>,
+		(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber),
+	>;
+}
+
+/// The storage layout before namespaces were introduced, keyed by the claim alone.
+pub mod v2 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, BytesClaimOf<T>, OldClaimInfo<T>>;
+
+	#[frame_support::storage_alias]
+	pub type ExpiringAt<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<BytesClaimOf<T>, <T as Config>::MaxClaimsPerExpiryBlock>,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type ClaimsByOwner<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<BytesClaimOf<T>, <T as Config>::MaxClaimsPerAccount>,
+		ValueQuery,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type PendingTransfers<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		BytesClaimOf<T>,
+		<T as frame_system::Config>::AccountId,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type ClaimApprovals<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		BytesClaimOf<T>,
+		<T as frame_system::Config>::AccountId,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type HashedProofs<T: Config> =
+		StorageMap<Pallet<T>, Identity, <T as frame_system::Config>::Hash, OldClaimInfo<T>>;
+}
+
+/// The storage layout before the `transferable` flag was added to [`ClaimInfo`].
+pub mod v3 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ClaimKeyOf<T>, OldClaimInfo<T>>;
+
+	#[frame_support::storage_alias]
+	pub type HashedProofs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(NamespaceOf<T>, <T as frame_system::Config>::Hash),
+		OldClaimInfo<T>,
+	>;
+}
+
+/// The storage layout before claims could have several owners.
+pub mod v5 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, ClaimKeyOf<T>, SingleOwnerClaimInfo<T>>;
+
+	#[frame_support::storage_alias]
+	pub type HashedProofs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(NamespaceOf<T>, <T as frame_system::Config>::Hash),
+		SingleOwnerClaimInfo<T>,
+	>;
+}
+
+/// The storage layout before the creation time was added to [`ClaimInfo`].
+pub mod v6 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> =
+		StorageMap<Pallet<T>, <T as Config>::ProofsHasher, ClaimKeyOf<T>, UntimedClaimInfo<T>>;
+
+	#[frame_support::storage_alias]
+	pub type HashedProofs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(NamespaceOf<T>, <T as frame_system::Config>::Hash),
+		UntimedClaimInfo<T>,
+	>;
+}
+
+/// The storage layout before claims could reference a parent.
+pub mod v7 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> =
+		StorageMap<Pallet<T>, <T as Config>::ProofsHasher, ClaimKeyOf<T>, UnlinkedClaimInfo<T>>;
+
+	#[frame_support::storage_alias]
+	pub type HashedProofs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(NamespaceOf<T>, <T as frame_system::Config>::Hash),
+		UnlinkedClaimInfo<T>,
+	>;
+}
+
+/// The storage layout before claims could be tagged with a content type.
+pub mod v8 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> =
+		StorageMap<Pallet<T>, <T as Config>::ProofsHasher, ClaimKeyOf<T>, UntaggedClaimInfo<T>>;
+
+	#[frame_support::storage_alias]
+	pub type HashedProofs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(NamespaceOf<T>, <T as frame_system::Config>::Hash),
+		UntaggedClaimInfo<T>,
+	>;
+}
+
+/// The storage layout before the block of the last transfer was added to [`ClaimInfo`].
+pub mod v9 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> =
+		StorageMap<Pallet<T>, <T as Config>::ProofsHasher, ClaimKeyOf<T>, UntrackedClaimInfo<T>>;
+
+	#[frame_support::storage_alias]
+	pub type HashedProofs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(NamespaceOf<T>, <T as frame_system::Config>::Hash),
+		UntrackedClaimInfo<T>,
+	>;
+}
+
+/// The storage layout before claims could be made private.
+pub mod v10 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type Proofs<T: Config> =
+		StorageMap<Pallet<T>, <T as Config>::ProofsHasher, ClaimKeyOf<T>, PublicClaimInfo<T>>;
+
+	#[frame_support::storage_alias]
+	pub type HashedProofs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(NamespaceOf<T>, <T as frame_system::Config>::Hash),
+		PublicClaimInfo<T>,
+	>;
+}
+
+pub mod v12 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type CreationLog<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		u64,
+		(ClaimOf<T>, <T as frame_system::Config>::AccountId),
+	>;
+}
+
+pub mod v14 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	pub type SwapOffers<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(ClaimKeyOf<T>, ClaimKeyOf<T>),
+		(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId),
+	>;
+}
+
+/// The storage versions whose `Proofs` layout differs from the one before, oldest first.
+const PROOFS_LAYOUTS: [u16; 10] = [1, 2, 3, 5, 6, 7, 8, 9, 10, 12];
+
+/// The first owner and creation block of a plain claim, decoded as `Proofs` was laid out at
+/// storage `version`. `None` if the claim is not stored in that layout.
+fn claim_in_layout<T: Config>(
+	version: u16,
+	namespace: &[u8],
+	claim: &[u8],
+) -> Option<(T::AccountId, T::BlockNumber)> {
+	// 版本 3 之前没有命名空间，存证都相当于位于默认命名空间中
+	if version < 3 {
+		if !namespace.is_empty() {
+			return None
+		}
+		let claim = BytesClaimOf::<T>::try_from(claim.to_vec()).ok()?;
+		return if version < 2 {
+			v1::Proofs::<T>::get(&claim)
+		} else {
+			v2::Proofs::<T>::get(&claim).map(|info| (info.owner, info.created_at))
+		};
+	}
+
+	let key = Pallet::<T>::claim_key(namespace, claim).ok()?;
+	let first_owner = |owners: BoundedVec<T::AccountId, T::MaxOwners>| owners.first().cloned();
+	match version {
+		3 => v3::Proofs::<T>::get(&key).map(|info| (info.owner, info.created_at)),
+		5 => v5::Proofs::<T>::get(&key).map(|info| (info.owner, info.created_at)),
+		6 => v6::Proofs::<T>::get(&key)
+			.and_then(|info| Some((first_owner(info.owners)?, info.created_at))),
+		7 => v7::Proofs::<T>::get(&key)
+			.and_then(|info| Some((first_owner(info.owners)?, info.created_at))),
+		8 => v8::Proofs::<T>::get(&key)
+			.and_then(|info| Some((first_owner(info.owners)?, info.created_at))),
+		9 => v9::Proofs::<T>::get(&key)
+			.and_then(|info| Some((first_owner(info.owners)?, info.created_at))),
+		10 => v10::Proofs::<T>::get(&key)
+			.and_then(|info| Some((first_owner(info.owners)?, info.created_at))),
+		_ => Proofs::<T>::get(&key)
+			.and_then(|info| Some((first_owner(info.owners)?, info.created_at))),
+	}
+}
+
+/// The first owner and creation block of a plain claim, whatever layout it is stored in.
+///
+/// The layout of the on-chain storage version is tried first. While a migration of `Proofs`
+/// is deferred or only partly applied, some claims are still in an older layout or already
+/// in a newer one than the version says, so every other layout is tried after it, newest
+/// first.
+pub fn get_claim_compat<T: Config>(
+	namespace: &[u8],
+	claim: &[u8],
+) -> Option<(T::AccountId, T::BlockNumber)> {
+	// 先按链上存储版本对应的布局解码，版本 0 的布局与版本 1 相同
+	let version = Pallet::<T>::on_chain_storage_version();
+	let current = PROOFS_LAYOUTS
+		.iter()
+		.rev()
+		.copied()
+		.find(|layout| version >= *layout)
+		.unwrap_or(1);
+	sp_std::iter::once(current)
+		.chain(PROOFS_LAYOUTS.iter().rev().copied().filter(|layout| *layout != current))
+		.find_map(|layout| claim_in_layout::<T>(layout, namespace, claim))
+}
+
+/// Translate every `(owner, block_number)` tuple into a [`ClaimInfo`] with empty metadata.
+///
+/// Claims created before this migration never expire and hold no deposit. The
+/// [`ClaimsByOwner`] index is built along the way; claims beyond `MaxClaimsPerAccount` for one
+/// owner are left out of it. Does nothing once the pallet is at storage version 2.
+pub struct MigrateToV2<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 2 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated = 0u64;
+		v2::Proofs::<T>::translate::<(T::AccountId, T::BlockNumber), _>(
+			|claim, (owner, created_at)| {
+				translated += 1;
+				let _ = v2::ClaimsByOwner::<T>::try_mutate(&owner, |claims| {
+					claims.try_push(claim.clone())
+				});
+				Some(OldClaimInfo {
+					owner: owner.clone(),
+					created_at,
+					expiry: None,
+					metadata: Default::default(),
+					depositor: owner,
+					deposit: Zero::zero(),
+				})
+			},
+		);
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(2 * translated + 1, 2 * translated + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let count = (Pallet::<T>::on_chain_storage_version() < 2)
+			.then(|| v1::Proofs::<T>::iter().count() as u32);
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count =
+			Option::<u32>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 2, "storage version not updated");
+		if let Some(count) = count {
+			ensure!(
+				v2::Proofs::<T>::iter().count() as u32 == count,
+				"claims lost during migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Move every claim and every index entry into the default namespace.
+///
+/// Claims are moved at most `MigrationBatchSize` at a time: the first batch in the runtime
+/// upgrade and the rest in `on_initialize` of the following blocks, resuming after the claim in
+/// [`MigrationCursor`]. Until the last batch, which also moves the hash-only claims and the
+/// expiry and owner indexes, signed calls fail with [`Error::Paused`], expired claims are not
+/// purged and the later migrations do nothing; [`MigrateToV3::resume`] runs them once the last
+/// batch is moved. Claims that cannot be converted into `ClaimData` are dropped. Does nothing
+/// once the pallet is at storage version 3.
+pub struct MigrateToV3<T>(PhantomData<T>);
+
+/// The migrations after [`MigrateToV3`], run by [`MigrateToV3::resume`] when the batched
+/// migration finishes in a later block.
+pub type LaterMigrations<T> = (
+	MigrateToV4<T>,
+	MigrateToV5<T>,
+	MigrateToV6<T>,
+	MigrateToV7<T>,
+	MigrateToV8<T>,
+	MigrateToV9<T>,
+	MigrateToV10<T>,
+	MigrateToV11<T>,
+	MigrateToV12<T>,
+	MigrateToV13<T>,
+	MigrateToV14<T>,
+	MigrateToV15<T>,
+	MigrateToV16<T>,
+	MigrateToV17<T>,
+);
+
+impl<T: Config> MigrateToV3<T> {
+	/// Move the next batch from `on_initialize`, and run [`LaterMigrations`] once the last one
+	/// is moved so that the pallet reaches [`STORAGE_VERSION`] before it is unpaused.
+	pub fn resume(limit: u32) -> Weight {
+		let weight = Self::step(limit);
+		if MigrationCursor::<T>::exists() {
+			return weight
+		}
+		weight.saturating_add(LaterMigrations::<T>::on_runtime_upgrade())
+	}
+
+	/// Move up to `limit` claims still in the old layout, and finish the migration once none is
+	/// left.
+	pub fn step(limit: u32) -> Weight {
+		// 每批至少移动一个存证，否则迁移永远无法完成
+		let limit = limit.max(1);
+		let namespace = Pallet::<T>::default_namespace();
+		let key = |claim: BytesClaimOf<T>| {
+			ClaimOf::<T>::try_from(claim.into_inner())
+				.ok()
+				.map(|claim| (namespace.clone(), claim))
+		};
+
+		// 新旧键共用同一前缀，只处理键确实由旧布局生成的存证，已经移动的存证被跳过
+		let mut iter = match MigrationCursor::<T>::get() {
+			Some(cursor) => v2::Proofs::<T>::iter_from(v2::Proofs::<T>::hashed_key_for(&cursor)),
+			None => v2::Proofs::<T>::iter(),
+		};
+		let mut read = 1u64;
+		let mut more = false;
+		let mut batch = Vec::new();
+		while let Some((claim, info)) = iter.next() {
+			read += 1;
+			if iter.last_raw_key() != &v2::Proofs::<T>::hashed_key_for(&claim)[..] {
+				continue
+			}
+			if batch.len() as u32 >= limit {
+				more = true;
+				break
+			}
+			batch.push((claim, info));
+		}
+
+		// 先读出这一批存证再写入，边读边写会改变迭代的位置
+		let moved = batch.len() as u64;
+		let last = batch.last().map(|(claim, _)| claim.clone());
+		for (claim, info) in batch {
+			v2::Proofs::<T>::remove(&claim);
+			let pending = v2::PendingTransfers::<T>::take(&claim);
+			let approval = v2::ClaimApprovals::<T>::take(&claim);
+			if let Some(key) = key(claim) {
+				if let Some(dest) = pending {
+					PendingTransfers::<T>::insert(&key, dest);
+				}
+				if let Some(operator) = approval {
+					ClaimApprovals::<T>::insert(&key, operator);
+				}
+				v3::Proofs::<T>::insert(key, info);
+			}
+		}
+		let weight = T::DbWeight::get().reads_writes(read + 2 * moved, 4 * moved + 1);
+
+		match last {
+			Some(last) if more => {
+				MigrationCursor::<T>::put(last);
+				weight
+			},
+			_ => weight.saturating_add(Self::finish()),
+		}
+	}
+
+	/// Move the hash-only claims and the indexes once every plain claim is moved, and bump the
+	/// storage version.
+	fn finish() -> Weight {
+		let namespace = Pallet::<T>::default_namespace();
+		let key = |claim: BytesClaimOf<T>| {
+			ClaimOf::<T>::try_from(claim.into_inner())
+				.ok()
+				.map(|claim| (namespace.clone(), claim))
+		};
+
+		let hashed: Vec<_> = v2::HashedProofs::<T>::drain().collect();
+		let mut moved = hashed.len() as u64;
+		for (hash, info) in hashed {
+			v3::HashedProofs::<T>::insert((namespace.clone(), hash), info);
+		}
+
+		// 索引中的存证数量不变，转换后不会超出上限
+		ExpiringAt::<T>::translate::<BoundedVec<BytesClaimOf<T>, T::MaxClaimsPerExpiryBlock>, _>(
+			|_, queue| {
+				moved += 1;
+				BoundedVec::try_from(queue.into_iter().filter_map(key).collect::<Vec<_>>()).ok()
+			},
+		);
+		ClaimsByOwner::<T>::translate::<BoundedVec<BytesClaimOf<T>, T::MaxClaimsPerAccount>, _>(
+			|_, claims| {
+				moved += 1;
+				BoundedVec::try_from(claims.into_iter().filter_map(key).collect::<Vec<_>>()).ok()
+			},
+		);
+		MigrationCursor::<T>::kill();
+		StorageVersion::new(3).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(moved, 2 * moved + 2)
+	}
+}
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 3 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		Self::step(T::MigrationBatchSize::get())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let count = (Pallet::<T>::on_chain_storage_version() < 3)
+			.then(|| v2::Proofs::<T>::iter_keys().count() as u32);
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count =
+			Option::<u32>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		// 分批迁移尚未完成时，剩余的存证在之后的区块中移动
+		if MigrationCursor::<T>::exists() {
+			return Ok(())
+		}
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 3, "storage version not updated");
+		if let Some(count) = count {
+			ensure!(
+				Proofs::<T>::iter_keys().count() as u32 == count,
+				"claims lost during migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Initialise [`ProofCount`] with the number of plain and hash-only claims in storage.
+///
+/// The count may start above `MaxTotalProofs`, in which case new claims are rejected until
+/// enough are removed. Does nothing once the pallet is at storage version 4.
+pub struct MigrateToV4<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 4 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let count = Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count();
+		ProofCount::<T>::put(count as u32);
+		StorageVersion::new(4).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(count as u64 + 1, 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 4, "storage version not updated");
+		let count = Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count();
+		ensure!(ProofCount::<T>::get() as usize == count, "proof count does not match storage");
+		Ok(())
+	}
+}
+
+/// Add the `transferable` flag to every plain and hash-only claim. Existing claims stay
+/// transferable. Does nothing once the pallet is at storage version 5.
+pub struct MigrateToV5<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 5 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut translated = 0u64;
+		v5::Proofs::<T>::translate::<OldClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		v5::HashedProofs::<T>::translate::<OldClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		StorageVersion::new(5).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let count = (Pallet::<T>::on_chain_storage_version() < 5).then(|| {
+			(Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count()) as u32
+		});
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count =
+			Option::<u32>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 5, "storage version not updated");
+		if let Some(count) = count {
+			ensure!(
+				(Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count()) as u32
+					== count,
+				"claims lost during migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Turn the single owner of every plain and hash-only claim into a one-entry `owners` list.
+/// Does nothing once the pallet is at storage version 6.
+pub struct MigrateToV6<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 6 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut translated = 0u64;
+		v6::Proofs::<T>::translate::<SingleOwnerClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		v6::HashedProofs::<T>::translate::<SingleOwnerClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		StorageVersion::new(6).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let count = (Pallet::<T>::on_chain_storage_version() < 6).then(|| {
+			(Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count()) as u32
+		});
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count =
+			Option::<u32>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 6, "storage version not updated");
+		if let Some(count) = count {
+			ensure!(
+				(v6::Proofs::<T>::iter().count() + v6::HashedProofs::<T>::iter().count()) as u32
+					== count,
+				"claims lost during migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Add the creation time to every plain and hash-only claim. It is not known for existing
+/// claims, so they get none. Does nothing once the pallet is at storage version 7.
+pub struct MigrateToV7<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 7 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut translated = 0u64;
+		v7::Proofs::<T>::translate::<UntimedClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		v7::HashedProofs::<T>::translate::<UntimedClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		StorageVersion::new(7).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let count = (Pallet::<T>::on_chain_storage_version() < 7).then(|| {
+			(Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count()) as u32
+		});
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count =
+			Option::<u32>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 7, "storage version not updated");
+		if let Some(count) = count {
+			ensure!(
+				(v7::Proofs::<T>::iter().count() + v7::HashedProofs::<T>::iter().count()) as u32
+					== count,
+				"claims lost during migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Add an empty parent to every plain and hash-only claim, as existing claims were created
+/// without one. Does nothing once the pallet is at storage version 8.
+pub struct MigrateToV8<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 8 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut translated = 0u64;
+		v8::Proofs::<T>::translate::<UnlinkedClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		v8::HashedProofs::<T>::translate::<UnlinkedClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		StorageVersion::new(8).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let count = (Pallet::<T>::on_chain_storage_version() < 8).then(|| {
+			(Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count()) as u32
+		});
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count =
+			Option::<u32>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 8, "storage version not updated");
+		if let Some(count) = count {
+			ensure!(
+				(v8::Proofs::<T>::iter().count() + v8::HashedProofs::<T>::iter().count()) as u32
+					== count,
+				"claims lost during migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Add an empty content type to every plain and hash-only claim, as existing claims were
+/// never tagged. Does nothing once the pallet is at storage version 9.
+pub struct MigrateToV9<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 9 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut translated = 0u64;
+		v9::Proofs::<T>::translate::<UntaggedClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		v9::HashedProofs::<T>::translate::<UntaggedClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		StorageVersion::new(9).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let count = (Pallet::<T>::on_chain_storage_version() < 9).then(|| {
+			(Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count()) as u32
+		});
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count =
+			Option::<u32>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 9, "storage version not updated");
+		if let Some(count) = count {
+			ensure!(
+				(v9::Proofs::<T>::iter().count() + v9::HashedProofs::<T>::iter().count()) as u32
+					== count,
+				"claims lost during migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Add an empty last transfer to every plain and hash-only claim, so no existing claim is held
+/// back by the transfer cooldown. Does nothing once the pallet is at storage version 10.
+pub struct MigrateToV10<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV10<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 10 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut translated = 0u64;
+		v10::Proofs::<T>::translate::<UntrackedClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		v10::HashedProofs::<T>::translate::<UntrackedClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		StorageVersion::new(10).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let count = (Pallet::<T>::on_chain_storage_version() < 10).then(|| {
+			(Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count()) as u32
+		});
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count =
+			Option::<u32>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 10, "storage version not updated");
+		if let Some(count) = count {
+			ensure!(
+				(v10::Proofs::<T>::iter().count() + v10::HashedProofs::<T>::iter().count()) as u32
+					== count,
+				"claims lost during migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Initialise [`NamespaceCount`] with the number of plain and hash-only claims in each
+/// namespace.
+///
+/// A count may start above `MaxClaimsPerNamespace`, in which case new claims in that
+/// namespace are rejected until enough are removed. Does nothing once the pallet is at storage
+/// version 11.
+pub struct MigrateToV11<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV11<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 11 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut counted = 0u64;
+		let namespaces = Proofs::<T>::iter_keys()
+			.map(|(namespace, _)| namespace)
+			.chain(HashedProofs::<T>::iter_keys().map(|(namespace, _)| namespace));
+		for namespace in namespaces {
+			counted += 1;
+			NamespaceCount::<T>::mutate(namespace, |count| *count = count.saturating_add(1));
+		}
+		StorageVersion::new(11).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(counted * 2 + 1, counted + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 11, "storage version not updated");
+		let count = Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count();
+		ensure!(
+			NamespaceCount::<T>::iter_values().map(|count| count as usize).sum::<usize>() == count,
+			"namespace counts do not match storage"
+		);
+		Ok(())
+	}
+}
+
+/// Mark every plain and hash-only claim as public, so existing claims stay visible to everyone.
+/// Does nothing once the pallet is at storage version 12.
+pub struct MigrateToV12<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV12<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 12 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut translated = 0u64;
+		Proofs::<T>::translate::<PublicClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		HashedProofs::<T>::translate::<PublicClaimInfo<T>, _>(|_, info| {
+			translated += 1;
+			Some(info.upgrade())
+		});
+		StorageVersion::new(12).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let count = (Pallet::<T>::on_chain_storage_version() < 12).then(|| {
+			(Proofs::<T>::iter_keys().count() + HashedProofs::<T>::iter_keys().count()) as u32
+		});
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count =
+			Option::<u32>::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 12, "storage version not updated");
+		if let Some(count) = count {
+			ensure!(
+				(Proofs::<T>::iter().count() + HashedProofs::<T>::iter().count()) as u32 == count,
+				"claims lost during migration"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Store the namespace of every [`CreationLog`] entry along with its claim, so
+/// [`Pallet::recent_claims`] can leave out private claims.
+///
+/// The namespace is taken from the claims the creator still owns. Entries whose claim the
+/// creator no longer owns are dropped, as their namespace is unknown. Does nothing once the
+/// pallet is at storage version 13.
+pub struct MigrateToV13<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV13<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 13 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut translated = 0u64;
+		CreationLog::<T>::translate::<(ClaimOf<T>, T::AccountId), _>(|_, (claim, creator)| {
+			translated += 1;
+			// 只能从创建者仍然持有的存证中找到命名空间
+			ClaimsByOwner::<T>::get(&creator)
+				.into_iter()
+				.find(|key| key.1 == claim)
+				.map(|key| (key, creator))
+		});
+		StorageVersion::new(13).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated * 2 + 1, translated + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 13, "storage version not updated");
+		Ok(())
+	}
+}
+
+/// Queue every existing [`Tombstones`] entry in [`TombstoneQueue`], oldest first, so
+/// [`Pallet::purge_tombstones`] eventually removes the tombstones left before the queue was
+/// introduced.
+///
+/// All tombstones are read and sorted before any is queued, which costs memory proportional to
+/// the number of tombstones. Does nothing once the pallet is at storage version 14.
+pub struct MigrateToV14<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV14<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 14 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		// 队列要求按留下墓碑的区块排序
+		let mut tombstones: Vec<_> =
+			Tombstones::<T>::iter().map(|(key, buried)| (buried, key)).collect();
+		tombstones.sort_by(|a, b| a.0.cmp(&b.0));
+
+		let queued = tombstones.len() as u64;
+		let (head, mut tail) = TombstoneQueueBounds::<T>::get();
+		for (_, key) in tombstones {
+			TombstoneQueue::<T>::insert(tail, key);
+			tail = tail.saturating_add(1);
+		}
+		TombstoneQueueBounds::<T>::put((head, tail));
+		StorageVersion::new(14).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(queued + 2, queued + 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 14, "storage version not updated");
+		ensure!(
+			TombstoneQueue::<T>::iter_values().count() >= Tombstones::<T>::iter_keys().count(),
+			"tombstones left out of the queue"
+		);
+		Ok(())
+	}
+}
+
+/// Record a zero deposit for every existing [`SwapOffers`] entry and index it in
+/// [`SwapOffersOf`] under both of its claims, so the offer is dropped once either claim changes
+/// hands.
+///
+/// Offers that do not fit into the index of one of their claims are dropped. Does nothing once
+/// the pallet is at storage version 15.
+pub struct MigrateToV15<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV15<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 15 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut translated = 0u64;
+		SwapOffers::<T>::translate::<(T::AccountId, T::AccountId), _>(
+			|offer, (offerer, counterparty)| {
+				translated += 1;
+				// 两个存证的索引都放得下时才保留报价
+				let fits = [&offer.0, &offer.1].into_iter().all(|claim| {
+					(SwapOffersOf::<T>::decode_len(claim).unwrap_or(0) as u32)
+						< T::MaxSwapOffersPerClaim::get()
+				});
+				if !fits {
+					return None
+				}
+				for claim in [&offer.0, &offer.1] {
+					SwapOffersOf::<T>::mutate(claim, |offers| {
+						let _ = offers.try_push(offer.clone());
+					});
+				}
+				// 旧报价没有收取押金
+				Some((offerer, counterparty, Zero::zero()))
+			},
+		);
+		StorageVersion::new(15).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(translated * 3 + 1, translated * 3 + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 15, "storage version not updated");
+		ensure!(
+			SwapOffers::<T>::iter_keys().all(|offer| {
+				SwapOffersOf::<T>::get(&offer.0).contains(&offer)
+					&& SwapOffersOf::<T>::get(&offer.1).contains(&offer)
+			}),
+			"swap offer missing from the index"
+		);
+		Ok(())
+	}
+}
+
+/// Index every existing [`HashedProofs`] entry in [`HashedClaimsByOwner`] under its owners, so
+/// hashed claims count against the account quota and are deleted when their owner is reaped.
+///
+/// Claims that do not fit into the index of an owner stay unindexed for that owner. Does nothing
+/// once the pallet is at storage version 16.
+pub struct MigrateToV16<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV16<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 16 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut indexed = 0u64;
+		for (key, info) in HashedProofs::<T>::iter() {
+			for owner in &info.owners {
+				indexed += 1;
+				HashedClaimsByOwner::<T>::mutate(owner, |claims| {
+					let _ = claims.try_push(key.clone());
+				});
+			}
+		}
+		StorageVersion::new(16).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(indexed * 2 + 1, indexed + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 16, "storage version not updated");
+		ensure!(
+			HashedProofs::<T>::iter().all(|(key, info)| {
+				info.owners.iter().all(|owner| {
+					let owned = HashedClaimsByOwner::<T>::get(owner);
+					owned.contains(&key) || owned.len() as u32 == T::MaxClaimsPerAccount::get()
+				})
+			}),
+			"hashed claim missing from its owner's index"
+		);
+		Ok(())
+	}
+}
+
+/// Drop every [`CreationLog`] entry older than the last `MaxRecentClaims`, which the log was not
+/// bounded by before. Does nothing once the pallet is at storage version 17.
+pub struct MigrateToV17<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV17<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// 分批迁移到版本 3 的过程中不做任何修改
+		if Pallet::<T>::on_chain_storage_version() >= 17 || MigrationCursor::<T>::exists() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let oldest_kept = NextSeq::<T>::get().saturating_sub(T::MaxRecentClaims::get().into());
+		let mut read = 0u64;
+		let pruned: Vec<u64> = CreationLog::<T>::iter_keys()
+			.filter(|seq| {
+				read += 1;
+				*seq < oldest_kept
+			})
+			.collect();
+		for seq in &pruned {
+			CreationLog::<T>::remove(seq);
+		}
+		StorageVersion::new(17).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(read + 3, pruned.len() as u64 + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(Pallet::<T>::on_chain_storage_version() >= 17, "storage version not updated");
+		ensure!(
+			CreationLog::<T>::iter_keys().count() as u32 <= T::MaxRecentClaims::get(),
+			"creation log longer than MaxRecentClaims"
+		);
+		Ok(())
+	}
+}
+
+/// The storage name of [`Proofs`].
+const PROOFS: &[u8] = b"Proofs";
+
+/// Move every claim in [`Proofs`] from keys hashed with `OldHasher` to keys hashed with
+/// `ProofsHasher`. Run it once in the runtime upgrade that changes `ProofsHasher`.
+///
+/// Only entries whose key really is the `OldHasher` key of their claim are moved, so entries
+/// already under the new hasher are left alone and running the migration again loses nothing.
+/// All claims are read before any is written back, which costs memory proportional to the
+/// number of claims.
+pub struct MigrateProofsHasher<T, OldHasher>(PhantomData<(T, OldHasher)>);
+
+impl<T: Config, OldHasher: ReversibleStorageHasher> OnRuntimeUpgrade
+	for MigrateProofsHasher<T, OldHasher>
+{
+	fn on_runtime_upgrade() -> Weight {
+		let pallet = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+		let prefix = storage_prefix(pallet, PROOFS);
+
+		// 先读出旧键下的全部存证再写入，新旧键共用同一前缀，边读边写会重复处理
+		let mut read = 0u64;
+		let moved: Vec<_> =
+			storage_key_iter::<ClaimKeyOf<T>, ClaimInfo<T>, OldHasher>(pallet, PROOFS)
+				.filter_map(|(key, info)| {
+					read += 1;
+					let old_key = [&prefix[..], OldHasher::hash(&key.encode()).as_ref()].concat();
+					// 只移动键确实由旧 hash 算法生成的存证
+					let moved =
+						unhashed::exists(&old_key) && old_key != Proofs::<T>::hashed_key_for(&key);
+					moved.then(|| (old_key, key, info))
+				})
+				.collect();
+
+		let written = moved.len() as u64;
+		for (old_key, key, info) in moved {
+			unhashed::kill(&old_key);
+			Proofs::<T>::insert(key, info);
+		}
+
+		T::DbWeight::get().reads_writes(read + written, written * 2)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let pallet = <Pallet<T> as PalletInfoAccess>::name().as_bytes();
+		Ok((storage_iter::<ClaimInfo<T>>(pallet, PROOFS).count() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+		ensure!(Proofs::<T>::iter().count() as u32 == count, "claims lost during migration");
+		Ok(())
+	}
+}
@@ -0,0 +1,423 @@
+use crate as pallet_poe;
+use codec::{Decode, DecodeAll, Encode, MaxEncodedLen};
+use frame_support::{
+	parameter_types,
+	traits::{ConstBool, ConstU16, ConstU32, ConstU64, ConstU8, EnsureOrigin, GenesisBuild},
+	Blake2_128Concat, BoundedVec, RuntimeDebug, Twox64Concat,
+};
+use frame_system as system;
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{
+	testing::{Header, TestXt},
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// The account that receives claim creation fees.
+pub const TREASURY: u64 = 99;
+
+/// The Unix time in milliseconds at which the test externalities start.
+pub const MOMENT: u64 = 1_600_000_000_000;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		PoeModule: pallet_poe,
+	}
+);
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = PoeModule;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<5>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub static MaxClaimLength: u32 = 10;
+	pub static MinClaimLength: u32 = 2;
+	pub static MaxClaimsPerBlockPerAccount: u32 = 10;
+	pub static EnforceCreatorWhitelist: bool = false;
+	pub static ClaimCreationFee: u64 = 0;
+	pub static TransferLockPeriod: u64 = 0;
+	pub static DefaultAccountQuota: u32 = 5;
+	pub static RequireRecipientExists: bool = false;
+	pub static RequireUtf8: bool = false;
+	pub static CaseInsensitiveClaims: bool = false;
+	pub static LastTransfer: Option<(Vec<u8>, u64, u64)> = None;
+	pub static MaxNamespacesPerAccount: u32 = 3;
+	pub static TransferCooldown: u64 = 0;
+	pub static MaxTotalProofs: u32 = 1_000;
+	pub static CapacityWarningThresholdPercent: u8 = 90;
+	pub static MaxClaimsPerNamespace: u32 = 1_000;
+	pub static MigrationBatchSize: u32 = 100;
+	pub static DefaultAcceptsClaims: bool = true;
+	pub static TombstoneRetention: u64 = 0;
+	pub static DefaultClaimExpiry: u64 = 10;
+}
+
+impl pallet_poe::Config for Test {
+	type MaxClaimLength = MaxClaimLength;
+	type ClaimData = BoundedVec<u8, MaxClaimLength>;
+	type ProofsHasher = Blake2_128Concat;
+	type MaxNamespaceLength = ConstU32<8>;
+	type MinClaimLength = MinClaimLength;
+	type DefaultClaimExpiry = DefaultClaimExpiry;
+	type MaxClaimsPerExpiryBlock = ConstU32<2_000>;
+	type MaxExpiredPerBlock = ConstU32<400>;
+	type MaxMetadataLength = ConstU32<16>;
+	type MaxClaimsPerAccount = ConstU32<8>;
+	type MaxBatchSize = ConstU32<4>;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	#[cfg(feature = "claim-deposits")]
+	type Deposits = pallet_poe::deposits::CurrencyDeposits<Balances, SlashToTreasury>;
+	#[cfg(not(feature = "claim-deposits"))]
+	type Deposits = pallet_poe::deposits::NoDeposits<u64>;
+	#[cfg(feature = "claim-deposits")]
+	type ClaimBaseDeposit = ConstU64<10>;
+	#[cfg(feature = "claim-deposits")]
+	type ClaimByteDeposit = ConstU64<1>;
+	#[cfg(not(feature = "claim-deposits"))]
+	type ClaimBaseDeposit = ConstU64<0>;
+	#[cfg(not(feature = "claim-deposits"))]
+	type ClaimByteDeposit = ConstU64<0>;
+	type PauseOrigin = frame_system::EnsureRoot<u64>;
+	type MaxTotalProofs = MaxTotalProofs;
+	type MaxClaimsPerBlockPerAccount = MaxClaimsPerBlockPerAccount;
+	type ExpiryWarningWindow = ConstU64<3>;
+	type UnsignedPriority = ConstU64<100>;
+	type MaxOwners = ConstU32<3>;
+	type CreatorAdminOrigin = frame_system::EnsureRoot<u64>;
+	type EnforceCreatorWhitelist = EnforceCreatorWhitelist;
+	type MaxHistoryLength = ConstU32<3>;
+	type FeeDestination = ConstU64<TREASURY>;
+	type ClaimCreationFee = ClaimCreationFee;
+	type TransferLockPeriod = TransferLockPeriod;
+	type MaxDelegates = ConstU32<2>;
+	type DefaultAccountQuota = DefaultAccountQuota;
+	type QuotaOrigin = frame_system::EnsureRoot<u64>;
+	type FreezeOrigin = frame_system::EnsureRoot<u64>;
+	type CreateOrigin = EnsureSignedOrCollective;
+	type UnixTime = Timestamp;
+	type RequireRecipientExists = RequireRecipientExists;
+	type RequireUtf8 = RequireUtf8;
+	type MaxAllowed = ConstU32<2>;
+	type OnClaimTransfer = RecordTransfer;
+	type MaxNamespacesPerAccount = MaxNamespacesPerAccount;
+	type TransferCooldown = TransferCooldown;
+	type CapacityWarningThresholdPercent = CapacityWarningThresholdPercent;
+	type MaxClaimsPerNamespace = MaxClaimsPerNamespace;
+	type MaxClaimTtl = ConstU64<100>;
+	type ChallengeEpochLength = ConstU64<10>;
+	type CaseInsensitiveClaims = CaseInsensitiveClaims;
+	type MaxViewers = ConstU32<2>;
+	type MigrationBatchSize = MigrationBatchSize;
+	type DefaultAcceptsClaims = DefaultAcceptsClaims;
+	type TombstoneRetention = TombstoneRetention;
+	#[cfg(feature = "claim-deposits")]
+	type SwapOfferDeposit = ConstU64<5>;
+	#[cfg(not(feature = "claim-deposits"))]
+	type SwapOfferDeposit = ConstU64<0>;
+	type MaxSwapOffersPerClaim = ConstU32<2>;
+	type MaxRecentClaims = ConstU32<4>;
+}
+
+/// Records the last claim transfer in `LastTransfer`.
+pub struct RecordTransfer;
+
+impl pallet_poe::OnClaimTransferHandler<u64> for RecordTransfer {
+	fn on_transfer(claim: &[u8], from: &u64, to: &u64) {
+		LastTransfer::set(Some((claim.to_vec(), *from, *to)));
+	}
+}
+
+/// Sends slashed deposits to `TREASURY`, so that tests can tell them from burned ones.
+#[cfg(feature = "claim-deposits")]
+pub struct SlashToTreasury;
+
+#[cfg(feature = "claim-deposits")]
+impl frame_support::traits::OnUnbalanced<pallet_balances::NegativeImbalance<Test>>
+	for SlashToTreasury
+{
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+		use frame_support::traits::Currency;
+		Balances::resolve_creating(&TREASURY, amount);
+	}
+}
+
+/// The account owning the claims created by the root origin, standing in for a collective.
+pub const COLLECTIVE: u64 = 98;
+
+/// Lets signed accounts create claims for themselves and resolves the root origin to
+/// `COLLECTIVE`.
+pub struct EnsureSignedOrCollective;
+
+impl EnsureOrigin<RuntimeOrigin> for EnsureSignedOrCollective {
+	type Success = u64;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<u64, RuntimeOrigin> {
+		o.into().and_then(|o| match o {
+			system::RawOrigin::Signed(who) => Ok(who),
+			system::RawOrigin::Root => Ok(COLLECTIVE),
+			r => Err(RuntimeOrigin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> RuntimeOrigin {
+		RuntimeOrigin::root()
+	}
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl<C> system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with_claims(vec![])
+}
+
+// Build genesis storage with the given claims preloaded.
+pub fn new_test_ext_with_claims(initial_claims: Vec<(Vec<u8>, u64)>) -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1_000), (2, 1_000), (3, 1_000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	pallet_poe::GenesisConfig::<Test> { initial_claims }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| {
+		// 事件只会在区块号大于 0 时被记录
+		System::set_block_number(1);
+		Timestamp::set_timestamp(MOMENT);
+	});
+	ext
+}
+
+/// A structured claim: a codec identifier and the hash of the content it addresses.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ContentAddress(pub u32, pub H256);
+
+impl TryFrom<Vec<u8>> for ContentAddress {
+	type Error = codec::Error;
+
+	fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+		Self::decode_all(&mut &bytes[..])
+	}
+}
+
+impl From<ContentAddress> for Vec<u8> {
+	fn from(address: ContentAddress) -> Self {
+		address.encode()
+	}
+}
+
+// A second mock runtime that stores claims as `ContentAddress` instead of raw bytes.
+pub mod content_address {
+	use super::*;
+
+	frame_support::construct_runtime!(
+		pub enum ContentTest where
+			Block = frame_system::mocking::MockBlock<ContentTest>,
+			NodeBlock = frame_system::mocking::MockBlock<ContentTest>,
+			UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<ContentTest>,
+		{
+			System: frame_system,
+			Balances: pallet_balances,
+			Timestamp: pallet_timestamp,
+			PoeModule: pallet_poe,
+		}
+	);
+
+	impl system::Config for ContentTest {
+		type BaseCallFilter = frame_support::traits::Everything;
+		type BlockWeights = ();
+		type BlockLength = ();
+		type DbWeight = ();
+		type RuntimeOrigin = RuntimeOrigin;
+		type RuntimeCall = RuntimeCall;
+		type Index = u64;
+		type BlockNumber = u64;
+		type Hash = H256;
+		type Hashing = BlakeTwo256;
+		type AccountId = u64;
+		type Lookup = IdentityLookup<Self::AccountId>;
+		type Header = Header;
+		type RuntimeEvent = RuntimeEvent;
+		type BlockHashCount = ConstU64<250>;
+		type Version = ();
+		type PalletInfo = PalletInfo;
+		type AccountData = pallet_balances::AccountData<u64>;
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+		type SS58Prefix = ConstU16<42>;
+		type OnSetCode = ();
+		type MaxConsumers = frame_support::traits::ConstU32<16>;
+	}
+
+	impl pallet_balances::Config for ContentTest {
+		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
+		type Balance = u64;
+		type RuntimeEvent = RuntimeEvent;
+		type DustRemoval = ();
+		type ExistentialDeposit = ConstU64<1>;
+		type AccountStore = System;
+		type WeightInfo = ();
+	}
+
+	impl pallet_timestamp::Config for ContentTest {
+		type Moment = u64;
+		type OnTimestampSet = ();
+		type MinimumPeriod = ConstU64<5>;
+		type WeightInfo = ();
+	}
+
+	impl pallet_poe::Config for ContentTest {
+		type MaxClaimLength = ConstU32<36>;
+		type ClaimData = ContentAddress;
+		type ProofsHasher = Twox64Concat;
+		type MaxNamespaceLength = ConstU32<8>;
+		type MinClaimLength = ConstU32<2>;
+		type DefaultClaimExpiry = ConstU64<10>;
+		type MaxClaimsPerExpiryBlock = ConstU32<2_000>;
+		type MaxExpiredPerBlock = ConstU32<400>;
+		type MaxMetadataLength = ConstU32<32>;
+		type MaxClaimsPerAccount = ConstU32<5>;
+		type MaxBatchSize = ConstU32<4>;
+		type RuntimeEvent = RuntimeEvent;
+		type WeightInfo = ();
+		#[cfg(feature = "claim-deposits")]
+		type Deposits = pallet_poe::deposits::HoldDeposits<Balances, ()>;
+		#[cfg(not(feature = "claim-deposits"))]
+		type Deposits = pallet_poe::deposits::NoDeposits<u64>;
+		#[cfg(feature = "claim-deposits")]
+		type ClaimBaseDeposit = ConstU64<10>;
+		#[cfg(feature = "claim-deposits")]
+		type ClaimByteDeposit = ConstU64<1>;
+		#[cfg(not(feature = "claim-deposits"))]
+		type ClaimBaseDeposit = ConstU64<0>;
+		#[cfg(not(feature = "claim-deposits"))]
+		type ClaimByteDeposit = ConstU64<0>;
+		type PauseOrigin = frame_system::EnsureRoot<u64>;
+		type MaxTotalProofs = ConstU32<1_000>;
+		type MaxClaimsPerBlockPerAccount = ConstU32<10>;
+		type ExpiryWarningWindow = ConstU64<3>;
+		type UnsignedPriority = ConstU64<100>;
+		type MaxOwners = ConstU32<3>;
+		type CreatorAdminOrigin = frame_system::EnsureRoot<u64>;
+		type EnforceCreatorWhitelist = ConstBool<false>;
+		type MaxHistoryLength = ConstU32<3>;
+		type FeeDestination = ConstU64<TREASURY>;
+		type ClaimCreationFee = ConstU64<0>;
+		type TransferLockPeriod = ConstU64<0>;
+		type MaxDelegates = ConstU32<2>;
+		type DefaultAccountQuota = ConstU32<5>;
+		type QuotaOrigin = frame_system::EnsureRoot<u64>;
+		type FreezeOrigin = frame_system::EnsureRoot<u64>;
+		type CreateOrigin = frame_system::EnsureSigned<u64>;
+		type UnixTime = Timestamp;
+		type RequireRecipientExists = ConstBool<false>;
+		type RequireUtf8 = ConstBool<false>;
+		type MaxAllowed = ConstU32<2>;
+		type OnClaimTransfer = ();
+		type MaxNamespacesPerAccount = ConstU32<3>;
+		type TransferCooldown = ConstU64<0>;
+		type CapacityWarningThresholdPercent = ConstU8<90>;
+		type MaxClaimsPerNamespace = ConstU32<1_000>;
+		type MaxClaimTtl = ConstU64<100>;
+		type ChallengeEpochLength = ConstU64<10>;
+		type CaseInsensitiveClaims = ConstBool<false>;
+		type MaxViewers = ConstU32<2>;
+		type MigrationBatchSize = ConstU32<100>;
+		type DefaultAcceptsClaims = ConstBool<true>;
+		type TombstoneRetention = ConstU64<0>;
+		type SwapOfferDeposit = ConstU64<0>;
+		type MaxSwapOffersPerClaim = ConstU32<2>;
+		type MaxRecentClaims = ConstU32<4>;
+	}
+
+	impl<C> system::offchain::SendTransactionTypes<C> for ContentTest
+	where
+		RuntimeCall: From<C>,
+	{
+		type OverarchingCall = RuntimeCall;
+		type Extrinsic = TestXt<RuntimeCall, ()>;
+	}
+
+	// Build genesis storage for the content-address runtime.
+	pub fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = system::GenesisConfig::default().build_storage::<ContentTest>().unwrap();
+		pallet_balances::GenesisConfig::<ContentTest> { balances: vec![(1, 1_000)] }
+			.assimilate_storage(&mut t)
+			.unwrap();
+
+		let mut ext: sp_io::TestExternalities = t.into();
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			Timestamp::set_timestamp(MOMENT);
+		});
+		ext
+	}
+}
@@ -0,0 +1,60 @@
+//! A compact binary delta that derives a new claim from a stored one.
+//!
+//! A patch is the SCALE encoding of a `Vec<PatchOp>`. Applying it builds the new claim by
+//! appending the output of every operation in turn, so unchanged runs of a large claim cost a
+//! few bytes to reference instead of being uploaded again.
+
+use codec::{Decode, DecodeAll, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// One operation of a patch.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PatchOp {
+	/// Append `len` bytes of the original claim, starting at `offset`.
+	Copy {
+		#[codec(compact)]
+		offset: u32,
+		#[codec(compact)]
+		len: u32,
+	},
+	/// Append the given bytes.
+	Insert(Vec<u8>),
+}
+
+/// Why a patch could not be applied.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum PatchError {
+	/// The patch does not decode, or copies bytes outside the original claim.
+	Invalid,
+	/// The patched claim would be longer than the allowed maximum.
+	ResultTooLong,
+}
+
+/// Encode `ops` as a patch.
+pub fn encode(ops: &[PatchOp]) -> Vec<u8> {
+	ops.encode()
+}
+
+/// Apply `patch` to `original`, failing once the result grows past `max_len` bytes.
+pub fn apply(original: &[u8], patch: &[u8], max_len: u32) -> Result<Vec<u8>, PatchError> {
+	let ops = Vec::<PatchOp>::decode_all(&mut &patch[..]).map_err(|_| PatchError::Invalid)?;
+
+	let mut patched = Vec::new();
+	for op in ops {
+		// 先检查结果长度再追加，避免构造超长的结果
+		let bytes = match &op {
+			PatchOp::Copy { offset, len } => {
+				let end = offset.checked_add(*len).ok_or(PatchError::Invalid)?;
+				original.get(*offset as usize..end as usize).ok_or(PatchError::Invalid)?
+			},
+			PatchOp::Insert(bytes) => &bytes[..],
+		};
+		if patched.len().saturating_add(bytes.len()) > max_len as usize {
+			return Err(PatchError::ResultTooLong)
+		}
+		patched.extend_from_slice(bytes);
+	}
+	Ok(patched)
+}
@@ -0,0 +1,113 @@
+//! Transaction checks that reject doomed claim calls before they enter a block.
+
+use crate::{Call, Config, Pallet};
+use codec::{Decode, Encode};
+use frame_support::traits::{Get, IsSubType};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::{fmt, marker::PhantomData, prelude::*};
+
+/// The `InvalidTransaction::Custom` code returned for claims longer than `MaxClaimLength`.
+pub const CLAIM_TOO_LONG: u8 = 1;
+
+/// Reject claim calls with a claim longer than `MaxClaimLength` at the transaction pool, before
+/// they pay for inclusion. Covers `create_claim`, `create_claim_if_absent`, `create_hashed_claim`,
+/// `transfer_claim`, `transfer_claim_with_metadata`, `propose_transfer`, `revoke_claim` and
+/// `renew_claim`, both claims of `swap_claims` and `supersede_claim`, and every claim of
+/// `create_claims`, `create_claims_best_effort` and `transfer_many`. The length is taken after
+/// [`Pallet::normalized_claim`], as the pallet does.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckClaimLength<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckClaimLength<T> {
+	/// Create a new instance of the check.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckClaimLength<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> fmt::Debug for CheckClaimLength<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "CheckClaimLength")
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckClaimLength<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckClaimLength";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let claims: Vec<&[u8]> = match call.is_sub_type() {
+			Some(Call::create_claim { claim, .. }) => vec![&claim[..]],
+			Some(Call::create_claim_if_absent { claim, .. }) => vec![&claim[..]],
+			Some(Call::create_hashed_claim { claim, .. }) => vec![&claim[..]],
+			Some(Call::transfer_claim { claim, .. }) => vec![&claim[..]],
+			Some(Call::transfer_claim_with_metadata { claim, .. }) => vec![&claim[..]],
+			Some(Call::propose_transfer { claim, .. }) => vec![&claim[..]],
+			Some(Call::revoke_claim { claim, .. }) => vec![&claim[..]],
+			Some(Call::renew_claim { claim, .. }) => vec![&claim[..]],
+			Some(Call::swap_claims { my_claim, their_claim, .. }) => {
+				vec![&my_claim[..], &their_claim[..]]
+			},
+			Some(Call::supersede_claim { old_claim, new_claim, .. }) => {
+				vec![&old_claim[..], &new_claim[..]]
+			},
+			Some(Call::create_claims { claims, .. }) => claims.iter().map(|c| &c[..]).collect(),
+			Some(Call::create_claims_best_effort { claims, .. }) => {
+				claims.iter().map(|c| &c[..]).collect()
+			},
+			Some(Call::transfer_many { transfers, .. }) => {
+				transfers.iter().map(|(claim, _)| &claim[..]).collect()
+			},
+			_ => return Ok(ValidTransaction::default()),
+		};
+
+		// 与模块一样按规范化之后的长度比较，批量调用中任何一个过长都拒绝
+		let max = T::MaxClaimLength::get();
+		if claims
+			.into_iter()
+			.any(|claim| Pallet::<T>::normalized_claim(claim).len() as u32 > max)
+		{
+			return Err(InvalidTransaction::Custom(CLAIM_TOO_LONG).into())
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
@@ -0,0 +1,7344 @@
+use crate::{
+	migrations,
+	mock::*,
+	patch::{self, PatchOp},
+	signed_extensions::{CheckClaimLength, CLAIM_TOO_LONG},
+	AcceptsClaims, AccountQuota, AllowedCreators, CapacityWarned, ClaimApprovals, ClaimGenerations,
+	ClaimHistory, ClaimInfo, ClaimKeyOf, ClaimStatus, ClaimsByOwner, ClaimsThisBlock, CreationLog,
+	Error, Escrows, Event, EventSeq, ExpiringAt, ExpiryCursor, FrozenClaims, HashedClaimsByOwner,
+	HashedProofs, Labels, MigrationCursor, NamespaceCount, NamespaceOf, NamespaceOwners,
+	NamespacesOwned, NextSeq, Paused, PendingTransfers, ProofCount, Proofs, RevokeDelegates,
+	RevokeReason, SimulateResult, Superseded, SwapOffers, SwapOffersOf, TombstoneQueue,
+	TombstoneQueueBounds, Tombstones, TransferAllowList, Viewers, WeightInfo,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_noop, assert_ok, assert_storage_noop,
+	dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo},
+	traits::{Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+use scale_info::{TypeDef, TypeInfo};
+use sp_core::{
+	offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	H256,
+};
+use sp_runtime::{
+	traits::{BadOrigin, Dispatchable, SignedExtension, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource},
+};
+
+const ALICE: u64 = 1;
+const BOB: u64 = 2;
+const CHARLIE: u64 = 3;
+
+fn bounded(claim: &[u8]) -> BoundedVec<u8, <Test as crate::Config>::MaxClaimLength> {
+	BoundedVec::try_from(claim.to_vec()).unwrap()
+}
+
+fn ns(namespace: &[u8]) -> NamespaceOf<Test> {
+	BoundedVec::try_from(namespace.to_vec()).unwrap()
+}
+
+fn key(claim: &[u8]) -> ClaimKeyOf<Test> {
+	(ns(b""), bounded(claim))
+}
+
+fn info(owner: u64, created_at: u64, expiry: Option<u64>, deposit: u64) -> ClaimInfo<Test> {
+	ClaimInfo {
+		owners: BoundedVec::truncate_from(vec![owner]),
+		created_at,
+		created_moment: Some(MOMENT),
+		expiry,
+		metadata: Default::default(),
+		depositor: owner,
+		deposit,
+		transferable: true,
+		parent: None,
+		content_type: None,
+		last_transferred_at: None,
+		private: false,
+	}
+}
+
+fn public_info(
+	owner: u64,
+	created_at: u64,
+	expiry: Option<u64>,
+	deposit: u64,
+) -> migrations::PublicClaimInfo<Test> {
+	migrations::PublicClaimInfo {
+		owners: BoundedVec::truncate_from(vec![owner]),
+		created_at,
+		created_moment: Some(MOMENT),
+		expiry,
+		metadata: Default::default(),
+		depositor: owner,
+		deposit,
+		transferable: true,
+		parent: None,
+		content_type: None,
+		last_transferred_at: None,
+	}
+}
+
+fn untracked_info(
+	owner: u64,
+	created_at: u64,
+	expiry: Option<u64>,
+	deposit: u64,
+) -> migrations::UntrackedClaimInfo<Test> {
+	migrations::UntrackedClaimInfo {
+		owners: BoundedVec::truncate_from(vec![owner]),
+		created_at,
+		created_moment: Some(MOMENT),
+		expiry,
+		metadata: Default::default(),
+		depositor: owner,
+		deposit,
+		transferable: true,
+		parent: None,
+		content_type: None,
+	}
+}
+
+fn untagged_info(
+	owner: u64,
+	created_at: u64,
+	expiry: Option<u64>,
+	deposit: u64,
+) -> migrations::UntaggedClaimInfo<Test> {
+	migrations::UntaggedClaimInfo {
+		owners: BoundedVec::truncate_from(vec![owner]),
+		created_at,
+		created_moment: Some(MOMENT),
+		expiry,
+		metadata: Default::default(),
+		depositor: owner,
+		deposit,
+		transferable: true,
+		parent: None,
+	}
+}
+
+fn unlinked_info(
+	owner: u64,
+	created_at: u64,
+	expiry: Option<u64>,
+	deposit: u64,
+) -> migrations::UnlinkedClaimInfo<Test> {
+	migrations::UnlinkedClaimInfo {
+		owners: BoundedVec::truncate_from(vec![owner]),
+		created_at,
+		created_moment: None,
+		expiry,
+		metadata: Default::default(),
+		depositor: owner,
+		deposit,
+		transferable: true,
+	}
+}
+
+fn untimed_info(
+	owner: u64,
+	created_at: u64,
+	expiry: Option<u64>,
+	deposit: u64,
+) -> migrations::UntimedClaimInfo<Test> {
+	migrations::UntimedClaimInfo {
+		owners: BoundedVec::truncate_from(vec![owner]),
+		created_at,
+		expiry,
+		metadata: Default::default(),
+		depositor: owner,
+		deposit,
+		transferable: true,
+	}
+}
+
+fn single_owner_info(
+	owner: u64,
+	created_at: u64,
+	expiry: Option<u64>,
+	deposit: u64,
+) -> migrations::SingleOwnerClaimInfo<Test> {
+	migrations::SingleOwnerClaimInfo {
+		owner,
+		created_at,
+		expiry,
+		metadata: Default::default(),
+		depositor: owner,
+		deposit,
+		transferable: true,
+	}
+}
+
+fn old_info(
+	owner: u64,
+	created_at: u64,
+	expiry: Option<u64>,
+	deposit: u64,
+) -> migrations::OldClaimInfo<Test> {
+	migrations::OldClaimInfo {
+		owner,
+		created_at,
+		expiry,
+		metadata: Default::default(),
+		depositor: owner,
+		deposit,
+	}
+}
+
+#[test]
+fn transfer_claim_works() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+
+		assert_eq!(
+			Proofs::<Test>::get(&key(&claim)),
+			Some(ClaimInfo {
+				owners: BoundedVec::truncate_from(vec![BOB]),
+				last_transferred_at: Some(1),
+				..info(ALICE, 1, Some(11), PoeModule::claim_deposit(2))
+			})
+		);
+		System::assert_last_event(Event::ClaimTransfered(ALICE, BOB, vec![], claim, 1, 1).into());
+	});
+}
+
+#[test]
+fn transfer_claim_fails_when_not_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(BOB), vec![], claim, BOB),
+			Error::<Test>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn create_claim_records_expiry() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_eq!(
+			Proofs::<Test>::get(&key(&claim)),
+			Some(info(ALICE, 1, Some(11), PoeModule::claim_deposit(2)))
+		);
+		System::assert_last_event(
+			Event::ClaimCreated(
+				ALICE,
+				vec![],
+				claim,
+				info(ALICE, 1, Some(11), PoeModule::claim_deposit(2)),
+				0,
+				0,
+				0,
+			)
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn created_event_carries_the_stored_claim_info() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			b"ns".to_vec(),
+			claim.clone(),
+			Some(b"meta".to_vec()),
+			Some(false),
+			None,
+			Some(b"text/plain".to_vec()),
+			None
+		));
+
+		// 只凭事件即可得到完整的存证信息，无需再读取存储
+		let event_info = System::events()
+			.into_iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::PoeModule(Event::ClaimCreated(_, _, _, info, _, _, _)) => Some(info),
+				_ => None,
+			})
+			.unwrap();
+		assert_eq!(Proofs::<Test>::get(&(ns(b"ns"), bounded(&claim))), Some(event_info.clone()));
+		assert_eq!(event_info.metadata.into_inner(), b"meta".to_vec());
+		assert!(!event_info.transferable);
+		assert_eq!(event_info.content_type.map(|c| c.into_inner()), Some(b"text/plain".to_vec()));
+	});
+}
+
+#[test]
+fn expired_claim_cannot_be_transferred_or_revoked() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		System::set_block_number(11);
+
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::ClaimExpired
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], claim, None),
+			Error::<Test>::ClaimExpired
+		);
+	});
+}
+
+#[test]
+fn claim_is_usable_until_expiry_block() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		System::set_block_number(10);
+
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None
+		));
+		assert_eq!(Proofs::<Test>::get(&key(&claim)), None);
+	});
+}
+
+#[test]
+fn on_initialize_without_expiring_claims_is_cheap() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		let weight = PoeModule::on_initialize(5);
+
+		assert_eq!(weight, <() as WeightInfo>::on_initialize(0));
+		assert!(Proofs::<Test>::contains_key(&key(&claim)));
+	});
+}
+
+#[test]
+fn on_initialize_purges_expired_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(ExpiringAt::<Test>::get(11).unwrap().len(), 1);
+
+		System::set_block_number(11);
+		PoeModule::on_initialize(11);
+
+		assert_eq!(Proofs::<Test>::get(&key(&claim)), None);
+		assert_eq!(ExpiringAt::<Test>::get(11), None);
+		assert!(ClaimsByOwner::<Test>::get(ALICE).is_empty());
+		System::assert_last_event(Event::ClaimExpired(vec![], claim, 1).into());
+	});
+}
+
+#[test]
+fn on_initialize_skips_claims_revoked_before_expiry() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], claim, None));
+
+		assert_eq!(ExpiringAt::<Test>::get(11), None);
+		assert_eq!(PoeModule::on_initialize(11), <() as WeightInfo>::on_initialize(0));
+	});
+}
+
+#[test]
+fn on_initialize_respects_the_purge_budget() {
+	new_test_ext().execute_with(|| {
+		for i in 0..1_000u32 {
+			Balances::make_free_balance_be(&i.into(), 100);
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(i.into()),
+				vec![],
+				i.encode(),
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+
+		System::set_block_number(11);
+		let weight = PoeModule::on_initialize(11);
+		assert_eq!(weight, <() as WeightInfo>::on_initialize(400));
+		assert_eq!(Proofs::<Test>::iter().count(), 600);
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(11));
+
+		// 剩余的过期存证会顺延到后续区块处理
+		System::set_block_number(12);
+		PoeModule::on_initialize(12);
+		assert_eq!(Proofs::<Test>::iter().count(), 200);
+
+		System::set_block_number(13);
+		PoeModule::on_initialize(13);
+		assert_eq!(Proofs::<Test>::iter().count(), 0);
+		assert_eq!(ExpiringAt::<Test>::get(11), None);
+		assert_eq!(ExpiryCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn genesis_config_preloads_claims() {
+	new_test_ext_with_claims(vec![(vec![1], ALICE), (vec![2], BOB)]).execute_with(|| {
+		// 创世存证没有创建时间
+		assert_eq!(
+			Proofs::<Test>::get(&key(&[1])),
+			Some(ClaimInfo { created_moment: None, ..info(ALICE, 0, None, 0) })
+		);
+		assert_eq!(
+			Proofs::<Test>::get(&key(&[2])),
+			Some(ClaimInfo { created_moment: None, ..info(BOB, 0, None, 0) })
+		);
+
+		assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], vec![1], BOB));
+	});
+}
+
+#[test]
+#[should_panic(expected = "genesis claim is longer than MaxClaimLength")]
+fn genesis_config_rejects_too_long_claims() {
+	new_test_ext_with_claims(vec![(vec![0; 11], ALICE)]);
+}
+
+#[test]
+#[should_panic(expected = "duplicate claim in genesis config")]
+fn genesis_config_rejects_duplicate_claims() {
+	new_test_ext_with_claims(vec![(vec![1], ALICE), (vec![1], BOB)]);
+}
+
+#[test]
+fn create_claim_enforces_min_length() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ClaimTooShort
+		);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+	});
+}
+
+#[test]
+fn create_claim_enforces_max_length() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0; 11],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ClaimTooLong
+		);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0; 10],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+	});
+}
+
+#[test]
+fn migration_to_v2_translates_tuples() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<PoeModule>();
+		migrations::v1::Proofs::<Test>::insert(bounded(&[0, 1]), (ALICE, 3));
+		migrations::v1::Proofs::<Test>::insert(bounded(&[0, 2]), (BOB, 4));
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateToV2::<Test>::pre_upgrade().unwrap();
+		migrations::MigrateToV2::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV2::<Test>::post_upgrade(state).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 2);
+		assert_eq!(migrations::v2::Proofs::<Test>::iter().count(), 2);
+		assert_eq!(
+			migrations::v2::Proofs::<Test>::get(&bounded(&[0, 1])),
+			Some(old_info(ALICE, 3, None, 0))
+		);
+		assert_eq!(
+			migrations::v2::Proofs::<Test>::get(&bounded(&[0, 2])),
+			Some(old_info(BOB, 4, None, 0))
+		);
+		assert_eq!(
+			migrations::v2::ClaimsByOwner::<Test>::get(ALICE).into_inner(),
+			vec![bounded(&[0, 1])]
+		);
+	});
+}
+
+#[test]
+fn migration_to_v3_moves_claims_into_the_default_namespace() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<PoeModule>();
+		migrations::v2::Proofs::<Test>::insert(bounded(&[0, 1]), old_info(ALICE, 3, Some(11), 12));
+		migrations::v2::Proofs::<Test>::insert(bounded(&[0, 2]), old_info(BOB, 4, None, 0));
+		migrations::v2::ExpiringAt::<Test>::insert(
+			11,
+			BoundedVec::truncate_from(vec![bounded(&[0, 1])]),
+		);
+		migrations::v2::ClaimsByOwner::<Test>::insert(
+			ALICE,
+			BoundedVec::truncate_from(vec![bounded(&[0, 1])]),
+		);
+		migrations::v2::ClaimsByOwner::<Test>::insert(
+			BOB,
+			BoundedVec::truncate_from(vec![bounded(&[0, 2])]),
+		);
+		migrations::v2::PendingTransfers::<Test>::insert(bounded(&[0, 1]), CHARLIE);
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateToV3::<Test>::pre_upgrade().unwrap();
+		migrations::MigrateToV3::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV3::<Test>::post_upgrade(state).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 3);
+		assert_eq!(migrations::v3::Proofs::<Test>::iter().count(), 2);
+		assert_eq!(
+			migrations::v3::Proofs::<Test>::get(&key(&[0, 1])),
+			Some(old_info(ALICE, 3, Some(11), 12))
+		);
+		assert_eq!(
+			migrations::v3::Proofs::<Test>::get(&key(&[0, 2])),
+			Some(old_info(BOB, 4, None, 0))
+		);
+		assert_eq!(ExpiringAt::<Test>::get(11).unwrap().into_inner(), vec![key(&[0, 1])]);
+		assert_eq!(ClaimsByOwner::<Test>::get(ALICE).into_inner(), vec![key(&[0, 1])]);
+		assert_eq!(ClaimsByOwner::<Test>::get(BOB).into_inner(), vec![key(&[0, 2])]);
+		assert_eq!(PendingTransfers::<Test>::get(&key(&[0, 1])), Some(CHARLIE));
+
+		// 迁移后的存证可以照常使用和过期
+		migrations::MigrateToV4::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV5::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV6::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV7::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV8::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV9::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV10::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV11::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV12::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV13::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV14::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV15::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV16::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV17::<Test>::on_runtime_upgrade();
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(BOB), vec![], vec![0, 2], None));
+		System::set_block_number(11);
+		PoeModule::on_initialize(11);
+		assert_eq!(Proofs::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn migration_to_v3_resumes_in_later_blocks() {
+	new_test_ext().execute_with(|| {
+		MigrationBatchSize::set(1);
+		StorageVersion::new(2).put::<PoeModule>();
+		let claims = [(vec![0, 1], ALICE, 3), (vec![0, 2], BOB, 4), (vec![0, 3], CHARLIE, 5)];
+		for (claim, owner, created_at) in &claims {
+			migrations::v2::Proofs::<Test>::insert(
+				bounded(claim),
+				old_info(*owner, *created_at, None, 0),
+			);
+			migrations::v2::ClaimsByOwner::<Test>::insert(
+				owner,
+				BoundedVec::truncate_from(vec![bounded(claim)]),
+			);
+		}
+		migrations::v2::PendingTransfers::<Test>::insert(bounded(&[0, 2]), ALICE);
+		let hash = PoeModule::claim_hash(&[0, 4]);
+		migrations::v2::HashedProofs::<Test>::insert(hash, old_info(BOB, 4, None, 0));
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateToV3::<Test>::pre_upgrade().unwrap();
+		migrations::MigrateToV3::<Test>::on_runtime_upgrade();
+
+		// 第一批只移动了一个存证，迁移完成之前拒绝签名调用，之后的迁移也不执行
+		assert_eq!(PoeModule::on_chain_storage_version(), 2);
+		assert!(MigrationCursor::<Test>::exists());
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![1, 1],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::Paused
+		);
+		migrations::MigrateToV4::<Test>::on_runtime_upgrade();
+		assert_eq!(PoeModule::on_chain_storage_version(), 2);
+
+		// 之后的区块继续从游标处移动剩余的存证
+		System::set_block_number(2);
+		PoeModule::on_initialize(2);
+		assert!(MigrationCursor::<Test>::exists());
+		System::set_block_number(3);
+		PoeModule::on_initialize(3);
+		assert!(!MigrationCursor::<Test>::exists());
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV3::<Test>::post_upgrade(state).unwrap();
+
+		// 最后一批移动完成后，之后的迁移在同一区块中执行，存证按当前布局可读
+		assert_eq!(PoeModule::on_chain_storage_version(), 17);
+		assert_eq!(Proofs::<Test>::iter().count(), 3);
+		for (claim, owner, created_at) in &claims {
+			assert_eq!(
+				PoeModule::get_claim(vec![], claim.clone(), None),
+				Some((*owner, *created_at))
+			);
+			assert_eq!(ClaimsByOwner::<Test>::get(owner).into_inner(), vec![key(claim)]);
+		}
+		assert_eq!(PendingTransfers::<Test>::get(&key(&[0, 2])), Some(ALICE));
+		assert!(HashedProofs::<Test>::contains_key(&(ns(b""), hash)));
+		assert_eq!(ProofCount::<Test>::get(), 4);
+		assert_eq!(NamespaceCount::<Test>::get(ns(b"")), 4);
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], vec![0, 1], None));
+	});
+}
+
+#[test]
+fn migrations_are_idempotent() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(PoeModule::on_chain_storage_version(), 17);
+
+		migrations::MigrateToV2::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV3::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV4::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV5::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV6::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV7::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV8::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV9::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV10::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV11::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV12::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV13::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV14::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV15::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV16::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV17::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			Proofs::<Test>::get(&key(&[0, 1])),
+			Some(info(ALICE, 1, Some(11), PoeModule::claim_deposit(2)))
+		);
+		assert_eq!(ProofCount::<Test>::get(), 1);
+		assert_eq!(NamespaceCount::<Test>::get(ns(b"")), 1);
+	});
+}
+
+#[test]
+fn migration_to_v4_counts_stored_claims() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<PoeModule>();
+		Proofs::<Test>::insert(key(&[0, 1]), info(ALICE, 1, None, 0));
+		Proofs::<Test>::insert(key(&[0, 2]), info(BOB, 1, None, 0));
+		HashedProofs::<Test>::insert(
+			(ns(b""), PoeModule::claim_hash(&[0, 3])),
+			info(BOB, 1, None, 0),
+		);
+
+		migrations::MigrateToV4::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV4::<Test>::post_upgrade(vec![]).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 4);
+		assert_eq!(ProofCount::<Test>::get(), 3);
+	});
+}
+
+#[test]
+fn migration_to_v11_counts_claims_per_namespace() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(10).put::<PoeModule>();
+		Proofs::<Test>::insert(key(&[0, 1]), info(ALICE, 1, None, 0));
+		Proofs::<Test>::insert((ns(b"a"), bounded(&[0, 1])), info(BOB, 1, None, 0));
+		HashedProofs::<Test>::insert(
+			(ns(b"a"), PoeModule::claim_hash(&[0, 2])),
+			info(BOB, 1, None, 0),
+		);
+
+		migrations::MigrateToV11::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV11::<Test>::post_upgrade(vec![]).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 11);
+		assert_eq!(NamespaceCount::<Test>::get(ns(b"")), 1);
+		assert_eq!(NamespaceCount::<Test>::get(ns(b"a")), 2);
+	});
+}
+
+#[test]
+fn migration_to_v5_makes_existing_claims_transferable() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(4).put::<PoeModule>();
+		migrations::v3::Proofs::<Test>::insert(key(&[0, 1]), old_info(ALICE, 1, Some(11), 12));
+		migrations::v3::HashedProofs::<Test>::insert(
+			(ns(b""), PoeModule::claim_hash(&[0, 2])),
+			old_info(BOB, 1, None, 0),
+		);
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateToV5::<Test>::pre_upgrade().unwrap();
+		migrations::MigrateToV5::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV5::<Test>::post_upgrade(state).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 5);
+		assert_eq!(
+			migrations::v5::Proofs::<Test>::get(&key(&[0, 1])),
+			Some(single_owner_info(ALICE, 1, Some(11), 12))
+		);
+		assert_eq!(
+			migrations::v5::HashedProofs::<Test>::get(&(ns(b""), PoeModule::claim_hash(&[0, 2]))),
+			Some(single_owner_info(BOB, 1, None, 0))
+		);
+	});
+}
+
+#[test]
+fn migration_to_v6_makes_owners_a_list() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(5).put::<PoeModule>();
+		migrations::v5::Proofs::<Test>::insert(
+			key(&[0, 1]),
+			single_owner_info(ALICE, 1, Some(11), 12),
+		);
+		migrations::v5::HashedProofs::<Test>::insert(
+			(ns(b""), PoeModule::claim_hash(&[0, 2])),
+			single_owner_info(BOB, 1, None, 0),
+		);
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateToV6::<Test>::pre_upgrade().unwrap();
+		migrations::MigrateToV6::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV6::<Test>::post_upgrade(state).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 6);
+		assert_eq!(
+			migrations::v6::Proofs::<Test>::get(&key(&[0, 1])),
+			Some(untimed_info(ALICE, 1, Some(11), 12))
+		);
+		assert_eq!(
+			migrations::v6::HashedProofs::<Test>::get(&(ns(b""), PoeModule::claim_hash(&[0, 2]))),
+			Some(untimed_info(BOB, 1, None, 0))
+		);
+	});
+}
+
+#[test]
+fn migration_to_v7_leaves_the_creation_time_of_existing_claims_unknown() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(6).put::<PoeModule>();
+		migrations::v6::Proofs::<Test>::insert(key(&[0, 1]), untimed_info(ALICE, 1, Some(11), 12));
+		migrations::v6::HashedProofs::<Test>::insert(
+			(ns(b""), PoeModule::claim_hash(&[0, 2])),
+			untimed_info(BOB, 1, None, 0),
+		);
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateToV7::<Test>::pre_upgrade().unwrap();
+		migrations::MigrateToV7::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV7::<Test>::post_upgrade(state).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 7);
+		assert_eq!(
+			migrations::v7::Proofs::<Test>::get(&key(&[0, 1])),
+			Some(unlinked_info(ALICE, 1, Some(11), 12))
+		);
+		assert_eq!(
+			migrations::v7::HashedProofs::<Test>::get(&(ns(b""), PoeModule::claim_hash(&[0, 2]))),
+			Some(unlinked_info(BOB, 1, None, 0))
+		);
+
+		migrations::MigrateToV8::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV9::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV10::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV11::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV12::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV13::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV14::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV15::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV16::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV17::<Test>::on_runtime_upgrade();
+		assert_eq!(PoeModule::claim_moment(vec![], vec![0, 1]), None);
+	});
+}
+
+#[test]
+fn migration_to_v8_leaves_existing_claims_without_a_parent() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(7).put::<PoeModule>();
+		migrations::v7::Proofs::<Test>::insert(
+			key(&[0, 1]),
+			migrations::UnlinkedClaimInfo {
+				created_moment: Some(MOMENT),
+				..unlinked_info(ALICE, 1, Some(11), 12)
+			},
+		);
+		migrations::v7::HashedProofs::<Test>::insert(
+			(ns(b""), PoeModule::claim_hash(&[0, 2])),
+			unlinked_info(BOB, 1, None, 0),
+		);
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateToV8::<Test>::pre_upgrade().unwrap();
+		migrations::MigrateToV8::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV8::<Test>::post_upgrade(state).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 8);
+		assert_eq!(
+			migrations::v8::Proofs::<Test>::get(&key(&[0, 1])),
+			Some(untagged_info(ALICE, 1, Some(11), 12))
+		);
+		assert_eq!(
+			migrations::v8::HashedProofs::<Test>::get(&(ns(b""), PoeModule::claim_hash(&[0, 2]))),
+			Some(migrations::UntaggedClaimInfo {
+				created_moment: None,
+				..untagged_info(BOB, 1, None, 0)
+			})
+		);
+
+		migrations::MigrateToV9::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV10::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV11::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV12::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV13::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV14::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV15::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV16::<Test>::on_runtime_upgrade();
+		migrations::MigrateToV17::<Test>::on_runtime_upgrade();
+		assert_eq!(PoeModule::ancestors(vec![], vec![0, 1], 10), Vec::<Vec<u8>>::new());
+	});
+}
+
+#[test]
+fn migration_to_v9_leaves_existing_claims_untagged() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(8).put::<PoeModule>();
+		migrations::v8::Proofs::<Test>::insert(
+			key(&[0, 2]),
+			migrations::UntaggedClaimInfo {
+				parent: Some(bounded(&[0, 1])),
+				..untagged_info(ALICE, 1, Some(11), 12)
+			},
+		);
+		migrations::v8::HashedProofs::<Test>::insert(
+			(ns(b""), PoeModule::claim_hash(&[0, 3])),
+			untagged_info(BOB, 1, None, 0),
+		);
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateToV9::<Test>::pre_upgrade().unwrap();
+		migrations::MigrateToV9::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV9::<Test>::post_upgrade(state).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 9);
+		assert_eq!(
+			migrations::v9::Proofs::<Test>::get(&key(&[0, 2])),
+			Some(migrations::UntrackedClaimInfo {
+				parent: Some(bounded(&[0, 1])),
+				..untracked_info(ALICE, 1, Some(11), 12)
+			})
+		);
+		assert_eq!(
+			migrations::v9::HashedProofs::<Test>::get(&(ns(b""), PoeModule::claim_hash(&[0, 3]))),
+			Some(untracked_info(BOB, 1, None, 0))
+		);
+	});
+}
+
+#[test]
+fn migration_to_v10_leaves_existing_claims_out_of_the_transfer_cooldown() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(9).put::<PoeModule>();
+		migrations::v9::Proofs::<Test>::insert(
+			key(&[0, 1]),
+			migrations::UntrackedClaimInfo {
+				content_type: Some(BoundedVec::truncate_from(b"image/png".to_vec())),
+				..untracked_info(ALICE, 1, Some(11), 12)
+			},
+		);
+		migrations::v9::HashedProofs::<Test>::insert(
+			(ns(b""), PoeModule::claim_hash(&[0, 2])),
+			untracked_info(BOB, 1, None, 0),
+		);
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateToV10::<Test>::pre_upgrade().unwrap();
+		migrations::MigrateToV10::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV10::<Test>::post_upgrade(state).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 10);
+		assert_eq!(
+			migrations::v10::Proofs::<Test>::get(&key(&[0, 1])),
+			Some(migrations::PublicClaimInfo {
+				content_type: Some(BoundedVec::truncate_from(b"image/png".to_vec())),
+				..public_info(ALICE, 1, Some(11), 12)
+			})
+		);
+		assert_eq!(
+			migrations::v10::HashedProofs::<Test>::get(&(ns(b""), PoeModule::claim_hash(&[0, 2]))),
+			Some(public_info(BOB, 1, None, 0))
+		);
+	});
+}
+
+#[test]
+fn migration_to_v12_leaves_existing_claims_public() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(11).put::<PoeModule>();
+		migrations::v10::Proofs::<Test>::insert(
+			key(&[0, 1]),
+			migrations::PublicClaimInfo {
+				last_transferred_at: Some(5),
+				..public_info(ALICE, 1, Some(11), 12)
+			},
+		);
+		migrations::v10::HashedProofs::<Test>::insert(
+			(ns(b""), PoeModule::claim_hash(&[0, 2])),
+			public_info(BOB, 1, None, 0),
+		);
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateToV12::<Test>::pre_upgrade().unwrap();
+		migrations::MigrateToV12::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV12::<Test>::post_upgrade(state).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 12);
+		assert_eq!(
+			Proofs::<Test>::get(&key(&[0, 1])),
+			Some(ClaimInfo { last_transferred_at: Some(5), ..info(ALICE, 1, Some(11), 12) })
+		);
+		assert_eq!(
+			HashedProofs::<Test>::get(&(ns(b""), PoeModule::claim_hash(&[0, 2]))),
+			Some(info(BOB, 1, None, 0))
+		);
+	});
+}
+
+#[test]
+fn migration_to_v13_adds_namespaces_to_the_creation_log() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(12).put::<PoeModule>();
+		Proofs::<Test>::insert((ns(b"a"), bounded(&[0, 1])), info(ALICE, 1, None, 0));
+		ClaimsByOwner::<Test>::insert(
+			ALICE,
+			BoundedVec::truncate_from(vec![(ns(b"a"), bounded(&[0, 1]))]),
+		);
+		migrations::v12::CreationLog::<Test>::insert(0, (bounded(&[0, 1]), ALICE));
+		// 创建者已经不再持有的存证无法确定命名空间
+		migrations::v12::CreationLog::<Test>::insert(1, (bounded(&[0, 2]), BOB));
+
+		migrations::MigrateToV13::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV13::<Test>::post_upgrade(vec![]).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 13);
+		assert_eq!(CreationLog::<Test>::get(0), Some(((ns(b"a"), bounded(&[0, 1])), ALICE)));
+		assert_eq!(CreationLog::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn migration_to_v14_queues_existing_tombstones_oldest_first() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(13).put::<PoeModule>();
+		Tombstones::<Test>::insert(key(&[0, 1]), 3);
+		Tombstones::<Test>::insert(key(&[0, 2]), 1);
+
+		migrations::MigrateToV14::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV14::<Test>::post_upgrade(vec![]).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 14);
+		assert_eq!(TombstoneQueueBounds::<Test>::get(), (0, 2));
+		assert_eq!(TombstoneQueue::<Test>::get(0), Some(key(&[0, 2])));
+		assert_eq!(TombstoneQueue::<Test>::get(1), Some(key(&[0, 1])));
+
+		// 迁移前留下的墓碑也会在空闲时被清理
+		PoeModule::on_idle(3, <() as WeightInfo>::on_idle(2));
+		assert_eq!(Tombstones::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn migration_to_v15_indexes_existing_swap_offers() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(14).put::<PoeModule>();
+		let offers = [(key(&[0, 1]), key(&[0, 2])), (key(&[0, 1]), key(&[0, 3]))];
+		for offer in &offers {
+			migrations::v14::SwapOffers::<Test>::insert(offer, (ALICE, BOB));
+		}
+		// 超出索引上限的报价被丢弃
+		migrations::v14::SwapOffers::<Test>::insert((key(&[0, 1]), key(&[0, 4])), (ALICE, BOB));
+
+		migrations::MigrateToV15::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV15::<Test>::post_upgrade(vec![]).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 15);
+		assert_eq!(SwapOffers::<Test>::iter().count(), 2);
+		assert_eq!(SwapOffersOf::<Test>::get(&key(&[0, 1])).len(), 2);
+		for offer in SwapOffersOf::<Test>::get(&key(&[0, 1])) {
+			assert_eq!(SwapOffers::<Test>::get(&offer), Some((ALICE, BOB, 0)));
+			assert_eq!(SwapOffersOf::<Test>::get(&offer.1).into_inner(), vec![offer.clone()]);
+		}
+	});
+}
+
+#[test]
+fn migration_to_v16_indexes_existing_hashed_claims() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(15).put::<PoeModule>();
+		let hashed = [(ALICE, [0, 1]), (ALICE, [0, 2]), (BOB, [0, 3])];
+		for (owner, claim) in &hashed {
+			HashedProofs::<Test>::insert(
+				(ns(b""), PoeModule::claim_hash(claim)),
+				info(*owner, 1, None, 0),
+			);
+		}
+
+		migrations::MigrateToV16::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV16::<Test>::post_upgrade(vec![]).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 16);
+		for (owner, claim) in &hashed {
+			assert!(HashedClaimsByOwner::<Test>::get(owner)
+				.contains(&(ns(b""), PoeModule::claim_hash(claim))));
+		}
+		assert_eq!(HashedClaimsByOwner::<Test>::get(ALICE).len(), 2);
+	});
+}
+
+#[test]
+fn migration_to_v17_prunes_the_creation_log() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(16).put::<PoeModule>();
+		for seq in 0..6u64 {
+			CreationLog::<Test>::insert(seq, (key(&[0, seq as u8]), ALICE));
+		}
+		NextSeq::<Test>::put(6);
+
+		migrations::MigrateToV17::<Test>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateToV17::<Test>::post_upgrade(vec![]).unwrap();
+
+		assert_eq!(PoeModule::on_chain_storage_version(), 17);
+		let mut kept: Vec<u64> = CreationLog::<Test>::iter_keys().collect();
+		kept.sort();
+		assert_eq!(kept, vec![2, 3, 4, 5]);
+	});
+}
+
+#[test]
+fn owner_index_follows_create_transfer_and_revoke() {
+	new_test_ext().execute_with(|| {
+		for claim in [vec![0, 1], vec![0, 2], vec![0, 3]] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_eq!(ClaimsByOwner::<Test>::get(ALICE).len(), 3);
+
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 2],
+			BOB
+		));
+		assert_eq!(
+			ClaimsByOwner::<Test>::get(ALICE).into_inner(),
+			vec![key(&[0, 1]), key(&[0, 3])]
+		);
+		assert_eq!(ClaimsByOwner::<Test>::get(BOB).into_inner(), vec![key(&[0, 2])]);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(BOB), vec![], vec![0, 2], None));
+		assert!(!ClaimsByOwner::<Test>::contains_key(BOB));
+	});
+}
+
+#[test]
+fn owner_index_is_bounded() {
+	new_test_ext().execute_with(|| {
+		for i in 0..5u8 {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, i],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 5],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::TooManyClaims
+		);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(CHARLIE),
+			vec![],
+			vec![0, 5],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(CHARLIE), vec![], vec![0, 5], ALICE),
+			Error::<Test>::TooManyClaims
+		);
+	});
+}
+
+#[test]
+fn create_claims_stores_every_claim() {
+	new_test_ext().execute_with(|| {
+		let claims = vec![vec![0, 1], vec![0, 2], vec![0, 3]];
+		assert_ok!(PoeModule::create_claims(RuntimeOrigin::signed(ALICE), vec![], claims.clone()));
+
+		for claim in &claims {
+			assert_eq!(
+				Proofs::<Test>::get(&key(claim)),
+				Some(info(ALICE, 1, Some(11), PoeModule::claim_deposit(2)))
+			);
+		}
+		let created = System::events()
+			.into_iter()
+			.filter(|record| {
+				matches!(record.event, RuntimeEvent::PoeModule(Event::ClaimCreated(..)))
+			})
+			.count();
+		assert_eq!(created, 3);
+	});
+}
+
+#[test]
+fn create_claims_is_atomic() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![0, 3],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_noop!(
+			PoeModule::create_claims(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![vec![0, 1], vec![0, 2], vec![0, 3]]
+			),
+			Error::<Test>::ProofAlreadyExistOwnedByOther
+		);
+		assert_noop!(
+			PoeModule::create_claims(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![vec![0, 1], vec![0; 11]]
+			),
+			Error::<Test>::ClaimTooLong
+		);
+		assert_noop!(
+			PoeModule::create_claims(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![vec![0, 1], vec![0, 1]]
+			),
+			Error::<Test>::ProofAlreadyExistOwnedBySelf
+		);
+		assert!(!Proofs::<Test>::contains_key(&key(&[0, 1])));
+	});
+}
+
+#[test]
+fn create_claims_rejects_oversized_batches() {
+	new_test_ext().execute_with(|| {
+		let claims = (0..5u8).map(|i| vec![0, i]).collect();
+		assert_noop!(
+			PoeModule::create_claims(RuntimeOrigin::signed(ALICE), vec![], claims),
+			Error::<Test>::BatchTooLarge
+		);
+	});
+}
+
+#[test]
+fn proposed_transfer_moves_ownership_once_accepted() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(PoeModule::propose_transfer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		System::assert_last_event(
+			Event::TransferProposed(ALICE, BOB, vec![], claim.clone()).into(),
+		);
+		// 接收方确认之前所有权不变
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![ALICE]);
+
+		assert_ok!(PoeModule::accept_transfer(RuntimeOrigin::signed(BOB), vec![], claim.clone()));
+
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![BOB]);
+		assert!(!PendingTransfers::<Test>::contains_key(&key(&claim)));
+		System::assert_last_event(Event::ClaimTransfered(ALICE, BOB, vec![], claim, 1, 1).into());
+	});
+}
+
+#[test]
+fn transfers_cannot_be_proposed_to_an_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::propose_transfer(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), ALICE),
+			Error::<Test>::CannotTransferToSelf
+		);
+
+		// 共同所有者之间不能通过提议移除对方
+		assert_ok!(PoeModule::add_owner(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+		assert_noop!(
+			PoeModule::propose_transfer(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::CannotTransferToSelf
+		);
+
+		// 提议之后才成为所有者的接收方也不能接受
+		assert_ok!(PoeModule::propose_transfer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+		assert_ok!(PoeModule::add_owner(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+		assert_noop!(
+			PoeModule::accept_transfer(RuntimeOrigin::signed(CHARLIE), vec![], claim.clone()),
+			Error::<Test>::CannotTransferToSelf
+		);
+		assert_eq!(
+			Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(),
+			vec![ALICE, BOB, CHARLIE]
+		);
+	});
+}
+
+#[test]
+fn cancelled_transfer_cannot_be_accepted() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::propose_transfer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+
+		assert_noop!(
+			PoeModule::cancel_transfer(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::cancel_transfer(RuntimeOrigin::signed(ALICE), vec![], claim.clone()));
+		System::assert_last_event(Event::TransferCancelled(ALICE, vec![], claim.clone()).into());
+
+		assert_noop!(
+			PoeModule::accept_transfer(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::NoPendingTransfer
+		);
+		assert_noop!(
+			PoeModule::cancel_transfer(RuntimeOrigin::signed(ALICE), vec![], claim.clone()),
+			Error::<Test>::NoPendingTransfer
+		);
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![ALICE]);
+	});
+}
+
+#[test]
+fn only_the_pending_recipient_can_accept() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::propose_transfer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+
+		assert_noop!(
+			PoeModule::accept_transfer(RuntimeOrigin::signed(CHARLIE), vec![], claim.clone()),
+			Error::<Test>::NotPendingRecipient
+		);
+	});
+}
+
+#[test]
+fn direct_transfer_clears_pending_transfer() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::propose_transfer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+
+		assert_noop!(
+			PoeModule::accept_transfer(RuntimeOrigin::signed(BOB), vec![], claim),
+			Error::<Test>::NoPendingTransfer
+		);
+	});
+}
+
+#[test]
+fn force_revoke_requires_root() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_noop!(
+			PoeModule::force_revoke(RuntimeOrigin::signed(ALICE), vec![], claim, None),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn force_revoke_removes_any_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(PoeModule::force_revoke(RuntimeOrigin::root(), vec![], claim.clone(), None));
+
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+		assert!(ClaimsByOwner::<Test>::get(ALICE).is_empty());
+		System::assert_last_event(Event::ClaimForceRevoked(vec![], claim.clone(), 0, 1).into());
+
+		assert_noop!(
+			PoeModule::force_revoke(RuntimeOrigin::root(), vec![], claim, None),
+			Error::<Test>::ClaimNotExist
+		);
+	});
+}
+
+#[cfg(feature = "claim-deposits")]
+#[test]
+fn create_claim_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1, 2];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 基础押金 10，外加每字节 1
+		assert_eq!(Balances::reserved_balance(ALICE), 13);
+		assert_eq!(Balances::free_balance(ALICE), 987);
+		System::assert_last_event(
+			Event::ClaimCreated(ALICE, vec![], claim, info(ALICE, 1, Some(11), 13), 0, 0, 0).into(),
+		);
+	});
+}
+
+#[cfg(feature = "claim-deposits")]
+#[test]
+fn create_claim_fails_without_enough_balance() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&BOB, 11);
+
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				vec![0, 1],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::InsufficientDeposit
+		);
+	});
+}
+
+#[cfg(feature = "claim-deposits")]
+#[test]
+fn removing_a_claim_refunds_the_depositor() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 12);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+
+		// 押金退还给最初的缴纳者，而不是当前所有者
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(BOB), vec![], claim, None));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+	});
+}
+
+#[test]
+fn expired_claims_refund_their_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		System::set_block_number(11);
+		PoeModule::on_initialize(11);
+
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn hashed_claim_stores_only_the_hash() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![7; 10];
+		assert_ok!(PoeModule::create_hashed_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone()
+		));
+
+		let hash = PoeModule::claim_hash(&claim);
+		assert!(HashedProofs::<Test>::contains_key((ns(b""), hash)));
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+		assert_eq!(PoeModule::verify_claim(vec![], claim.clone()), Some((ALICE, 1)));
+		// 押金按 32 字节的哈希计算
+		#[cfg(feature = "claim-deposits")]
+		assert_eq!(Balances::reserved_balance(ALICE), 42);
+		System::assert_last_event(
+			Event::HashedClaimCreated(ALICE, vec![], hash, PoeModule::claim_deposit(32), 0, 0)
+				.into(),
+		);
+
+		assert_noop!(
+			PoeModule::create_hashed_claim(RuntimeOrigin::signed(BOB), vec![], claim),
+			Error::<Test>::ProofAlreadyExistOwnedByOther
+		);
+	});
+}
+
+#[test]
+fn hashed_claim_enforces_payload_length() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoeModule::create_hashed_claim(RuntimeOrigin::signed(ALICE), vec![], vec![0; 11]),
+			Error::<Test>::ClaimTooLong
+		);
+		assert_noop!(
+			PoeModule::create_hashed_claim(RuntimeOrigin::signed(ALICE), vec![], vec![0]),
+			Error::<Test>::ClaimTooShort
+		);
+	});
+}
+
+#[test]
+fn hashed_claim_can_be_revoked_by_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_hashed_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone()
+		));
+
+		assert_noop!(
+			PoeModule::revoke_hashed_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::revoke_hashed_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone()
+		));
+
+		assert_eq!(PoeModule::verify_claim(vec![], claim.clone()), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		System::assert_last_event(
+			Event::HashedClaimRevoked(ALICE, vec![], PoeModule::claim_hash(&claim), 1).into(),
+		);
+	});
+}
+
+#[test]
+fn hashed_claims_share_the_quota_and_respect_tombstones() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=3 {
+			assert_ok!(PoeModule::create_hashed_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, i]
+			));
+		}
+		for i in 1..=2 {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![1, i],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_eq!(HashedClaimsByOwner::<Test>::get(ALICE).len(), 3);
+
+		// 哈希存证和普通存证一起受账户配额的限制
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![1, 3],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::TooManyClaims
+		);
+		assert_noop!(
+			PoeModule::create_hashed_claim(RuntimeOrigin::signed(ALICE), vec![], vec![0, 4]),
+			Error::<Test>::TooManyClaims
+		);
+
+		// 吊销哈希存证后配额重新可用
+		assert_ok!(PoeModule::revoke_hashed_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1]
+		));
+		assert_eq!(HashedClaimsByOwner::<Test>::get(ALICE).len(), 2);
+		assert_ok!(PoeModule::create_hashed_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 4]
+		));
+
+		// 被放弃的存证不能以哈希的形式重新创建
+		Tombstones::<Test>::insert(key(&[2, 2]), 1);
+		assert_noop!(
+			PoeModule::create_hashed_claim(RuntimeOrigin::signed(BOB), vec![], vec![2, 2]),
+			Error::<Test>::ClaimTombstoned
+		);
+		assert_ok!(PoeModule::do_try_state());
+	});
+}
+
+#[test]
+fn set_paused_requires_pause_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(PoeModule::set_paused(RuntimeOrigin::signed(ALICE), true), BadOrigin);
+	});
+}
+
+#[test]
+fn paused_pallet_rejects_claim_calls() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(PoeModule::set_paused(RuntimeOrigin::root(), true));
+		assert!(Paused::<Test>::get());
+		System::assert_last_event(Event::Paused.into());
+
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 2],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), None),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::Paused
+		);
+
+		// 暂停期间仍然可以解除暂停
+		assert_ok!(PoeModule::set_paused(RuntimeOrigin::root(), false));
+		System::assert_last_event(Event::Unpaused.into());
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], claim, None));
+	});
+}
+
+#[test]
+fn lifecycle_events_carry_the_block_number() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		System::set_block_number(3);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		System::assert_last_event(
+			Event::ClaimCreated(
+				ALICE,
+				vec![],
+				claim.clone(),
+				info(ALICE, 3, Some(13), PoeModule::claim_deposit(2)),
+				0,
+				0,
+				0,
+			)
+			.into(),
+		);
+
+		System::set_block_number(5);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		System::assert_last_event(
+			Event::ClaimTransfered(ALICE, BOB, vec![], claim.clone(), 5, 1).into(),
+		);
+
+		System::set_block_number(7);
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			None
+		));
+		System::assert_last_event(Event::ClaimRevoked(BOB, BOB, vec![], claim, 7, 0, 2).into());
+	});
+}
+
+#[test]
+fn approved_operator_can_transfer() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(PoeModule::approve(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+		System::assert_last_event(Event::ClaimApproved(ALICE, BOB, vec![], claim.clone()).into());
+
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![CHARLIE]);
+		System::assert_last_event(
+			Event::ClaimTransfered(ALICE, CHARLIE, vec![], claim.clone(), 1, 1).into(),
+		);
+
+		// 转移后授权被自动清除
+		assert!(!ClaimApprovals::<Test>::contains_key(&key(&claim)));
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(BOB), vec![], claim, None),
+			Error::<Test>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn approved_operator_can_revoke() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::approve(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			None
+		));
+
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+		assert!(!ClaimApprovals::<Test>::contains_key(&key(&claim)));
+	});
+}
+
+#[test]
+fn cancelled_approval_no_longer_works() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::approve(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+
+		assert_noop!(
+			PoeModule::cancel_approval(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::cancel_approval(RuntimeOrigin::signed(ALICE), vec![], claim.clone()));
+		System::assert_last_event(
+			Event::ClaimApprovalCancelled(ALICE, vec![], claim.clone()).into(),
+		);
+
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone(), CHARLIE),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			PoeModule::cancel_approval(RuntimeOrigin::signed(ALICE), vec![], claim),
+			Error::<Test>::NoApproval
+		);
+	});
+}
+
+#[test]
+fn namespaces_keep_identical_claims_apart() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			b"a".to_vec(),
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			b"b".to_vec(),
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_eq!(
+			Proofs::<Test>::get(&(ns(b"a"), bounded(&claim))).unwrap().owners.into_inner(),
+			vec![ALICE]
+		);
+		assert_eq!(
+			Proofs::<Test>::get(&(ns(b"b"), bounded(&claim))).unwrap().owners.into_inner(),
+			vec![BOB]
+		);
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+		System::assert_last_event(
+			Event::ClaimCreated(
+				BOB,
+				b"b".to_vec(),
+				claim.clone(),
+				info(BOB, 1, Some(11), PoeModule::claim_deposit(2)),
+				0,
+				1,
+				1,
+			)
+			.into(),
+		);
+
+		// 同一命名空间内仍然不能重复
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				b"a".to_vec(),
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ProofAlreadyExistOwnedByOther
+		);
+
+		// 吊销只作用于所在的命名空间
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ALICE),
+			b"a".to_vec(),
+			claim.clone(),
+			None
+		));
+		assert!(Proofs::<Test>::contains_key(&(ns(b"b"), bounded(&claim))));
+	});
+}
+
+#[test]
+fn namespace_length_is_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![0; 9],
+				vec![0, 1],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::NamespaceTooLong
+		);
+	});
+}
+
+#[test]
+fn check_claim_length_rejects_long_claims_before_dispatch() {
+	new_test_ext().execute_with(|| {
+		let check = CheckClaimLength::<Test>::new();
+		let info = DispatchInfo::default();
+		let call = |claim: Vec<u8>| {
+			RuntimeCall::PoeModule(crate::Call::create_claim {
+				namespace: vec![],
+				claim,
+				metadata: None,
+				transferable: None,
+				parent: None,
+				content_type: None,
+				ttl: None,
+			})
+		};
+
+		assert_eq!(
+			check.validate(&ALICE, &call(vec![0; 11]), &info, 0),
+			Err(InvalidTransaction::Custom(CLAIM_TOO_LONG).into())
+		);
+		assert_ok!(check.validate(&ALICE, &call(vec![0; 10]), &info, 0));
+
+		let revoke = RuntimeCall::PoeModule(crate::Call::revoke_claim {
+			namespace: vec![],
+			claim: vec![0; 11],
+			reason: None,
+		});
+		assert!(check.validate(&ALICE, &revoke, &info, 0).is_err());
+
+		// 批量调用中的每个存证以及交换和替换的两个存证都要检查
+		let (short, long) = (vec![0; 10], vec![0; 11]);
+		let calls = |claim: Vec<u8>| {
+			vec![
+				crate::Call::create_claims {
+					namespace: vec![],
+					claims: vec![short.clone(), claim.clone()],
+				},
+				crate::Call::create_claims_best_effort {
+					namespace: vec![],
+					claims: vec![short.clone(), claim.clone()],
+				},
+				crate::Call::swap_claims {
+					namespace: vec![],
+					my_claim: short.clone(),
+					their_claim: claim.clone(),
+					counterparty: BOB,
+				},
+				crate::Call::swap_claims {
+					namespace: vec![],
+					my_claim: claim.clone(),
+					their_claim: short.clone(),
+					counterparty: BOB,
+				},
+				crate::Call::propose_transfer {
+					namespace: vec![],
+					claim: claim.clone(),
+					dest: BOB,
+				},
+				crate::Call::supersede_claim {
+					namespace: vec![],
+					old_claim: short.clone(),
+					new_claim: claim.clone(),
+				},
+				crate::Call::supersede_claim {
+					namespace: vec![],
+					old_claim: claim.clone(),
+					new_claim: short.clone(),
+				},
+				crate::Call::create_hashed_claim { namespace: vec![], claim: claim.clone() },
+				crate::Call::transfer_many {
+					namespace: vec![],
+					transfers: vec![(short.clone(), BOB), (claim.clone(), BOB)],
+				},
+			]
+		};
+		for call in calls(long) {
+			assert_eq!(
+				check.validate(&ALICE, &RuntimeCall::PoeModule(call), &info, 0),
+				Err(InvalidTransaction::Custom(CLAIM_TOO_LONG).into())
+			);
+		}
+		for call in calls(short.clone()) {
+			assert_ok!(check.validate(&ALICE, &RuntimeCall::PoeModule(call), &info, 0));
+		}
+
+		// 按规范化之后的长度检查，转为小写后变长的存证同样被拒绝
+		CaseInsensitiveClaims::set(true);
+		RequireUtf8::set(true);
+		let dotted = "İİİİİ".as_bytes().to_vec();
+		assert_eq!(dotted.len(), 10);
+		assert_eq!(
+			check.validate(&ALICE, &call(dotted.clone()), &info, 0),
+			Err(InvalidTransaction::Custom(CLAIM_TOO_LONG).into())
+		);
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				dotted,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ClaimTooLong
+		);
+		assert_ok!(check.validate(&ALICE, &call(b"ABCDEFGHIJ".to_vec()), &info, 0));
+
+		// 与存证长度无关的调用不受影响
+		let pause = RuntimeCall::PoeModule(crate::Call::set_paused { paused: true });
+		assert_ok!(check.validate(&ALICE, &pause, &info, 0));
+	});
+}
+
+#[test]
+fn claim_lengths_and_deposits_use_the_normalized_claim() {
+	new_test_ext().execute_with(|| {
+		CaseInsensitiveClaims::set(true);
+		RequireUtf8::set(true);
+		let create = |claim: &[u8]| {
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.to_vec(),
+				None,
+				None,
+				None,
+				None,
+				None,
+			)
+		};
+
+		// 开尔文符号占三个字节，转为小写后只剩一个字节，低于最小长度
+		let kelvin = "\u{212A}".as_bytes();
+		assert_eq!(kelvin.len(), 3);
+		assert_noop!(create(kelvin), Error::<Test>::ClaimTooShort);
+		assert_noop!(
+			PoeModule::create_hashed_claim(RuntimeOrigin::signed(ALICE), vec![], kelvin.to_vec()),
+			Error::<Test>::ClaimTooShort
+		);
+
+		// 押金同样按规范化之后的长度计算
+		let dotted = "İ".as_bytes();
+		assert_eq!(dotted.len(), 2);
+		assert_eq!(
+			PoeModule::simulate_create(ALICE, vec![], dotted.to_vec()).deposit,
+			PoeModule::claim_deposit(3)
+		);
+		assert_ok!(create(dotted));
+		let stored = PoeModule::bounded_claim(dotted).unwrap();
+		assert_eq!(
+			Proofs::<Test>::get((ns(b""), stored)).unwrap().deposit,
+			PoeModule::claim_deposit(3)
+		);
+
+		assert_noop!(
+			PoeModule::supersede_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				dotted.to_vec(),
+				kelvin.to_vec()
+			),
+			Error::<Test>::ClaimTooShort
+		);
+		assert_ok!(PoeModule::supersede_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			dotted.to_vec(),
+			"İİ".as_bytes().to_vec()
+		));
+		let stored = PoeModule::bounded_claim("İİ".as_bytes()).unwrap();
+		assert_eq!(
+			Proofs::<Test>::get((ns(b""), stored)).unwrap().deposit,
+			PoeModule::claim_deposit(6)
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_reports_expiring_claims_once() {
+	let mut ext = new_test_ext();
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 存证在区块 11 过期，尚未进入告警窗口
+		PoeModule::offchain_worker(1);
+		assert!(pool_state.read().transactions.is_empty());
+
+		System::set_block_number(8);
+		PoeModule::offchain_worker(8);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, None);
+		assert_eq!(
+			tx.call,
+			RuntimeCall::PoeModule(crate::Call::report_expiring_soon {
+				key: key(&claim),
+				expiry: 11
+			})
+		);
+
+		// 同一告警只提交一次
+		System::set_block_number(9);
+		PoeModule::offchain_worker(9);
+		assert!(pool_state.read().transactions.is_empty());
+
+		assert_ok!(PoeModule::report_expiring_soon(RuntimeOrigin::none(), key(&claim), 11));
+		System::assert_last_event(Event::ClaimExpiringSoon(vec![], claim, 11).into());
+	});
+}
+
+#[test]
+fn report_expiring_soon_is_validated() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		let call = |expiry| crate::Call::report_expiring_soon { key: key(&claim), expiry };
+
+		// 尚未进入告警窗口
+		assert_eq!(
+			PoeModule::validate_unsigned(TransactionSource::Local, &call(11)),
+			Err(InvalidTransaction::Stale.into())
+		);
+		assert_noop!(
+			PoeModule::report_expiring_soon(RuntimeOrigin::none(), key(&claim), 11),
+			Error::<Test>::NotExpiringSoon
+		);
+
+		System::set_block_number(8);
+		assert_ok!(PoeModule::validate_unsigned(TransactionSource::Local, &call(11)));
+		assert_eq!(
+			PoeModule::validate_unsigned(TransactionSource::External, &call(11)),
+			Err(InvalidTransaction::Call.into())
+		);
+		assert_eq!(
+			PoeModule::validate_unsigned(TransactionSource::Local, &call(10)),
+			Err(InvalidTransaction::Stale.into())
+		);
+		assert_noop!(
+			PoeModule::report_expiring_soon(RuntimeOrigin::signed(ALICE), key(&claim), 11),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn renew_claim_moves_expiry_forward() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		System::set_block_number(5);
+		assert_ok!(PoeModule::renew_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone()));
+
+		// 过期区块从 11 推迟到 15，创建区块保持不变
+		assert_eq!(
+			Proofs::<Test>::get(&key(&claim)),
+			Some(info(ALICE, 1, Some(15), PoeModule::claim_deposit(2)))
+		);
+		assert_eq!(ExpiringAt::<Test>::get(11), None);
+		assert_eq!(ExpiringAt::<Test>::get(15).unwrap().into_inner(), vec![key(&claim)]);
+		System::assert_last_event(Event::ClaimRenewed(ALICE, vec![], claim.clone(), 15).into());
+
+		// 原过期区块不再清理该存证
+		System::set_block_number(11);
+		PoeModule::on_initialize(11);
+		assert!(Proofs::<Test>::contains_key(&key(&claim)));
+	});
+}
+
+#[test]
+fn renew_claim_fails_for_missing_expired_or_foreign_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_noop!(
+			PoeModule::renew_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone()),
+			Error::<Test>::ClaimNotExist
+		);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::renew_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			PoeModule::renew_claim(RuntimeOrigin::signed(ALICE), vec![], claim),
+			Error::<Test>::ClaimExpired
+		);
+	});
+}
+
+#[test]
+fn proof_count_is_capped() {
+	new_test_ext_with_claims(vec![(vec![0, 0], ALICE)]).execute_with(|| {
+		assert_eq!(ProofCount::<Test>::get(), 1);
+
+		assert_ok!(PoeModule::create_hashed_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1]
+		));
+		assert_ok!(PoeModule::create_claims(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![vec![0, 2], vec![0, 3]]
+		));
+		for i in 4..1_000u32 {
+			Balances::make_free_balance_be(&i.into(), 100);
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(i.into()),
+				vec![],
+				i.encode(),
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_eq!(ProofCount::<Test>::get(), 1_000);
+
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(CHARLIE),
+				vec![],
+				vec![0, 4],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::StorageFull
+		);
+		assert_noop!(
+			PoeModule::create_hashed_claim(RuntimeOrigin::signed(CHARLIE), vec![], vec![0, 4]),
+			Error::<Test>::StorageFull
+		);
+
+		// 转移不改变存证总数
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![0, 2],
+			ALICE
+		));
+		assert_eq!(ProofCount::<Test>::get(), 1_000);
+
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], vec![0, 2], None));
+		assert_eq!(ProofCount::<Test>::get(), 999);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(CHARLIE),
+			vec![],
+			vec![0, 4],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(ProofCount::<Test>::get(), 1_000);
+	});
+}
+
+#[test]
+fn claims_carry_metadata() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let note = vec![7; 16];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			Some(note.clone()),
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().metadata.into_inner(), note);
+
+		assert_ok!(PoeModule::set_metadata(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			vec![8; 4]
+		));
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().metadata.into_inner(), vec![8; 4]);
+		System::assert_last_event(Event::ClaimMetadataSet(ALICE, vec![], claim.clone()).into());
+
+		assert_noop!(
+			PoeModule::set_metadata(RuntimeOrigin::signed(BOB), vec![], claim.clone(), vec![]),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::set_metadata(RuntimeOrigin::signed(ALICE), vec![], claim, vec![0; 17]),
+			Error::<Test>::MetadataTooLong
+		);
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 2],
+				Some(vec![0; 17]),
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::MetadataTooLong
+		);
+	});
+}
+
+#[test]
+fn transfer_claim_to_self_fails() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::approve(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+
+		System::set_block_number(5);
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), ALICE),
+			Error::<Test>::CannotTransferToSelf
+		);
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone(), ALICE),
+			Error::<Test>::CannotTransferToSelf
+		);
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().created_at, 1);
+	});
+}
+
+#[test]
+fn short_claims_are_refunded_unused_weight() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let calls = [
+			crate::Call::create_claim {
+				namespace: vec![],
+				claim: claim.clone(),
+				metadata: None,
+				transferable: None,
+				parent: None,
+				content_type: None,
+				ttl: None,
+			},
+			crate::Call::transfer_claim { namespace: vec![], claim: claim.clone(), dest: BOB },
+			crate::Call::revoke_claim { namespace: vec![], claim: claim.clone(), reason: None },
+		];
+		let origins = [ALICE, ALICE, BOB];
+
+		for (call, who) in calls.into_iter().zip(origins) {
+			let call = RuntimeCall::PoeModule(call);
+			let worst_case = call.get_dispatch_info().weight;
+			let post_info = call.dispatch(RuntimeOrigin::signed(who)).unwrap();
+			assert!(post_info.actual_weight.unwrap().ref_time() < worst_case.ref_time());
+		}
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+	});
+}
+
+#[test]
+fn revoke_claims_removes_every_claim() {
+	new_test_ext().execute_with(|| {
+		let claims = vec![vec![0, 1], vec![0, 2], vec![0, 3]];
+		assert_ok!(PoeModule::create_claims(RuntimeOrigin::signed(ALICE), vec![], claims.clone()));
+
+		assert_ok!(PoeModule::revoke_claims(RuntimeOrigin::signed(ALICE), vec![], claims.clone()));
+
+		// 创建三个存证用掉了前三个事件序号
+		for (index, claim) in (3..).zip(&claims) {
+			assert!(!Proofs::<Test>::contains_key(&key(claim)));
+			System::assert_has_event(
+				Event::ClaimRevoked(ALICE, ALICE, vec![], claim.clone(), 1, 0, index).into(),
+			);
+		}
+		assert!(ClaimsByOwner::<Test>::get(ALICE).is_empty());
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn revoke_claims_is_atomic() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claims(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![vec![0, 1], vec![0, 2]]
+		));
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![0, 3],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_noop!(
+			PoeModule::revoke_claims(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![vec![0, 1], vec![0, 2], vec![0, 3]]
+			),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			PoeModule::revoke_claims(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![vec![0, 1], vec![0, 4]]
+			),
+			Error::<Test>::ClaimNotExist
+		);
+		assert_noop!(
+			PoeModule::revoke_claims(RuntimeOrigin::signed(ALICE), vec![], vec![vec![0, 1]; 5]),
+			Error::<Test>::BatchTooLarge
+		);
+		assert!(Proofs::<Test>::contains_key(&key(&[0, 1])));
+	});
+}
+
+#[test]
+fn renounced_claim_cannot_be_recreated() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::renounce_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+
+		System::set_block_number(3);
+		assert_ok!(PoeModule::renounce_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone()));
+		System::assert_last_event(Event::ClaimRenounced(ALICE, vec![], claim.clone(), 1).into());
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+		assert_eq!(Tombstones::<Test>::get(&key(&claim)), Some(3));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+
+		// 墓碑与从未存在的存证区分开
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ClaimTombstoned
+		);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![0, 2],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 墓碑只作用于所在的命名空间
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			b"app".to_vec(),
+			claim,
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+	});
+}
+
+#[test]
+fn get_claim_reads_live_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_eq!(PoeModule::get_claim(vec![], claim.clone(), None), None);
+		assert!(!PoeModule::claim_exists(vec![], claim.clone(), None));
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(PoeModule::get_claim(vec![], claim.clone(), None), Some((ALICE, 1)));
+		assert!(PoeModule::claim_exists(vec![], claim.clone(), None));
+		assert!(!PoeModule::claim_exists(b"app".to_vec(), claim.clone(), None));
+
+		// 已过期但尚未清理的存证不再被报告
+		System::set_block_number(11);
+		assert_eq!(PoeModule::get_claim(vec![], claim, None), None);
+	});
+}
+
+#[test]
+fn claims_can_be_stored_as_custom_claim_data() {
+	content_address::new_test_ext().execute_with(|| {
+		use content_address::{ContentTest, RuntimeOrigin};
+
+		let address = ContentAddress(7, H256::repeat_byte(1));
+		assert_ok!(content_address::PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			address.encode(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		let (namespace, stored) = Proofs::<ContentTest>::iter_keys().next().unwrap();
+		assert!(namespace.is_empty());
+		assert_eq!(stored, address);
+		assert_eq!(
+			content_address::PoeModule::get_claim(vec![], address.encode(), None),
+			Some((ALICE, 1))
+		);
+
+		// 无法转换为 ContentAddress 的存证被拒绝
+		assert_noop!(
+			content_address::PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0; 3],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<ContentTest>::InvalidClaim
+		);
+	});
+}
+
+#[test]
+fn metadata_is_bounded_by_each_runtime_cap() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			Some(vec![0; 16]),
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 2],
+				Some(vec![0; 17]),
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::MetadataTooLong
+		);
+	});
+
+	// 第二个模拟运行时的元数据上限不同
+	content_address::new_test_ext().execute_with(|| {
+		use content_address::{ContentTest, RuntimeOrigin};
+
+		let address = ContentAddress(7, H256::repeat_byte(1));
+		assert_ok!(content_address::PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			address.encode(),
+			Some(vec![0; 32]),
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			content_address::PoeModule::set_metadata(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				address.encode(),
+				vec![0; 33]
+			),
+			Error::<ContentTest>::MetadataTooLong
+		);
+	});
+}
+
+#[test]
+fn claim_creation_is_rate_limited_per_block() {
+	new_test_ext().execute_with(|| {
+		MaxClaimsPerBlockPerAccount::set(3);
+
+		assert_ok!(PoeModule::create_claims(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![vec![0, 1], vec![0, 2]]
+		));
+		assert_ok!(PoeModule::create_hashed_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 3]
+		));
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 4],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::RateLimited
+		);
+		assert_eq!(ClaimsThisBlock::<Test>::get(ALICE), (1, 3));
+
+		// 其他账户不受影响
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![0, 4],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 下一个区块重新计数
+		System::set_block_number(2);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 5],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(ClaimsThisBlock::<Test>::get(ALICE), (2, 1));
+	});
+}
+
+#[test]
+fn non_transferable_claims_cannot_change_hands() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			Some(false),
+			None,
+			None,
+			None
+		));
+		assert!(!Proofs::<Test>::get(&key(&claim)).unwrap().transferable);
+
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::ClaimNotTransferable
+		);
+		assert_noop!(
+			PoeModule::propose_transfer(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::ClaimNotTransferable
+		);
+
+		// 只有所有者可以修改转移设置
+		assert_noop!(
+			PoeModule::set_transferable(RuntimeOrigin::signed(BOB), vec![], claim.clone(), true),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::set_transferable(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			true
+		));
+		System::assert_last_event(
+			Event::ClaimTransferableSet(ALICE, vec![], claim.clone(), true).into(),
+		);
+
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![BOB]);
+	});
+}
+
+#[test]
+fn locking_a_claim_blocks_a_pending_transfer() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::propose_transfer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_ok!(PoeModule::set_transferable(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			false
+		));
+
+		assert_noop!(
+			PoeModule::accept_transfer(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::ClaimNotTransferable
+		);
+
+		// 锁定的存证仍然可以吊销
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None
+		));
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+	});
+}
+
+#[test]
+fn try_state_passes_for_healthy_storage() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![0, 2],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::create_hashed_claim(RuntimeOrigin::signed(BOB), vec![], vec![0, 3]));
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			BOB
+		));
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(BOB), vec![], vec![0, 2], None));
+
+		assert_ok!(PoeModule::do_try_state());
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(<PoeModule as Hooks<u64>>::try_state(1));
+	});
+}
+
+#[test]
+fn try_state_detects_corrupted_storage() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 存证数量与计数不一致
+		ProofCount::<Test>::put(2);
+		assert_eq!(PoeModule::do_try_state(), Err("ProofCount does not match the stored claims"));
+		ProofCount::<Test>::put(1);
+
+		// 索引中的存证不存在
+		ClaimsByOwner::<Test>::mutate(BOB, |claims| claims.try_push(key(&[0, 2])).unwrap());
+		assert_eq!(PoeModule::do_try_state(), Err("an indexed claim does not exist"));
+		ClaimsByOwner::<Test>::remove(BOB);
+
+		// 存证没有出现在所有者的索引中
+		Proofs::<Test>::insert(key(&[0, 2]), info(BOB, 1, None, 0));
+		ProofCount::<Test>::put(2);
+		assert_eq!(PoeModule::do_try_state(), Err("a claim is missing from its owner's index"));
+		Proofs::<Test>::remove(key(&[0, 2]));
+		ProofCount::<Test>::put(1);
+		assert_ok!(PoeModule::do_try_state());
+
+		// 被冻结的存证不存在
+		FrozenClaims::<Test>::insert(key(&[0, 2]), ());
+		assert_eq!(PoeModule::do_try_state(), Err("a frozen claim does not exist"));
+		FrozenClaims::<Test>::remove(key(&[0, 2]));
+
+		// 命名空间中的存证数量与计数不一致
+		NamespaceCount::<Test>::insert(ns(b""), 2);
+		assert_eq!(
+			PoeModule::do_try_state(),
+			Err("NamespaceCount does not match the stored claims")
+		);
+		NamespaceCount::<Test>::insert(ns(b""), 1);
+
+		// 长度上限降低后，已有的存证超出上限
+		MaxClaimLength::set(1);
+		assert_eq!(PoeModule::do_try_state(), Err("a claim is longer than MaxClaimLength"));
+	});
+}
+
+#[test]
+fn joint_owners_can_revoke_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(PoeModule::add_owner(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+		System::assert_last_event(Event::OwnerAdded(ALICE, vec![], claim.clone(), BOB).into());
+		assert_eq!(
+			Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(),
+			vec![ALICE, BOB]
+		);
+		assert_eq!(ClaimsByOwner::<Test>::get(BOB).into_inner(), vec![key(&claim)]);
+		assert_noop!(
+			PoeModule::add_owner(RuntimeOrigin::signed(BOB), vec![], claim.clone(), ALICE),
+			Error::<Test>::AlreadyOwner
+		);
+		assert_noop!(
+			PoeModule::add_owner(RuntimeOrigin::signed(CHARLIE), vec![], claim.clone(), CHARLIE),
+			Error::<Test>::NotClaimOwner
+		);
+
+		// 共同所有者可以吊销存证，押金退还给创建者
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			None
+		));
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+		assert!(!ClaimsByOwner::<Test>::contains_key(ALICE));
+		assert!(!ClaimsByOwner::<Test>::contains_key(BOB));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn the_last_owner_cannot_be_removed() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::add_owner(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+
+		assert_noop!(
+			PoeModule::remove_owner(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), CHARLIE),
+			Error::<Test>::NotAnOwner
+		);
+
+		// 所有者可以移除自己
+		assert_ok!(PoeModule::remove_owner(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			ALICE
+		));
+		System::assert_last_event(Event::OwnerRemoved(BOB, vec![], claim.clone(), ALICE).into());
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![BOB]);
+		assert!(!ClaimsByOwner::<Test>::contains_key(ALICE));
+
+		assert_noop!(
+			PoeModule::remove_owner(RuntimeOrigin::signed(BOB), vec![], claim.clone(), BOB),
+			Error::<Test>::LastOwner
+		);
+		assert_noop!(
+			PoeModule::remove_owner(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn owners_are_bounded_by_max_owners() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::add_owner(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+		assert_ok!(PoeModule::add_owner(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+
+		assert_noop!(
+			PoeModule::add_owner(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), 4),
+			Error::<Test>::TooManyOwners
+		);
+	});
+}
+
+#[test]
+fn transferring_a_jointly_owned_claim_replaces_every_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::add_owner(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::CannotTransferToSelf
+		);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![CHARLIE]);
+		assert!(!ClaimsByOwner::<Test>::contains_key(ALICE));
+		assert!(!ClaimsByOwner::<Test>::contains_key(BOB));
+		assert_eq!(ClaimsByOwner::<Test>::get(CHARLIE).into_inner(), vec![key(&claim)]);
+		System::assert_has_event(
+			Event::ClaimTransfered(ALICE, CHARLIE, vec![], claim.clone(), 1, 1).into(),
+		);
+		System::assert_last_event(Event::ClaimTransfered(BOB, CHARLIE, vec![], claim, 1, 2).into());
+		assert_ok!(PoeModule::do_try_state());
+	});
+}
+
+#[test]
+fn query_claim_reports_stored_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(PoeModule::query_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone()));
+		System::assert_last_event(Event::ClaimQueried(vec![], claim, Some(ALICE), Some(1)).into());
+	});
+}
+
+#[test]
+fn query_claim_reports_missing_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::query_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone()));
+		System::assert_last_event(Event::ClaimQueried(vec![], claim.clone(), None, None).into());
+
+		// 查询不修改存储，暂停期间也可以调用
+		assert_ok!(PoeModule::set_paused(RuntimeOrigin::root(), true));
+		assert_ok!(PoeModule::query_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone()));
+		assert_eq!(
+			crate::Call::<Test>::query_claim { namespace: vec![], claim }
+				.get_dispatch_info()
+				.weight,
+			Weight::zero()
+		);
+	});
+}
+
+#[test]
+fn proofs_are_keyed_with_the_configured_hasher() {
+	use frame_support::{Blake2_128Concat, StorageHasher, Twox64Concat};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		let raw = Proofs::<Test>::hashed_key_for(&key(&[0, 1]));
+		assert!(raw.ends_with(&Blake2_128Concat::hash(&key(&[0, 1]).encode())));
+		assert_eq!(PoeModule::get_claim(vec![], vec![0, 1], None), Some((ALICE, 1)));
+	});
+
+	content_address::new_test_ext().execute_with(|| {
+		use content_address::{ContentTest, RuntimeOrigin};
+
+		let address = ContentAddress(7, H256::repeat_byte(1));
+		assert_ok!(content_address::PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			address.encode(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		let key: ClaimKeyOf<ContentTest> = (Default::default(), address.clone());
+		let raw = Proofs::<ContentTest>::hashed_key_for(&key);
+		assert!(raw.ends_with(&Twox64Concat::hash(&key.encode())));
+		assert_eq!(
+			content_address::PoeModule::get_claim(vec![], address.encode(), None),
+			Some((ALICE, 1))
+		);
+	});
+}
+
+#[test]
+fn migrating_the_proofs_hasher_keeps_every_claim() {
+	use frame_support::{migration::put_storage_value, StorageHasher, Twox64Concat};
+
+	new_test_ext().execute_with(|| {
+		// 两个存证还在旧的 hash 算法下，一个已经在新的 hash 算法下
+		for (claim, owner) in [(vec![0, 1], ALICE), (vec![0, 2], BOB)] {
+			put_storage_value(
+				b"PoeModule",
+				b"Proofs",
+				&Twox64Concat::hash(&key(&claim).encode()),
+				info(owner, 1, None, 0),
+			);
+		}
+		Proofs::<Test>::insert(key(&[0, 3]), info(CHARLIE, 1, None, 0));
+
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::MigrateProofsHasher::<Test, Twox64Concat>::pre_upgrade().unwrap();
+		migrations::MigrateProofsHasher::<Test, Twox64Concat>::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		migrations::MigrateProofsHasher::<Test, Twox64Concat>::post_upgrade(state).unwrap();
+
+		assert_eq!(Proofs::<Test>::iter().count(), 3);
+		assert_eq!(Proofs::<Test>::get(&key(&[0, 1])), Some(info(ALICE, 1, None, 0)));
+		assert_eq!(Proofs::<Test>::get(&key(&[0, 2])), Some(info(BOB, 1, None, 0)));
+		assert_eq!(Proofs::<Test>::get(&key(&[0, 3])), Some(info(CHARLIE, 1, None, 0)));
+
+		// 再次执行不会丢失存证
+		migrations::MigrateProofsHasher::<Test, Twox64Concat>::on_runtime_upgrade();
+		assert_eq!(Proofs::<Test>::iter().count(), 3);
+		assert_eq!(PoeModule::get_claim(vec![], vec![0, 1], None), Some((ALICE, 1)));
+	});
+}
+
+#[test]
+fn only_whitelisted_accounts_create_claims_when_enforced() {
+	new_test_ext().execute_with(|| {
+		EnforceCreatorWhitelist::set(true);
+
+		assert_noop!(PoeModule::add_creator(RuntimeOrigin::signed(ALICE), ALICE), BadOrigin);
+		assert_ok!(PoeModule::add_creator(RuntimeOrigin::root(), ALICE));
+		System::assert_last_event(Event::CreatorAdded(ALICE).into());
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				vec![0, 2],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::NotWhitelisted
+		);
+		assert_noop!(
+			PoeModule::create_claims(RuntimeOrigin::signed(BOB), vec![], vec![vec![0, 2]]),
+			Error::<Test>::NotWhitelisted
+		);
+		assert_noop!(
+			PoeModule::create_hashed_claim(RuntimeOrigin::signed(BOB), vec![], vec![0, 2]),
+			Error::<Test>::NotWhitelisted
+		);
+
+		// 移出白名单后不能再创建，但已有的存证保留
+		assert_ok!(PoeModule::remove_creator(RuntimeOrigin::root(), ALICE));
+		System::assert_last_event(Event::CreatorRemoved(ALICE).into());
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 3],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::NotWhitelisted
+		);
+		assert!(Proofs::<Test>::contains_key(&key(&[0, 1])));
+		assert_noop!(
+			PoeModule::remove_creator(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::NotWhitelisted
+		);
+	});
+}
+
+#[test]
+fn disabling_the_whitelist_restores_open_access() {
+	new_test_ext().execute_with(|| {
+		EnforceCreatorWhitelist::set(true);
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				vec![0, 1],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::NotWhitelisted
+		);
+
+		EnforceCreatorWhitelist::set(false);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert!(!AllowedCreators::<Test>::contains_key(BOB));
+	});
+}
+
+#[test]
+fn claim_history_records_every_owner_in_order() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		System::set_block_number(2);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		System::set_block_number(3);
+		assert_ok!(PoeModule::propose_transfer(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+		assert_ok!(PoeModule::accept_transfer(
+			RuntimeOrigin::signed(CHARLIE),
+			vec![],
+			claim.clone()
+		));
+
+		assert_eq!(
+			PoeModule::claim_history(vec![], claim.clone(), None),
+			vec![(ALICE, 1), (BOB, 2), (CHARLIE, 3)]
+		);
+
+		// 记录已满时丢弃最早的一条
+		System::set_block_number(4);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(CHARLIE),
+			vec![],
+			claim.clone(),
+			ALICE
+		));
+		assert_eq!(
+			ClaimHistory::<Test>::get(&key(&claim)).into_inner(),
+			vec![(BOB, 2), (CHARLIE, 3), (ALICE, 4)]
+		);
+
+		// 存证删除后记录一并删除
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None
+		));
+		assert!(!ClaimHistory::<Test>::contains_key(&key(&claim)));
+		assert!(PoeModule::claim_history(vec![], claim, None).is_empty());
+	});
+}
+
+#[cfg(feature = "claim-deposits")]
+#[test]
+fn creating_claims_pays_the_fee_to_the_fee_destination() {
+	new_test_ext().execute_with(|| {
+		ClaimCreationFee::set(5);
+
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		// 手续费转给接收账户，押金仍然单独保留
+		assert_eq!(Balances::free_balance(TREASURY), 5);
+		assert_eq!(Balances::reserved_balance(ALICE), 12);
+		assert_eq!(Balances::free_balance(ALICE), 983);
+		System::assert_last_event(
+			Event::ClaimCreated(
+				ALICE,
+				vec![],
+				claim.clone(),
+				info(ALICE, 1, Some(11), 12),
+				5,
+				0,
+				0,
+			)
+			.into(),
+		);
+
+		// 撤销存证只退还押金，手续费不退还
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], claim, None));
+		assert_eq!(Balances::free_balance(ALICE), 995);
+		assert_eq!(Balances::free_balance(TREASURY), 5);
+
+		// 哈希存证同样收取手续费
+		let hashed = vec![7; 10];
+		assert_ok!(PoeModule::create_hashed_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			hashed.clone()
+		));
+		assert_eq!(Balances::free_balance(TREASURY), 10);
+		System::assert_last_event(
+			Event::HashedClaimCreated(BOB, vec![], PoeModule::claim_hash(&hashed), 42, 5, 2).into(),
+		);
+	});
+}
+
+#[cfg(feature = "claim-deposits")]
+#[test]
+fn create_claim_fails_without_enough_balance_for_the_fee() {
+	new_test_ext().execute_with(|| {
+		ClaimCreationFee::set(5);
+		Balances::make_free_balance_be(&BOB, 5);
+
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				vec![0, 1],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::InsufficientFee
+		);
+		assert_eq!(Balances::free_balance(TREASURY), 0);
+	});
+}
+
+#[test]
+fn claims_with_prefix_returns_matching_claims_up_to_the_limit() {
+	new_test_ext().execute_with(|| {
+		for (who, claim) in [
+			(ALICE, vec![1, 1, 0]),
+			(BOB, vec![1, 1, 1]),
+			(ALICE, vec![1, 2, 0]),
+			(BOB, vec![2, 1, 1]),
+		] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(who),
+				vec![],
+				claim,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(CHARLIE),
+			b"docs".to_vec(),
+			vec![1, 1, 2],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 存储迭代顺序不固定，排序后再比较
+		let mut found = PoeModule::claims_with_prefix(&[1, 1], 10)
+			.into_iter()
+			.map(|(claim, owner)| (claim.into_inner(), owner))
+			.collect::<Vec<_>>();
+		found.sort();
+		assert_eq!(
+			found,
+			vec![(vec![1, 1, 0], ALICE), (vec![1, 1, 1], BOB), (vec![1, 1, 2], CHARLIE)]
+		);
+
+		assert_eq!(PoeModule::claims_with_prefix(&[1, 1], 2).len(), 2);
+		assert_eq!(PoeModule::claims_with_prefix(&[1], 10).len(), 4);
+		assert!(PoeModule::claims_with_prefix(&[3], 10).is_empty());
+		assert!(PoeModule::claims_with_prefix(&[1, 1], 0).is_empty());
+	});
+}
+
+#[test]
+fn claims_cannot_be_transferred_during_the_lock_period() {
+	new_test_ext().execute_with(|| {
+		TransferLockPeriod::set(3);
+
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		System::set_block_number(3);
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::ClaimLocked
+		);
+		// 接受待确认的转移同样受锁定期限制
+		assert_ok!(PoeModule::propose_transfer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_noop!(
+			PoeModule::accept_transfer(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::ClaimLocked
+		);
+
+		System::set_block_number(4);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+
+		// 转移不会重置创建区块，新所有者无需再等待锁定期
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().created_at, 1);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+		assert_eq!(PoeModule::get_claim(vec![], claim, None), Some((CHARLIE, 1)));
+	});
+}
+
+#[test]
+fn revoke_delegates_can_revoke_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 只有所有者可以添加代理人
+		assert_noop!(
+			PoeModule::add_revoke_delegate(RuntimeOrigin::signed(BOB), vec![], claim.clone(), BOB),
+			Error::<Test>::NotClaimOwner
+		);
+		for delegate in [BOB, CHARLIE] {
+			assert_ok!(PoeModule::add_revoke_delegate(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				delegate
+			));
+		}
+		System::assert_last_event(
+			Event::RevokeDelegateAdded(ALICE, vec![], claim.clone(), CHARLIE).into(),
+		);
+		assert_noop!(
+			PoeModule::add_revoke_delegate(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				BOB
+			),
+			Error::<Test>::DelegateAlreadyExists
+		);
+		assert_noop!(
+			PoeModule::add_revoke_delegate(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), 4),
+			Error::<Test>::TooManyDelegates
+		);
+
+		// 代理人只能吊销存证，不能转移
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone(), 4),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(4), vec![], claim.clone(), None),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(CHARLIE),
+			vec![],
+			claim.clone(),
+			None
+		));
+		System::assert_last_event(
+			Event::ClaimRevoked(ALICE, CHARLIE, vec![], claim.clone(), 1, 0, 1).into(),
+		);
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+		assert!(!RevokeDelegates::<Test>::contains_key(&key(&claim)));
+	});
+}
+
+#[test]
+fn transferring_a_claim_clears_its_revoke_delegates() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		for delegate in [BOB, CHARLIE] {
+			assert_ok!(PoeModule::add_revoke_delegate(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				delegate
+			));
+		}
+
+		assert_ok!(PoeModule::remove_revoke_delegate(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		System::assert_last_event(
+			Event::RevokeDelegateRemoved(ALICE, vec![], claim.clone(), BOB).into(),
+		);
+		assert_noop!(
+			PoeModule::remove_revoke_delegate(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				BOB
+			),
+			Error::<Test>::DelegateNotFound
+		);
+		assert_eq!(RevokeDelegates::<Test>::get(&key(&claim)).into_inner(), vec![CHARLIE]);
+
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert!(!RevokeDelegates::<Test>::contains_key(&key(&claim)));
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(CHARLIE), vec![], claim, None),
+			Error::<Test>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn revoke_my_expired_stops_at_the_limit() {
+	new_test_ext().execute_with(|| {
+		for i in 0..5u8 {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, i],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![1, 0],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 过期后 on_initialize 尚未清理时由所有者自行删除
+		System::set_block_number(11);
+		let result = PoeModule::revoke_my_expired(RuntimeOrigin::signed(ALICE), 3);
+		assert_ok!(result);
+		System::assert_last_event(Event::ExpiredClaimsRevoked(ALICE, 3).into());
+		assert_eq!(result.unwrap().actual_weight, Some(<() as WeightInfo>::revoke_my_expired(3)));
+		assert_eq!(ClaimsByOwner::<Test>::get(ALICE).len(), 2);
+		#[cfg(feature = "claim-deposits")]
+		assert_eq!(Balances::reserved_balance(ALICE), 24);
+		assert_eq!(ProofCount::<Test>::get(), 3);
+
+		// 只删除调用者自己的存证
+		assert_ok!(PoeModule::revoke_my_expired(RuntimeOrigin::signed(ALICE), 10));
+		System::assert_last_event(Event::ExpiredClaimsRevoked(ALICE, 2).into());
+		assert!(ClaimsByOwner::<Test>::get(ALICE).is_empty());
+		assert!(Proofs::<Test>::contains_key(&key(&[1, 0])));
+	});
+}
+
+#[test]
+fn revoke_my_expired_keeps_valid_claims() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		System::set_block_number(5);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 2],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		System::set_block_number(11);
+		assert_ok!(PoeModule::revoke_my_expired(RuntimeOrigin::signed(ALICE), 10));
+		System::assert_last_event(Event::ExpiredClaimsRevoked(ALICE, 1).into());
+		assert!(!Proofs::<Test>::contains_key(&key(&[0, 1])));
+		assert!(Proofs::<Test>::contains_key(&key(&[0, 2])));
+	});
+}
+
+#[test]
+fn recreating_a_claim_reports_its_generation() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		System::assert_last_event(
+			Event::ClaimCreated(
+				ALICE,
+				vec![],
+				claim.clone(),
+				info(ALICE, 1, Some(11), PoeModule::claim_deposit(2)),
+				0,
+				0,
+				0,
+			)
+			.into(),
+		);
+
+		// 过期清理后由新的所有者重新创建
+		System::set_block_number(11);
+		PoeModule::on_initialize(11);
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		System::assert_last_event(
+			Event::ClaimRecreated(BOB, vec![], claim.clone(), 2, 1, 2).into(),
+		);
+
+		// 每次删除后重新创建，代数都会增加
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			None
+		));
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		System::assert_last_event(
+			Event::ClaimRecreated(ALICE, vec![], claim.clone(), 3, 2, 4).into(),
+		);
+		assert_eq!(ClaimGenerations::<Test>::get(&key(&claim)), 3);
+	});
+}
+
+#[test]
+fn max_claim_length_is_exposed_to_clients() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(PoeModule::max_claim_length(), 10);
+		MaxClaimLength::set(4);
+		assert_eq!(PoeModule::max_claim_length(), 4);
+
+		// 常量出现在元数据中
+		let constant = PoeModule::pallet_constants_metadata()
+			.into_iter()
+			.find(|constant| constant.name == "MaxClaimLength")
+			.unwrap();
+		assert_eq!(constant.value, 4u32.encode());
+
+		// 每个错误都带有说明，客户端可以按编号找到错误信息
+		let errors = Error::<Test>::type_info();
+		let variants = match errors.type_def() {
+			TypeDef::Variant(errors) => errors.variants(),
+			_ => unreachable!("errors are an enum"),
+		};
+		assert!(variants.iter().all(|variant| !variant.docs().is_empty()));
+	});
+}
+
+#[test]
+fn empty_claims_are_rejected() {
+	new_test_ext().execute_with(|| {
+		// 即使不限制最小长度，空存证也会被拒绝
+		MinClaimLength::set(0);
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::EmptyClaim
+		);
+		assert_noop!(
+			PoeModule::create_claims(RuntimeOrigin::signed(ALICE), vec![], vec![vec![0], vec![]]),
+			Error::<Test>::EmptyClaim
+		);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert!(Proofs::<Test>::contains_key(&key(&[0])));
+	});
+}
+
+#[test]
+fn transfer_claim_with_metadata_updates_both() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			Some(vec![1; 4]),
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(PoeModule::transfer_claim_with_metadata(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB,
+			vec![2; 8]
+		));
+		let info = Proofs::<Test>::get(&key(&claim)).unwrap();
+		assert_eq!(info.owners.into_inner(), vec![BOB]);
+		assert_eq!(info.metadata.into_inner(), vec![2; 8]);
+		System::assert_has_event(
+			Event::ClaimTransfered(ALICE, BOB, vec![], claim.clone(), 1, 1).into(),
+		);
+		System::assert_last_event(Event::ClaimMetadataSet(ALICE, vec![], claim.clone()).into());
+
+		// 转移检查失败时元数据保持不变
+		assert_ok!(PoeModule::set_transferable(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			false
+		));
+		assert_noop!(
+			PoeModule::transfer_claim_with_metadata(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				claim.clone(),
+				CHARLIE,
+				vec![3; 8]
+			),
+			Error::<Test>::ClaimNotTransferable
+		);
+		assert_noop!(
+			PoeModule::transfer_claim_with_metadata(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				claim,
+				CHARLIE,
+				vec![3; 17]
+			),
+			Error::<Test>::MetadataTooLong
+		);
+	});
+}
+
+#[test]
+fn account_quotas_override_the_default() {
+	new_test_ext().execute_with(|| {
+		DefaultAccountQuota::set(3);
+
+		assert_noop!(
+			PoeModule::set_account_quota(RuntimeOrigin::signed(ALICE), ALICE, Some(5)),
+			BadOrigin
+		);
+		assert_noop!(
+			PoeModule::set_account_quota(RuntimeOrigin::root(), ALICE, Some(9)),
+			Error::<Test>::QuotaTooHigh
+		);
+		assert_ok!(PoeModule::set_account_quota(RuntimeOrigin::root(), ALICE, Some(5)));
+		System::assert_last_event(Event::AccountQuotaSet(ALICE, Some(5)).into());
+
+		for i in 0..5u8 {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, i],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 5],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::TooManyClaims
+		);
+
+		// 没有单独配额的账户仍使用默认配额
+		for i in 0..3u8 {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				vec![1, i],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				vec![1, 3],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::TooManyClaims
+		);
+
+		// 恢复默认配额后已有的存证保留，但不能再创建
+		assert_ok!(PoeModule::set_account_quota(RuntimeOrigin::root(), ALICE, None));
+		assert!(!AccountQuota::<Test>::contains_key(ALICE));
+		assert_eq!(PoeModule::account_quota(&ALICE), 3);
+		assert_eq!(ClaimsByOwner::<Test>::get(ALICE).len(), 5);
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 5],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::TooManyClaims
+		);
+	});
+}
+
+#[test]
+fn hashed_claims_can_be_revoked_by_hash() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![7; 10];
+		assert_ok!(PoeModule::create_hashed_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone()
+		));
+		let hash = PoeModule::claim_hash(&claim);
+
+		assert_noop!(
+			PoeModule::revoke_claim_by_hash(RuntimeOrigin::signed(ALICE), vec![], H256::zero()),
+			Error::<Test>::ClaimNotExist
+		);
+		assert_noop!(
+			PoeModule::revoke_claim_by_hash(RuntimeOrigin::signed(BOB), vec![], hash),
+			Error::<Test>::NotClaimOwner
+		);
+
+		assert_ok!(PoeModule::revoke_claim_by_hash(RuntimeOrigin::signed(ALICE), vec![], hash));
+		assert!(!HashedProofs::<Test>::contains_key((ns(b""), hash)));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		System::assert_last_event(Event::HashedClaimRevoked(ALICE, vec![], hash, 1).into());
+	});
+}
+
+#[test]
+fn create_claims_best_effort_skips_failing_claims() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![0, 2],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 已存在和过长的存证被跳过，其余存证照常创建
+		assert_ok!(PoeModule::create_claims_best_effort(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![vec![0, 1], vec![0, 2], vec![0; 11], vec![0, 3]]
+		));
+		System::assert_last_event(Event::BatchCompleted(ALICE, 2, 2).into());
+
+		assert_eq!(
+			ClaimsByOwner::<Test>::get(ALICE).into_inner(),
+			vec![key(&[0, 1]), key(&[0, 3])]
+		);
+		assert_eq!(Proofs::<Test>::get(&key(&[0, 2])).unwrap().owners.into_inner(), vec![BOB]);
+		#[cfg(feature = "claim-deposits")]
+		assert_eq!(Balances::reserved_balance(ALICE), 24);
+		assert_eq!(ProofCount::<Test>::get(), 3);
+		assert_ok!(PoeModule::do_try_state());
+
+		assert_noop!(
+			PoeModule::create_claims_best_effort(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![vec![1, 0]; 5]
+			),
+			Error::<Test>::BatchTooLarge
+		);
+	});
+}
+
+#[test]
+fn bounded_claim_checks_the_length() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			PoeModule::bounded_claim(&[1; 10]).map(|claim| claim.into_inner()),
+			Ok(vec![1; 10])
+		);
+		assert_eq!(PoeModule::bounded_claim(&[1; 11]), Err(Error::<Test>::ClaimTooLong));
+		assert_eq!(
+			content_address::PoeModule::bounded_claim(&[0; 3]),
+			Err(Error::<content_address::ContentTest>::InvalidClaim)
+		);
+	});
+}
+
+#[test]
+fn ensure_owner_checks_existence_expiry_and_ownership() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_eq!(
+			PoeModule::ensure_owner(&ALICE, &[], &claim).unwrap_err(),
+			Error::<Test>::ClaimNotExist
+		);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(
+			PoeModule::ensure_owner(&ALICE, &[], &claim),
+			Ok((key(&claim), info(ALICE, 1, Some(11), PoeModule::claim_deposit(2))))
+		);
+		assert_eq!(
+			PoeModule::ensure_owner(&BOB, &[], &claim).unwrap_err(),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_eq!(
+			PoeModule::ensure_owner(&ALICE, &[0; 9], &claim).unwrap_err(),
+			Error::<Test>::NamespaceTooLong
+		);
+
+		System::set_block_number(11);
+		assert_eq!(
+			PoeModule::ensure_owner(&ALICE, &[], &claim).unwrap_err(),
+			Error::<Test>::ClaimExpired
+		);
+	});
+}
+
+#[test]
+fn claim_status_reports_the_remaining_lifetime() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_eq!(PoeModule::claim_status(vec![], claim.clone(), None), ClaimStatus::NotFound);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(
+			PoeModule::claim_status(vec![], claim.clone(), None),
+			ClaimStatus::Active { owner: ALICE, expires_in: Some(10) }
+		);
+
+		System::set_block_number(7);
+		assert_eq!(
+			PoeModule::claim_status(vec![], claim.clone(), None),
+			ClaimStatus::Active { owner: ALICE, expires_in: Some(4) }
+		);
+
+		// 过期但尚未被 on_initialize 清理
+		System::set_block_number(11);
+		assert_eq!(
+			PoeModule::claim_status(vec![], claim.clone(), None),
+			ClaimStatus::Expired { owner: ALICE }
+		);
+
+		assert_eq!(PoeModule::claim_status(vec![], vec![9, 9], None), ClaimStatus::NotFound);
+		assert_eq!(PoeModule::claim_status(vec![], vec![1; 11], None), ClaimStatus::NotFound);
+	});
+}
+
+#[test]
+fn frozen_claim_cannot_be_changed_until_unfrozen() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(PoeModule::set_frozen(RuntimeOrigin::root(), vec![], claim.clone(), true));
+		assert!(FrozenClaims::<Test>::contains_key(&key(&claim)));
+		System::assert_last_event(Event::ClaimFrozenSet(vec![], claim.clone(), true).into());
+
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), None),
+			Error::<Test>::ClaimFrozen
+		);
+		assert_noop!(
+			PoeModule::set_metadata(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), vec![7]),
+			Error::<Test>::ClaimFrozen
+		);
+
+		assert_ok!(PoeModule::set_frozen(RuntimeOrigin::root(), vec![], claim.clone(), false));
+		assert!(!FrozenClaims::<Test>::contains_key(&key(&claim)));
+
+		assert_ok!(PoeModule::set_metadata(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			vec![7]
+		));
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(BOB), vec![], claim, None));
+	});
+}
+
+#[test]
+fn set_frozen_requires_the_freeze_origin_and_an_existing_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_noop!(
+			PoeModule::set_frozen(RuntimeOrigin::root(), vec![], claim.clone(), true),
+			Error::<Test>::ClaimNotExist
+		);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		// 所有者不能自行冻结或解冻存证
+		assert_noop!(
+			PoeModule::set_frozen(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), true),
+			BadOrigin
+		);
+
+		// 强制吊销会同时清除冻结状态
+		assert_ok!(PoeModule::set_frozen(RuntimeOrigin::root(), vec![], claim.clone(), true));
+		assert_ok!(PoeModule::force_revoke(RuntimeOrigin::root(), vec![], claim.clone(), None));
+		assert!(!FrozenClaims::<Test>::contains_key(&key(&claim)));
+	});
+}
+
+#[test]
+fn create_claim_if_absent_is_idempotent_for_the_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim_if_absent(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone()
+		));
+		assert_eq!(PoeModule::get_claim(vec![], claim.clone(), None), Some((ALICE, 1)));
+		#[cfg(feature = "claim-deposits")]
+		assert_eq!(Balances::reserved_balance(ALICE), 12);
+
+		// 重试不会修改存证，也不会再次收取押金
+		assert_ok!(PoeModule::create_claim_if_absent(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone()
+		));
+		System::assert_last_event(Event::ClaimAlreadyPresent(ALICE, vec![], claim.clone()).into());
+		#[cfg(feature = "claim-deposits")]
+		assert_eq!(Balances::reserved_balance(ALICE), 12);
+		assert_eq!(ClaimsByOwner::<Test>::get(ALICE).len(), 1);
+
+		assert_noop!(
+			PoeModule::create_claim_if_absent(RuntimeOrigin::signed(BOB), vec![], claim),
+			Error::<Test>::NotClaimOwner
+		);
+	});
+}
+
+#[test]
+fn create_origin_decides_the_owner_of_new_claims() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		Balances::make_free_balance_be(&COLLECTIVE, 100);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::root(),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(PoeModule::get_claim(vec![], claim.clone(), None), Some((COLLECTIVE, 1)));
+		assert_eq!(ClaimsByOwner::<Test>::get(COLLECTIVE).into_inner(), vec![key(&claim)]);
+		#[cfg(feature = "claim-deposits")]
+		assert_eq!(Balances::reserved_balance(COLLECTIVE), 12);
+
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::none(),
+				vec![],
+				vec![0, 2],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn claims_record_the_block_and_the_unix_time_of_their_creation() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		System::set_block_number(3);
+		Timestamp::set_timestamp(MOMENT + 12_000);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(PoeModule::get_claim(vec![], claim.clone(), None), Some((ALICE, 3)));
+		assert_eq!(PoeModule::claim_moment(vec![], claim.clone()), Some(MOMENT + 12_000));
+
+		// 转移不会改变创建时间
+		Timestamp::set_timestamp(MOMENT + 18_000);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_eq!(PoeModule::claim_moment(vec![], claim.clone()), Some(MOMENT + 12_000));
+
+		assert_eq!(PoeModule::claim_moment(vec![], vec![0, 2]), None);
+	});
+}
+
+#[test]
+fn supersede_claim_keeps_the_identity_of_the_old_claim() {
+	new_test_ext().execute_with(|| {
+		let old_claim = vec![0, 1];
+		let new_claim = vec![0, 1, 2];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			old_claim.clone(),
+			Some(vec![7]),
+			None,
+			None,
+			None,
+			None
+		));
+
+		System::set_block_number(4);
+		assert_ok!(PoeModule::supersede_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			old_claim.clone(),
+			new_claim.clone()
+		));
+		System::assert_last_event(
+			Event::ClaimSuperseded(ALICE, vec![], old_claim.clone(), new_claim.clone(), 1).into(),
+		);
+
+		// 新存证沿用原来的创建区块、过期区块、元数据和所有权记录，押金按新长度计算
+		assert_eq!(
+			Proofs::<Test>::get(&key(&new_claim)),
+			Some(ClaimInfo {
+				metadata: BoundedVec::truncate_from(vec![7]),
+				..info(ALICE, 1, Some(11), PoeModule::claim_deposit(3))
+			})
+		);
+		assert!(!Proofs::<Test>::contains_key(&key(&old_claim)));
+		#[cfg(feature = "claim-deposits")]
+		assert_eq!(Balances::reserved_balance(ALICE), 13);
+		assert_eq!(ClaimsByOwner::<Test>::get(ALICE).into_inner(), vec![key(&new_claim)]);
+		assert_eq!(ExpiringAt::<Test>::get(11).unwrap().into_inner(), vec![key(&new_claim)]);
+		assert_eq!(PoeModule::claim_history(vec![], new_claim.clone(), None), vec![(ALICE, 1)]);
+		assert_eq!(ProofCount::<Test>::get(), 1);
+
+		// 旧存证通过别名解析到新存证
+		assert_eq!(Superseded::<Test>::get(&key(&old_claim)), Some(bounded(&new_claim)));
+		assert_eq!(
+			PoeModule::resolve_claim(vec![], old_claim.clone()),
+			Some((new_claim.clone(), ALICE, 1))
+		);
+		assert_eq!(
+			PoeModule::resolve_claim(vec![], new_claim.clone()),
+			Some((new_claim.clone(), ALICE, 1))
+		);
+		assert_eq!(PoeModule::resolve_claim(vec![], vec![9, 9]), None);
+
+		// 重新创建旧存证后不再跟随别名
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			old_claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert!(!Superseded::<Test>::contains_key(&key(&old_claim)));
+		assert_eq!(PoeModule::resolve_claim(vec![], old_claim), Some((vec![0, 1], BOB, 4)));
+	});
+}
+
+#[test]
+fn superseded_claims_keep_their_viewers_and_are_logged() {
+	new_test_ext().execute_with(|| {
+		let old_claim = vec![0, 1];
+		let new_claim = vec![0, 1, 2];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			old_claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::set_private(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			old_claim.clone(),
+			true
+		));
+		assert_ok!(PoeModule::add_viewer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			old_claim.clone(),
+			BOB
+		));
+
+		assert_ok!(PoeModule::supersede_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			old_claim.clone(),
+			new_claim.clone()
+		));
+
+		// 新存证仍是私有的，原来的查看者仍可以查到
+		assert!(Proofs::<Test>::get(&key(&new_claim)).unwrap().private);
+		assert_eq!(Viewers::<Test>::get(&key(&new_claim)).into_inner(), vec![BOB]);
+		assert!(!Viewers::<Test>::contains_key(&key(&old_claim)));
+		assert_eq!(PoeModule::get_claim(vec![], new_claim.clone(), Some(BOB)), Some((ALICE, 1)));
+		assert_eq!(PoeModule::get_claim(vec![], new_claim.clone(), Some(CHARLIE)), None);
+
+		// 新存证和其他新建的存证一样记入创建记录
+		assert_eq!(CreationLog::<Test>::get(1), Some((key(&new_claim), ALICE)));
+		assert_eq!(NextSeq::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn supersede_claim_fails_for_existing_or_foreign_claims() {
+	new_test_ext().execute_with(|| {
+		for claim in [vec![0, 1], vec![0, 2]] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+
+		assert_noop!(
+			PoeModule::supersede_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 1],
+				vec![0, 2]
+			),
+			Error::<Test>::ProofAlreadyExistOwnedBySelf
+		);
+		assert_noop!(
+			PoeModule::supersede_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 1],
+				vec![0, 1]
+			),
+			Error::<Test>::ProofAlreadyExistOwnedBySelf
+		);
+		assert_noop!(
+			PoeModule::supersede_claim(RuntimeOrigin::signed(BOB), vec![], vec![0, 1], vec![0, 3]),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::supersede_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 1],
+				vec![1; 11]
+			),
+			Error::<Test>::ClaimTooLong
+		);
+	});
+}
+
+#[test]
+fn transfers_can_require_an_existing_recipient() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		let unused = 42;
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		RequireRecipientExists::set(true);
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), unused),
+			Error::<Test>::RecipientNotFound
+		);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+
+		// 关闭检查时可以转移给任何账户
+		RequireRecipientExists::set(false);
+		assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(BOB), vec![], claim, unused));
+	});
+}
+
+#[test]
+fn list_claims_pages_through_every_claim() {
+	let genesis: Vec<_> =
+		(0..10u8).map(|i| (vec![i + 1], if i < 5 { ALICE } else { BOB })).collect();
+	new_test_ext_with_claims(genesis.clone()).execute_with(|| {
+		let mut listed = Vec::new();
+		let mut cursor = None;
+		let mut pages = 0;
+		loop {
+			let (claims, next) = PoeModule::list_claims(cursor, 4);
+			assert!(claims.len() <= 4);
+			listed.extend(claims.into_iter().map(|(claim, owner, created_at)| {
+				assert_eq!(created_at, 0);
+				(claim.into_inner(), owner)
+			}));
+			pages += 1;
+			match next {
+				Some(next) => cursor = Some(next),
+				None => break,
+			}
+		}
+
+		assert_eq!(pages, 3);
+		listed.sort();
+		assert_eq!(listed, genesis);
+	});
+}
+
+#[test]
+fn integrity_test_accepts_a_sane_config() {
+	<PoeModule as Hooks<u64>>::integrity_test();
+}
+
+#[test]
+#[should_panic(expected = "MaxClaimLength must be greater than zero")]
+fn integrity_test_rejects_zero_max_claim_length() {
+	MaxClaimLength::set(0);
+	MinClaimLength::set(0);
+	<PoeModule as Hooks<u64>>::integrity_test();
+}
+
+#[test]
+#[should_panic(expected = "MinClaimLength must not exceed MaxClaimLength")]
+fn integrity_test_rejects_min_above_max_claim_length() {
+	MinClaimLength::set(11);
+	<PoeModule as Hooks<u64>>::integrity_test();
+}
+
+#[test]
+#[should_panic(expected = "DefaultClaimExpiry must be at least one block")]
+fn integrity_test_rejects_zero_default_claim_expiry() {
+	DefaultClaimExpiry::set(0);
+	<PoeModule as Hooks<u64>>::integrity_test();
+}
+
+#[cfg(feature = "claim-deposits")]
+#[test]
+fn force_revoke_and_slash_does_not_refund_the_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		let deposit = PoeModule::claim_deposit(claim.len());
+		assert_eq!(Balances::reserved_balance(ALICE), deposit);
+		let free = Balances::free_balance(ALICE);
+
+		assert_noop!(
+			PoeModule::force_revoke_and_slash(RuntimeOrigin::signed(ALICE), vec![], claim.clone()),
+			BadOrigin
+		);
+		assert_ok!(PoeModule::force_revoke_and_slash(RuntimeOrigin::root(), vec![], claim.clone()));
+
+		// 押金被罚没并转给 TREASURY，没有退还给所有者
+		assert!(!Proofs::<Test>::contains_key(key(&claim)));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), free);
+		assert_eq!(Balances::free_balance(TREASURY), deposit);
+		System::assert_has_event(Event::ClaimForceRevoked(vec![], claim, 0, 1).into());
+		System::assert_last_event(Event::ClaimSlashed(ALICE, deposit).into());
+	});
+}
+
+#[cfg(feature = "claim-deposits")]
+#[test]
+fn force_revoke_still_refunds_the_deposit() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		let free = Balances::free_balance(ALICE);
+
+		assert_ok!(PoeModule::force_revoke(RuntimeOrigin::root(), vec![], claim, None));
+
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), free + PoeModule::claim_deposit(2));
+		assert_eq!(Balances::free_balance(TREASURY), 0);
+	});
+}
+
+#[test]
+fn require_utf8_rejects_invalid_claims() {
+	new_test_ext().execute_with(|| {
+		// 未启用时接受任意字节
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0xff, 0xfe],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		RequireUtf8::set(true);
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0xc3, 0x28],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::InvalidUtf8
+		);
+		assert_noop!(
+			PoeModule::create_hashed_claim(RuntimeOrigin::signed(ALICE), vec![], vec![0xc3, 0x28]),
+			Error::<Test>::InvalidUtf8
+		);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			"证明".as_bytes().to_vec(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+	});
+}
+
+#[test]
+fn allow_listed_accounts_can_claim_transfer() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 不在白名单中的账户不能领取
+		assert_noop!(
+			PoeModule::claim_transfer(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::NotAllowed
+		);
+
+		// 只有所有者可以修改白名单
+		assert_noop!(
+			PoeModule::allow_transfer_to(RuntimeOrigin::signed(BOB), vec![], claim.clone(), BOB),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::allow_transfer_to(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		System::assert_last_event(Event::TransferAllowed(ALICE, vec![], claim.clone(), BOB).into());
+		assert_noop!(
+			PoeModule::allow_transfer_to(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::AlreadyAllowed
+		);
+		assert_ok!(PoeModule::allow_transfer_to(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+		assert_noop!(
+			PoeModule::allow_transfer_to(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), 4),
+			Error::<Test>::TooManyAllowed
+		);
+
+		assert_ok!(PoeModule::claim_transfer(
+			RuntimeOrigin::signed(CHARLIE),
+			vec![],
+			claim.clone()
+		));
+		assert_eq!(
+			Proofs::<Test>::get(&key(&claim)).map(|info| info.owners.into_inner()),
+			Some(vec![CHARLIE])
+		);
+		System::assert_last_event(
+			Event::ClaimTransfered(ALICE, CHARLIE, vec![], claim.clone(), 1, 1).into(),
+		);
+
+		// 转移后白名单被清空，BOB 不能再领取
+		assert!(!TransferAllowList::<Test>::contains_key(key(&claim)));
+		assert_noop!(
+			PoeModule::claim_transfer(RuntimeOrigin::signed(BOB), vec![], claim),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn disallow_transfer_to_removes_an_account() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::allow_transfer_to(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+
+		assert_ok!(PoeModule::disallow_transfer_to(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		System::assert_last_event(
+			Event::TransferDisallowed(ALICE, vec![], claim.clone(), BOB).into(),
+		);
+		assert!(!TransferAllowList::<Test>::contains_key(key(&claim)));
+		assert_noop!(
+			PoeModule::disallow_transfer_to(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				BOB
+			),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			PoeModule::claim_transfer(RuntimeOrigin::signed(BOB), vec![], claim),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn transfers_notify_the_transfer_handler() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(LastTransfer::get(), None);
+
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_eq!(LastTransfer::get(), Some((claim.clone(), ALICE, BOB)));
+
+		// 接受转移同样会通知
+		assert_ok!(PoeModule::propose_transfer(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+		assert_ok!(PoeModule::accept_transfer(
+			RuntimeOrigin::signed(CHARLIE),
+			vec![],
+			claim.clone()
+		));
+		assert_eq!(LastTransfer::get(), Some((claim, BOB, CHARLIE)));
+	});
+}
+
+#[test]
+fn create_claim_weight_grows_with_owned_claims() {
+	new_test_ext().execute_with(|| {
+		let first = PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None,
+		);
+		assert_eq!(first.unwrap().actual_weight, Some(<() as WeightInfo>::create_claim(2, 0)));
+
+		let second = PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 2],
+			None,
+			None,
+			None,
+			None,
+			None,
+		);
+		assert_eq!(second.unwrap().actual_weight, Some(<() as WeightInfo>::create_claim(2, 1)));
+		assert!(
+			<() as WeightInfo>::create_claim(2, 1).ref_time()
+				> <() as WeightInfo>::create_claim(2, 0).ref_time()
+		);
+
+		// 预先按最长的存证和最多的已有存证收取
+		let call = crate::Call::<Test>::create_claim {
+			namespace: vec![],
+			claim: vec![0, 3],
+			metadata: None,
+			transferable: None,
+			parent: None,
+			content_type: None,
+			ttl: None,
+		};
+		assert_eq!(call.get_dispatch_info().weight, <() as WeightInfo>::create_claim(10, 8));
+	});
+}
+
+#[test]
+fn transfer_all_claims_skips_ineligible_claims() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=4 {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, i],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_ok!(PoeModule::set_frozen(RuntimeOrigin::root(), vec![], vec![0, 2], true));
+
+		assert_noop!(
+			PoeModule::transfer_all_claims(RuntimeOrigin::signed(ALICE), ALICE, 10),
+			Error::<Test>::CannotTransferToSelf
+		);
+		let result = PoeModule::transfer_all_claims(RuntimeOrigin::signed(ALICE), BOB, 10);
+		assert_ok!(result);
+		System::assert_last_event(Event::ClaimsBulkTransferred(ALICE, BOB, 3, 1).into());
+		assert_eq!(
+			result.unwrap().actual_weight,
+			Some(
+				<() as WeightInfo>::transfer_all_claims(3)
+					.saturating_add(<Test as frame_system::Config>::DbWeight::get().reads(1))
+			)
+		);
+
+		// 被冻结的存证留在原所有者名下
+		assert_eq!(ClaimsByOwner::<Test>::get(ALICE).into_inner(), vec![key(&[0, 2])]);
+		assert_eq!(
+			ClaimsByOwner::<Test>::get(BOB).into_inner(),
+			vec![key(&[0, 1]), key(&[0, 3]), key(&[0, 4])]
+		);
+		assert_eq!(
+			Proofs::<Test>::get(key(&[0, 3])).map(|info| info.owners.into_inner()),
+			Some(vec![BOB])
+		);
+	});
+}
+
+#[test]
+fn transfer_all_claims_stops_at_the_limit() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=3 {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, i],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+
+		assert_ok!(PoeModule::transfer_all_claims(RuntimeOrigin::signed(ALICE), BOB, 2));
+		System::assert_last_event(Event::ClaimsBulkTransferred(ALICE, BOB, 2, 0).into());
+		assert_eq!(ClaimsByOwner::<Test>::get(ALICE).len(), 1);
+		assert_eq!(ClaimsByOwner::<Test>::get(BOB).len(), 2);
+	});
+}
+
+#[test]
+fn validate_claim_matches_create_claim_errors() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_storage_noop!(assert_eq!(
+			PoeModule::validate_claim(&BOB, &[], &[0; 11]),
+			Err(Error::<Test>::ClaimTooLong)
+		));
+		assert_storage_noop!(assert_eq!(
+			PoeModule::validate_claim(&BOB, &[], &[0, 1]),
+			Err(Error::<Test>::ProofAlreadyExistOwnedByOther)
+		));
+		assert_storage_noop!(assert_eq!(PoeModule::validate_claim(&BOB, &[], &[0, 2]), Ok(())));
+
+		// 与可调用函数返回相同的错误
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				vec![0; 11],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ClaimTooLong
+		);
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				vec![0, 1],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ProofAlreadyExistOwnedByOther
+		);
+	});
+}
+
+#[test]
+fn recreating_a_claim_tells_apart_its_owner() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ProofAlreadyExistOwnedBySelf
+		);
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				claim,
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::ProofAlreadyExistOwnedByOther
+		);
+	});
+}
+
+#[test]
+fn namespaces_per_account_are_capped() {
+	new_test_ext().execute_with(|| {
+		for namespace in [b"a", b"b", b"c"] {
+			assert_ok!(PoeModule::register_namespace(
+				RuntimeOrigin::signed(ALICE),
+				namespace.to_vec()
+			));
+		}
+		System::assert_last_event(Event::NamespaceRegistered(ALICE, b"c".to_vec()).into());
+		assert_eq!(NamespaceOwners::<Test>::get(ns(b"a")), Some(ALICE));
+		assert_eq!(NamespacesOwned::<Test>::get(ALICE), 3);
+
+		assert_noop!(
+			PoeModule::register_namespace(RuntimeOrigin::signed(ALICE), b"d".to_vec()),
+			Error::<Test>::TooManyNamespaces
+		);
+		assert_noop!(
+			PoeModule::register_namespace(RuntimeOrigin::signed(BOB), b"a".to_vec()),
+			Error::<Test>::NamespaceTaken
+		);
+		assert_noop!(
+			PoeModule::register_namespace(RuntimeOrigin::signed(BOB), vec![]),
+			Error::<Test>::NamespaceReserved
+		);
+		assert_noop!(
+			PoeModule::release_namespace(RuntimeOrigin::signed(BOB), b"a".to_vec()),
+			Error::<Test>::NamespaceNotOwned
+		);
+
+		// 释放一个命名空间后可以再登记一个
+		assert_ok!(PoeModule::release_namespace(RuntimeOrigin::signed(ALICE), b"b".to_vec()));
+		System::assert_last_event(Event::NamespaceReleased(ALICE, b"b".to_vec()).into());
+		assert_eq!(NamespaceOwners::<Test>::get(ns(b"b")), None);
+		assert_eq!(NamespacesOwned::<Test>::get(ALICE), 2);
+		assert_ok!(PoeModule::register_namespace(RuntimeOrigin::signed(ALICE), b"d".to_vec()));
+		assert_ok!(PoeModule::do_try_state());
+	});
+}
+
+#[test]
+fn creating_a_claim_registers_its_namespace() {
+	new_test_ext().execute_with(|| {
+		for namespace in [b"a", b"b", b"c"] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				namespace.to_vec(),
+				vec![0, 1],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_eq!(NamespaceOwners::<Test>::get(ns(b"c")), Some(ALICE));
+		assert_eq!(NamespacesOwned::<Test>::get(ALICE), 3);
+
+		// 已登记的和空的命名空间不占用配额
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			b"a".to_vec(),
+			vec![0, 2],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				b"d".to_vec(),
+				vec![0, 1],
+				None,
+				None,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::TooManyNamespaces
+		);
+
+		// 其他账户仍然可以在已登记的命名空间中创建存证
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			b"a".to_vec(),
+			vec![0, 3],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(NamespacesOwned::<Test>::get(BOB), 0);
+	});
+}
+
+#[test]
+fn emit_proof_count_reports_the_claim_count() {
+	new_test_ext().execute_with(|| {
+		for i in 1..=3 {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, i],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+
+		assert_noop!(PoeModule::emit_proof_count(RuntimeOrigin::none()), BadOrigin);
+		assert_ok!(PoeModule::emit_proof_count(RuntimeOrigin::signed(BOB)));
+		System::assert_last_event(Event::ProofCountReported(3).into());
+	});
+}
+
+#[test]
+fn claims_can_reference_a_parent() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![0, 2],
+			None,
+			None,
+			Some(vec![0, 1]),
+			None,
+			None
+		));
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 3],
+			None,
+			None,
+			Some(vec![0, 2]),
+			None,
+			None
+		));
+
+		assert_eq!(Proofs::<Test>::get(&key(&[0, 2])).unwrap().parent, Some(bounded(&[0, 1])));
+		assert_eq!(Proofs::<Test>::get(&key(&[0, 3])).unwrap().parent, Some(bounded(&[0, 2])));
+		assert_eq!(PoeModule::ancestors(vec![], vec![0, 3], 10), vec![vec![0, 2], vec![0, 1]]);
+		assert_eq!(PoeModule::ancestors(vec![], vec![0, 3], 1), vec![vec![0, 2]]);
+		assert_eq!(PoeModule::ancestors(vec![], vec![0, 1], 10), Vec::<Vec<u8>>::new());
+
+		// 父存证被撤销后仍会列出，但不再继续向上查找
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(BOB), vec![], vec![0, 2], None));
+		assert_eq!(PoeModule::ancestors(vec![], vec![0, 3], 10), vec![vec![0, 2]]);
+	});
+}
+
+#[test]
+fn parent_must_exist_in_the_same_namespace() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 2],
+				None,
+				None,
+				Some(vec![0, 1]),
+				None,
+				None
+			),
+			Error::<Test>::ParentNotExist
+		);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			b"a".to_vec(),
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 2],
+				None,
+				None,
+				Some(vec![0, 1]),
+				None,
+				None
+			),
+			Error::<Test>::ParentNotExist
+		);
+	});
+}
+
+#[test]
+fn admin_calls_are_operational() {
+	new_test_ext().execute_with(|| {
+		let set_paused = crate::Call::<Test>::set_paused { paused: true };
+		assert_eq!(set_paused.get_dispatch_info().class, DispatchClass::Operational);
+		let force_revoke =
+			crate::Call::<Test>::force_revoke { namespace: vec![], claim: vec![0, 1] };
+		assert_eq!(force_revoke.get_dispatch_info().class, DispatchClass::Operational);
+		let force_revoke_and_slash =
+			crate::Call::<Test>::force_revoke_and_slash { namespace: vec![], claim: vec![0, 1] };
+		assert_eq!(force_revoke_and_slash.get_dispatch_info().class, DispatchClass::Operational);
+
+		let create_claim = crate::Call::<Test>::create_claim {
+			namespace: vec![],
+			claim: vec![0, 1],
+			metadata: None,
+			transferable: None,
+			parent: None,
+			content_type: None,
+			ttl: None,
+		};
+		assert_eq!(create_claim.get_dispatch_info().class, DispatchClass::Normal);
+	});
+}
+
+#[test]
+fn content_type_is_set_at_creation_and_updatable() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			Some(b"application/pdf".to_vec()),
+			None
+		));
+		let content_type = |claim: &[u8]| {
+			Proofs::<Test>::get(&key(claim))
+				.unwrap()
+				.content_type
+				.map(|tag| tag.into_inner())
+		};
+		assert_eq!(content_type(&claim), Some(b"application/pdf".to_vec()));
+
+		assert_noop!(
+			PoeModule::set_content_type(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				claim.clone(),
+				Some(b"image/png".to_vec())
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::set_content_type(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			Some(b"image/png".to_vec())
+		));
+		assert_eq!(content_type(&claim), Some(b"image/png".to_vec()));
+		System::assert_last_event(
+			Event::ClaimContentTypeSet(ALICE, vec![], claim.clone(), Some(b"image/png".to_vec()))
+				.into(),
+		);
+
+		assert_ok!(PoeModule::set_content_type(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None
+		));
+		assert_eq!(content_type(&claim), None);
+	});
+}
+
+#[test]
+fn content_type_length_is_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 1],
+				None,
+				None,
+				None,
+				Some(vec![b'a'; 33]),
+				None
+			),
+			Error::<Test>::ContentTypeTooLong
+		);
+
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			Some(vec![b'a'; 32]),
+			None
+		));
+		assert_noop!(
+			PoeModule::set_content_type(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![0, 1],
+				Some(vec![b'a'; 33])
+			),
+			Error::<Test>::ContentTypeTooLong
+		);
+	});
+}
+
+#[test]
+fn get_claim_compat_reads_legacy_tuples() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<PoeModule>();
+		migrations::v1::Proofs::<Test>::insert(bounded(&[0, 1]), (ALICE, 3));
+
+		assert_eq!(migrations::get_claim_compat::<Test>(&[], &[0, 1]), Some((ALICE, 3)));
+		assert_eq!(migrations::get_claim_compat::<Test>(b"a", &[0, 1]), None);
+		assert_eq!(migrations::get_claim_compat::<Test>(&[], &[0, 2]), None);
+
+		// 已按新布局写入的存证也能读到
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			vec![0, 2],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(migrations::get_claim_compat::<Test>(&[], &[0, 2]), Some((BOB, 1)));
+	});
+}
+
+#[test]
+fn get_claim_compat_reads_the_current_layout() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			b"a".to_vec(),
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_eq!(migrations::get_claim_compat::<Test>(b"a", &[0, 1]), Some((ALICE, 1)));
+
+		StorageVersion::new(2).put::<PoeModule>();
+		migrations::v2::Proofs::<Test>::insert(bounded(&[0, 2]), old_info(BOB, 4, None, 0));
+		assert_eq!(migrations::get_claim_compat::<Test>(&[], &[0, 2]), Some((BOB, 4)));
+	});
+}
+
+#[test]
+fn transferred_claims_cool_down_before_moving_again() {
+	new_test_ext().execute_with(|| {
+		TransferCooldown::set(5);
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 从未转移过的存证可以立即转移
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().last_transferred_at, Some(1));
+
+		System::set_block_number(5);
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone(), CHARLIE),
+			Error::<Test>::TransferCooldownActive
+		);
+
+		System::set_block_number(6);
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![CHARLIE]);
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().last_transferred_at, Some(6));
+	});
+}
+
+#[test]
+fn simulate_create_predicts_the_deposit_without_creating() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			PoeModule::simulate_create(ALICE, vec![], vec![0, 1]),
+			SimulateResult {
+				deposit: PoeModule::claim_deposit(2),
+				fee: 0,
+				would_succeed: true,
+				error: None
+			}
+		);
+		assert!(!Proofs::<Test>::contains_key(&key(&[0, 1])));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(ProofCount::<Test>::get(), 0);
+		assert!(System::events().is_empty());
+
+		// 报告稳定的错误编码，而不是错误的声明顺序
+		let too_long = PoeModule::error_code(&Error::<Test>::ClaimTooLong);
+		assert_eq!(too_long, 2);
+		assert_eq!(
+			PoeModule::simulate_create(ALICE, vec![], vec![0; 11]),
+			SimulateResult {
+				deposit: PoeModule::claim_deposit(11),
+				fee: 0,
+				would_succeed: false,
+				error: Some(too_long)
+			}
+		);
+	});
+}
+
+#[cfg(feature = "claim-deposits")]
+#[test]
+fn hold_deposits_hold_release_and_slash_through_the_fungible_api() {
+	use crate::deposits::{ClaimDeposits, HoldDeposits};
+	use frame_support::traits::fungible::InspectHold;
+
+	type Deposits = HoldDeposits<Balances, ()>;
+	new_test_ext().execute_with(|| {
+		// 押金以 hold 的形式保留，可以通过 fungible 接口查询
+		assert_ok!(Deposits::reserve(&ALICE, 12));
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&ALICE), 12);
+		assert_eq!(Deposits::reserved_balance(&ALICE), 12);
+		Deposits::unreserve(&ALICE, 12);
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 1000);
+
+		// 罚没的押金被销毁，手续费从可用余额中支付
+		assert_ok!(Deposits::reserve(&ALICE, 12));
+		assert_eq!(Deposits::slash_reserved(&ALICE, 5), 5);
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&ALICE), 7);
+		assert_eq!(Balances::free_balance(ALICE), 988);
+		assert_ok!(Deposits::pay_fee(&ALICE, &BOB, 10));
+		assert_eq!(Balances::free_balance(ALICE), 978);
+		assert_eq!(Balances::free_balance(BOB), 1010);
+		assert!(Deposits::reserve(&ALICE, 1000).is_err());
+	});
+}
+
+#[cfg(feature = "claim-deposits")]
+#[test]
+fn claim_deposits_can_be_held_with_the_fungible_api() {
+	use frame_support::traits::fungible::InspectHold;
+
+	content_address::new_test_ext().execute_with(|| {
+		use content_address::{Balances, ContentTest, RuntimeOrigin};
+
+		// 内容地址运行时用 HoldDeposits 保留押金，创建时持有，撤销时全部释放
+		let address = ContentAddress(7, H256::repeat_byte(1));
+		assert_ok!(content_address::PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			address.encode(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		let key: ClaimKeyOf<ContentTest> = (Default::default(), address.clone());
+		let deposit = Proofs::<ContentTest>::get(&key).unwrap().deposit;
+		assert!(deposit > 0);
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&ALICE), deposit);
+
+		assert_ok!(content_address::PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			address.encode(),
+			None
+		));
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&ALICE), 0);
+	});
+}
+
+#[cfg(not(feature = "claim-deposits"))]
+#[test]
+fn claims_record_no_deposit_without_claim_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::create_hashed_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 2]
+		));
+
+		// 没有预留任何押金，存证中记录的押金也为零
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Proofs::<Test>::get(&key(&[0, 1])).unwrap().deposit, 0);
+		assert_eq!(
+			HashedProofs::<Test>::get((ns(b""), PoeModule::claim_hash(&[0, 2])))
+				.unwrap()
+				.deposit,
+			0
+		);
+		assert_eq!(PoeModule::simulate_create(ALICE, vec![], vec![0, 3]).deposit, 0);
+	});
+}
+
+#[test]
+fn labels_are_separate_from_claim_identity() {
+	new_test_ext().execute_with(|| {
+		let first = vec![0, 1];
+		let second = vec![2, 3];
+		for claim in [&first, &second] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+
+		assert_ok!(PoeModule::set_label(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			first.clone(),
+			b"report".to_vec()
+		));
+		assert_eq!(Labels::<Test>::get(&key(&first)).unwrap().into_inner(), b"report".to_vec());
+		System::assert_last_event(
+			Event::ClaimLabelSet(ALICE, vec![], first.clone(), b"report".to_vec()).into(),
+		);
+
+		// 两个存证可以使用相同的显示名称
+		assert_ok!(PoeModule::set_label(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			second.clone(),
+			b"report".to_vec()
+		));
+		assert_eq!(Labels::<Test>::get(&key(&first)), Labels::<Test>::get(&key(&second)));
+
+		// 只有所有者可以设置，且长度受限
+		assert_noop!(
+			PoeModule::set_label(RuntimeOrigin::signed(BOB), vec![], first.clone(), vec![1]),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_noop!(
+			PoeModule::set_label(RuntimeOrigin::signed(ALICE), vec![], first.clone(), vec![1; 65]),
+			Error::<Test>::LabelTooLong
+		);
+
+		// 吊销存证时清除显示名称，另一个存证不受影响
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			first.clone(),
+			None
+		));
+		assert!(!Labels::<Test>::contains_key(&key(&first)));
+		assert!(Labels::<Test>::contains_key(&key(&second)));
+	});
+}
+
+#[test]
+fn reaped_accounts_lose_their_claims() {
+	new_test_ext().execute_with(|| {
+		let sole = vec![0, 1];
+		let joint = vec![2, 3];
+		for claim in [&sole, &joint] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			sole.clone(),
+			BOB
+		));
+		assert_ok!(PoeModule::add_owner(RuntimeOrigin::signed(ALICE), vec![], joint.clone(), BOB));
+		assert_eq!(ClaimsByOwner::<Test>::get(BOB).len(), 2);
+		let hashed = vec![4, 5];
+		assert_ok!(PoeModule::create_hashed_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			hashed.clone()
+		));
+		let hash = PoeModule::claim_hash(&hashed);
+
+		// 预留的押金会让账户保持存活，先释放它，BOB 转出全部余额后账户被回收
+		<Balances as frame_support::traits::ReservableCurrency<u64>>::unreserve(
+			&BOB,
+			Balances::reserved_balance(BOB),
+		);
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(BOB), CHARLIE, 1_000));
+		assert!(!System::account_exists(&BOB));
+
+		// 独自持有的存证被删除，押金退还给押金提供者
+		assert!(!Proofs::<Test>::contains_key(&key(&sole)));
+		assert!(Tombstones::<Test>::contains_key(&key(&sole)));
+		#[cfg(feature = "claim-deposits")]
+		assert_eq!(Balances::reserved_balance(ALICE), 12);
+		System::assert_has_event(Event::ClaimReaped(BOB, vec![], sole, 4).into());
+
+		// 共同持有的存证只移除 BOB
+		assert_eq!(Proofs::<Test>::get(&key(&joint)).unwrap().owners.into_inner(), vec![ALICE]);
+		assert!(!ClaimsByOwner::<Test>::contains_key(BOB));
+
+		// 哈希存证同样被删除
+		assert!(!HashedProofs::<Test>::contains_key((ns(b""), hash)));
+		assert!(!HashedClaimsByOwner::<Test>::contains_key(BOB));
+		assert_eq!(ProofCount::<Test>::get(), 1);
+		System::assert_has_event(Event::HashedClaimReaped(BOB, vec![], hash, 5).into());
+		assert_ok!(PoeModule::do_try_state());
+	});
+}
+
+#[test]
+fn escrowed_claims_are_released_to_the_beneficiary() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 释放区块必须晚于当前区块
+		for release_block in [0, 1] {
+			assert_noop!(
+				PoeModule::escrow_claim(
+					RuntimeOrigin::signed(ALICE),
+					vec![],
+					claim.clone(),
+					BOB,
+					release_block
+				),
+				Error::<Test>::EscrowReleaseNotInFuture
+			);
+		}
+		assert_ok!(PoeModule::escrow_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB,
+			5
+		));
+		assert_eq!(Escrows::<Test>::get(&key(&claim)), Some((BOB, 5)));
+		System::assert_last_event(
+			Event::ClaimEscrowed(ALICE, vec![], claim.clone(), BOB, 5).into(),
+		);
+
+		// 托管期间不能转移、吊销、修改或再次托管
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), CHARLIE),
+			Error::<Test>::ClaimInEscrow
+		);
+		assert_noop!(
+			PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), None),
+			Error::<Test>::ClaimInEscrow
+		);
+		assert_noop!(
+			PoeModule::set_metadata(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), vec![1]),
+			Error::<Test>::ClaimInEscrow
+		);
+		assert_noop!(
+			PoeModule::escrow_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				CHARLIE,
+				5
+			),
+			Error::<Test>::ClaimInEscrow
+		);
+
+		// 到达释放区块之前不能释放
+		assert_noop!(
+			PoeModule::release_escrow(RuntimeOrigin::signed(CHARLIE), vec![], claim.clone()),
+			Error::<Test>::EscrowNotDue
+		);
+
+		// 到达释放区块后任何人都可以释放给受益人，之后不能再取消
+		System::set_block_number(5);
+		assert_noop!(
+			PoeModule::cancel_escrow(RuntimeOrigin::signed(ALICE), vec![], claim.clone()),
+			Error::<Test>::EscrowDue
+		);
+		assert_ok!(PoeModule::release_escrow(
+			RuntimeOrigin::signed(CHARLIE),
+			vec![],
+			claim.clone()
+		));
+		System::assert_last_event(Event::EscrowReleased(vec![], claim.clone(), BOB).into());
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![BOB]);
+		assert_eq!(ClaimsByOwner::<Test>::get(BOB).into_inner(), vec![key(&claim)]);
+		assert!(ClaimsByOwner::<Test>::get(ALICE).is_empty());
+		assert!(!Escrows::<Test>::contains_key(&key(&claim)));
+		assert_noop!(
+			PoeModule::release_escrow(RuntimeOrigin::signed(CHARLIE), vec![], claim),
+			Error::<Test>::NotInEscrow
+		);
+	});
+}
+
+#[test]
+fn owners_can_cancel_an_escrow_before_release() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::escrow_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB,
+			5
+		));
+
+		assert_noop!(
+			PoeModule::cancel_escrow(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::cancel_escrow(RuntimeOrigin::signed(ALICE), vec![], claim.clone()));
+		System::assert_last_event(Event::EscrowCancelled(ALICE, vec![], claim.clone()).into());
+		assert!(!Escrows::<Test>::contains_key(&key(&claim)));
+
+		// 取消后存证恢复正常
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![CHARLIE]);
+	});
+}
+
+#[test]
+fn owners_can_cancel_an_escrow_that_cannot_be_released() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 被授权的操作者不能托管存证
+		assert_ok!(PoeModule::approve(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+		assert_noop!(
+			PoeModule::escrow_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone(), CHARLIE, 5),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::escrow_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			CHARLIE,
+			5
+		));
+
+		// 受益人的配额已满，释放必然失败，所有者可以在释放区块之后取消
+		for i in 0..5 {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(CHARLIE),
+				vec![],
+				vec![1, i],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		System::set_block_number(5);
+		assert_noop!(
+			PoeModule::release_escrow(RuntimeOrigin::signed(BOB), vec![], claim.clone()),
+			Error::<Test>::TooManyClaims
+		);
+		assert_ok!(PoeModule::cancel_escrow(RuntimeOrigin::signed(ALICE), vec![], claim.clone()));
+		System::assert_last_event(Event::EscrowCancelled(ALICE, vec![], claim.clone()).into());
+		assert!(!Escrows::<Test>::contains_key(&key(&claim)));
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![ALICE]);
+		assert_eq!(ClaimsByOwner::<Test>::get(CHARLIE).len(), 5);
+	});
+}
+
+#[test]
+fn error_codes_are_stable_and_unique() {
+	new_test_ext().execute_with(|| {
+		// 编码与最初的声明顺序一致，从 1 开始
+		assert_eq!(PoeModule::error_code(&Error::<Test>::ProofAlreadyExistOwnedByOther), 1);
+		assert_eq!(PoeModule::error_code(&Error::<Test>::ClaimTooLong), 2);
+		assert_eq!(PoeModule::error_code(&Error::<Test>::ClaimNotExist), 7);
+		assert_eq!(PoeModule::error_code(&Error::<Test>::Paused), 17);
+		assert_eq!(PoeModule::error_code(&Error::<Test>::ProofAlreadyExistOwnedBySelf), 43);
+		assert_eq!(PoeModule::error_code(&Error::<Test>::EscrowDue), 58);
+
+		// 每个错误都有编码，并且互不相同
+		let errors = Error::<Test>::type_info();
+		let variants = match errors.type_def() {
+			TypeDef::Variant(errors) => errors.variants(),
+			_ => unreachable!("errors are an enum"),
+		};
+		let mut codes: Vec<u16> = variants
+			.iter()
+			.map(|variant| {
+				let error = Error::<Test>::decode(&mut &[variant.index][..]).unwrap();
+				PoeModule::error_code(&error)
+			})
+			.collect();
+		assert!(codes.iter().all(|code| *code != 0));
+		codes.sort();
+		codes.dedup();
+		assert_eq!(codes.len(), variants.len());
+	});
+}
+
+#[test]
+fn try_create_claim_reports_failures_as_events() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::try_create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone()
+		));
+		assert_eq!(
+			Proofs::<Test>::get(&key(&claim)),
+			Some(info(ALICE, 1, Some(11), PoeModule::claim_deposit(2)))
+		);
+
+		// 失败时调用仍然成功，存储不变，并发送带有错误编码的事件
+		assert_ok!(PoeModule::try_create_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone()));
+		assert_eq!(
+			Proofs::<Test>::get(&key(&claim)),
+			Some(info(ALICE, 1, Some(11), PoeModule::claim_deposit(2)))
+		);
+		System::assert_last_event(
+			Event::ClaimFailed(
+				BOB,
+				PoeModule::error_code(&Error::<Test>::ProofAlreadyExistOwnedByOther),
+			)
+			.into(),
+		);
+
+		assert_ok!(PoeModule::try_create_claim(RuntimeOrigin::signed(BOB), vec![], vec![0; 11]));
+		System::assert_last_event(Event::ClaimFailed(BOB, 2).into());
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn recent_claims_lists_the_newest_creations_first() {
+	new_test_ext().execute_with(|| {
+		for byte in 0..5u8 {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![byte; 2],
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_eq!(NextSeq::<Test>::get(), 5);
+
+		assert_eq!(
+			PoeModule::recent_claims(3),
+			vec![
+				(4, bounded(&[4; 2]), ALICE),
+				(3, bounded(&[3; 2]), ALICE),
+				(2, bounded(&[2; 2]), ALICE)
+			]
+		);
+
+		// 吊销后的存证仍然保留在创建记录中
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], vec![4; 2], None));
+		assert_eq!(CreationLog::<Test>::get(4), Some((key(&[4; 2]), ALICE)));
+
+		// 只保留最近的 MaxRecentClaims 条记录
+		assert_eq!(CreationLog::<Test>::get(0), None);
+		assert_eq!(CreationLog::<Test>::iter().count(), 4);
+		assert_eq!(PoeModule::recent_claims(10).len(), 4);
+		assert_eq!(PoeModule::recent_claims(u32::MAX).len(), 4);
+	});
+}
+
+#[test]
+fn storage_near_capacity_is_reported_once_per_crossing() {
+	new_test_ext().execute_with(|| {
+		MaxTotalProofs::set(10);
+		CapacityWarningThresholdPercent::set(80);
+		// 每个账户最多持有 5 个存证，前 5 个由 ALICE 创建，其余由 BOB 创建
+		let create = |byte: u8| {
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(if byte < 5 { ALICE } else { BOB }),
+				vec![],
+				vec![byte; 2],
+				None,
+				None,
+				None,
+				None,
+				None,
+			)
+		};
+		let warnings = || {
+			System::events()
+				.into_iter()
+				.filter(|record| {
+					matches!(record.event, RuntimeEvent::PoeModule(Event::StorageNearCapacity(..)))
+				})
+				.count()
+		};
+
+		for byte in 0..7 {
+			assert_ok!(create(byte));
+		}
+		assert_eq!(warnings(), 0);
+
+		// 达到 80% 时告警一次
+		assert_ok!(create(7));
+		System::assert_last_event(Event::StorageNearCapacity(8, 10).into());
+		assert!(CapacityWarned::<Test>::get());
+
+		// 继续创建不会重复告警
+		assert_ok!(create(8));
+		assert_ok!(create(9));
+		assert_eq!(warnings(), 1);
+
+		// 回落到阈值以下后再次达到阈值时重新告警
+		for byte in [9, 8, 7] {
+			assert_ok!(PoeModule::revoke_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				vec![byte; 2],
+				None
+			));
+		}
+		assert!(!CapacityWarned::<Test>::get());
+		assert_ok!(create(7));
+		assert_eq!(warnings(), 2);
+	});
+}
+
+#[test]
+fn swap_claims_exchanges_owners_once_both_sides_agree() {
+	new_test_ext().execute_with(|| {
+		let a = vec![0, 1];
+		let b = vec![2, 3];
+		for (who, claim) in [(ALICE, &a), (BOB, &b)] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(who),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+
+		// ALICE 提出用 A 交换 BOB 的 B，此时还不转移
+		assert_ok!(PoeModule::swap_claims(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			a.clone(),
+			b.clone(),
+			BOB
+		));
+		assert_eq!(
+			SwapOffers::<Test>::get((key(&a), key(&b))),
+			Some((ALICE, BOB, <Test as crate::Config>::SwapOfferDeposit::get()))
+		);
+		assert_eq!(SwapOffersOf::<Test>::get(&key(&b)).into_inner(), vec![(key(&a), key(&b))]);
+		assert_eq!(Proofs::<Test>::get(&key(&a)).unwrap().owners.into_inner(), vec![ALICE]);
+		System::assert_last_event(
+			Event::SwapOffered(ALICE, BOB, vec![], a.clone(), b.clone()).into(),
+		);
+
+		// BOB 确认后双方的存证同时交换
+		assert_ok!(PoeModule::swap_claims(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			b.clone(),
+			a.clone(),
+			ALICE
+		));
+		assert_eq!(Proofs::<Test>::get(&key(&a)).unwrap().owners.into_inner(), vec![BOB]);
+		assert_eq!(Proofs::<Test>::get(&key(&b)).unwrap().owners.into_inner(), vec![ALICE]);
+		assert_eq!(ClaimsByOwner::<Test>::get(ALICE).into_inner(), vec![key(&b)]);
+		assert_eq!(ClaimsByOwner::<Test>::get(BOB).into_inner(), vec![key(&a)]);
+		assert!(!SwapOffers::<Test>::contains_key((key(&a), key(&b))));
+		assert!(!SwapOffersOf::<Test>::contains_key(&key(&a)));
+		assert!(!SwapOffersOf::<Test>::contains_key(&key(&b)));
+		System::assert_last_event(Event::ClaimsSwapped(BOB, ALICE, vec![], b, a).into());
+	});
+}
+
+#[test]
+fn swap_claims_checks_ownership_when_executed() {
+	new_test_ext().execute_with(|| {
+		let a = vec![0, 1];
+		let b = vec![2, 3];
+		for (who, claim) in [(ALICE, &a), (BOB, &b)] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(who),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		assert_noop!(
+			PoeModule::swap_claims(RuntimeOrigin::signed(ALICE), vec![], a.clone(), a.clone(), BOB),
+			Error::<Test>::InvalidSwap
+		);
+
+		// 不能用别人的存证报价
+		assert_noop!(
+			PoeModule::swap_claims(RuntimeOrigin::signed(ALICE), vec![], b.clone(), a.clone(), BOB),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			PoeModule::swap_claims(
+				RuntimeOrigin::signed(CHARLIE),
+				vec![],
+				a.clone(),
+				b.clone(),
+				BOB
+			),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_ok!(PoeModule::swap_claims(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			a.clone(),
+			b.clone(),
+			BOB
+		));
+
+		// 报价之后 ALICE 锁定了 A，确认时交换失败且不做任何修改
+		assert_ok!(PoeModule::set_transferable(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			a.clone(),
+			false
+		));
+		assert_noop!(
+			PoeModule::swap_claims(RuntimeOrigin::signed(BOB), vec![], b.clone(), a.clone(), ALICE),
+			Error::<Test>::ClaimNotTransferable
+		);
+		assert_eq!(Proofs::<Test>::get(&key(&b)).unwrap().owners.into_inner(), vec![BOB]);
+
+		// ALICE 转走 A 后报价随之作废，不会再对新的所有者生效
+		assert_ok!(PoeModule::set_transferable(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			a.clone(),
+			true
+		));
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			a.clone(),
+			CHARLIE
+		));
+		assert!(!SwapOffers::<Test>::contains_key((key(&a), key(&b))));
+		assert!(!SwapOffersOf::<Test>::contains_key(&key(&b)));
+	});
+}
+
+#[test]
+fn swap_offers_are_cancelled_capped_and_dropped_with_their_claims() {
+	new_test_ext().execute_with(|| {
+		let (a, b, c, d) = (vec![0, 1], vec![2, 3], vec![4, 5], vec![6, 7]);
+		for (who, claim) in [(ALICE, &a), (BOB, &b), (BOB, &c), (BOB, &d)] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(who),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		let offer = |claim: &Vec<u8>| {
+			PoeModule::swap_claims(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				a.clone(),
+				claim.clone(),
+				BOB,
+			)
+		};
+
+		// 只有报价人可以撤销报价
+		assert_ok!(offer(&b));
+		assert_noop!(
+			PoeModule::cancel_swap_offer(RuntimeOrigin::signed(BOB), vec![], a.clone(), b.clone()),
+			Error::<Test>::NoSwapOffer
+		);
+		assert_ok!(PoeModule::cancel_swap_offer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			a.clone(),
+			b.clone()
+		));
+		System::assert_last_event(
+			Event::SwapOfferCancelled(ALICE, vec![], a.clone(), b.clone()).into(),
+		);
+		assert!(!SwapOffers::<Test>::contains_key((key(&a), key(&b))));
+		assert!(!SwapOffersOf::<Test>::contains_key(&key(&a)));
+		assert_noop!(
+			PoeModule::cancel_swap_offer(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				a.clone(),
+				b.clone()
+			),
+			Error::<Test>::NoSwapOffer
+		);
+
+		// 一个存证最多参与 MaxSwapOffersPerClaim 个报价
+		assert_ok!(offer(&b));
+		assert_ok!(offer(&c));
+		assert_noop!(offer(&d), Error::<Test>::TooManySwapOffers);
+
+		// 想要的存证被删除后，相关报价一并作废
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(BOB), vec![], b.clone(), None));
+		assert!(!SwapOffers::<Test>::contains_key((key(&a), key(&b))));
+		assert_eq!(SwapOffersOf::<Test>::get(&key(&a)).into_inner(), vec![(key(&a), key(&c))]);
+		assert_ok!(offer(&d));
+	});
+}
+
+#[test]
+fn swap_claims_compares_normalized_claims() {
+	new_test_ext().execute_with(|| {
+		CaseInsensitiveClaims::set(true);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			b"ab".to_vec(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::swap_claims(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				b"ab".to_vec(),
+				b"AB".to_vec(),
+				BOB
+			),
+			Error::<Test>::InvalidSwap
+		);
+	});
+}
+
+#[cfg(feature = "claim-deposits")]
+#[test]
+fn swap_offers_reserve_a_deposit_until_they_are_removed() {
+	new_test_ext().execute_with(|| {
+		let (a, b) = (vec![0, 1], vec![2, 3]);
+		for (who, claim) in [(ALICE, &a), (BOB, &b)] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(who),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		let offer = || {
+			PoeModule::swap_claims(RuntimeOrigin::signed(ALICE), vec![], a.clone(), b.clone(), BOB)
+		};
+
+		assert_ok!(offer());
+		assert_eq!(Balances::reserved_balance(ALICE), 17);
+		assert_ok!(PoeModule::cancel_swap_offer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			a.clone(),
+			b.clone()
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 12);
+
+		// 再次报价不会重复收取押金，存证转移后押金退还
+		assert_ok!(offer());
+		assert_ok!(offer());
+		assert_eq!(Balances::reserved_balance(ALICE), 17);
+		assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], a, CHARLIE));
+		assert_eq!(Balances::reserved_balance(ALICE), 12);
+	});
+}
+
+#[test]
+fn namespaces_hold_at_most_max_claims_per_namespace() {
+	new_test_ext().execute_with(|| {
+		MaxClaimsPerNamespace::set(3);
+		let create = |namespace: &[u8], byte: u8| {
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				namespace.to_vec(),
+				vec![byte; 2],
+				None,
+				None,
+				None,
+				None,
+				None,
+			)
+		};
+
+		for byte in 0..3 {
+			assert_ok!(create(b"a", byte));
+		}
+		assert_eq!(NamespaceCount::<Test>::get(ns(b"a")), 3);
+		assert_noop!(create(b"a", 3), Error::<Test>::NamespaceFull);
+		assert_noop!(
+			PoeModule::create_hashed_claim(RuntimeOrigin::signed(ALICE), b"a".to_vec(), vec![3; 2]),
+			Error::<Test>::NamespaceFull
+		);
+
+		// 其他命名空间不受影响
+		assert_ok!(create(b"b", 3));
+		assert_eq!(NamespaceCount::<Test>::get(ns(b"b")), 1);
+
+		// 吊销后命名空间重新有空位
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ALICE),
+			b"a".to_vec(),
+			vec![0; 2],
+			None
+		));
+		assert_eq!(NamespaceCount::<Test>::get(ns(b"a")), 2);
+		assert_ok!(create(b"a", 4));
+		assert_ok!(PoeModule::do_try_state());
+	});
+}
+
+#[test]
+fn transfer_many_moves_each_claim_to_its_recipient() {
+	new_test_ext().execute_with(|| {
+		let claims = [vec![0, 1], vec![2, 3], vec![4, 5]];
+		for claim in &claims {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+
+		assert_ok!(PoeModule::transfer_many(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![
+				(claims[0].clone(), BOB),
+				(claims[1].clone(), CHARLIE),
+				(claims[2].clone(), TREASURY)
+			],
+		));
+		for ((claim, dest), index) in claims.iter().zip([BOB, CHARLIE, TREASURY]).zip(3..) {
+			assert_eq!(Proofs::<Test>::get(&key(claim)).unwrap().owners.into_inner(), vec![dest]);
+			System::assert_has_event(
+				Event::ClaimTransfered(ALICE, dest, vec![], claim.clone(), 1, index).into(),
+			);
+		}
+		assert!(ClaimsByOwner::<Test>::get(ALICE).is_empty());
+		assert_ok!(PoeModule::do_try_state());
+	});
+}
+
+#[test]
+fn transfer_many_reverts_the_whole_batch() {
+	new_test_ext().execute_with(|| {
+		for (who, claim) in [(ALICE, vec![0, 1]), (ALICE, vec![2, 3]), (BOB, vec![4, 5])] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(who),
+				vec![],
+				claim,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+
+		// 批量中有一个存证不属于调用者时，其他存证也不转移
+		assert_noop!(
+			PoeModule::transfer_many(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![(vec![0, 1], CHARLIE), (vec![4, 5], CHARLIE)],
+			),
+			Error::<Test>::NotAuthorized
+		);
+
+		// 转移给自己和重复的存证同样被拒绝
+		assert_noop!(
+			PoeModule::transfer_many(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![(vec![0, 1], BOB), (vec![2, 3], ALICE)],
+			),
+			Error::<Test>::CannotTransferToSelf
+		);
+		assert_noop!(
+			PoeModule::transfer_many(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![(vec![0, 1], BOB), (vec![0, 1], CHARLIE)],
+			),
+			Error::<Test>::DuplicateClaim
+		);
+		assert_eq!(ClaimsByOwner::<Test>::get(ALICE).len(), 2);
+	});
+}
+
+#[test]
+fn claims_expire_after_their_own_ttl() {
+	new_test_ext().execute_with(|| {
+		let short = vec![0, 1];
+		let default = vec![2, 3];
+		for (claim, ttl) in [(&short, Some(3)), (&default, None)] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim.clone(),
+				None,
+				None,
+				None,
+				None,
+				ttl
+			));
+		}
+		assert_eq!(Proofs::<Test>::get(&key(&short)).unwrap().expiry, Some(4));
+		assert_eq!(Proofs::<Test>::get(&key(&default)).unwrap().expiry, Some(11));
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![4, 5],
+				None,
+				None,
+				None,
+				None,
+				Some(101)
+			),
+			Error::<Test>::TtlTooLong
+		);
+		// 存活期为零的存证会在本区块的过期清理之后才过期，永远不会被清理
+		assert_noop!(
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				vec![4, 5],
+				None,
+				None,
+				None,
+				None,
+				Some(0)
+			),
+			Error::<Test>::ZeroTtl
+		);
+
+		// 自定义存活期的存证先过期
+		System::set_block_number(4);
+		PoeModule::on_initialize(4);
+		assert!(!Proofs::<Test>::contains_key(&key(&short)));
+		assert!(Proofs::<Test>::contains_key(&key(&default)));
+
+		// 使用默认存活期的存证按默认时间过期
+		System::set_block_number(11);
+		PoeModule::on_initialize(11);
+		assert!(!Proofs::<Test>::contains_key(&key(&default)));
+	});
+}
+
+#[test]
+fn lifecycle_events_are_numbered_in_order() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(BOB),
+			vec![],
+			claim.clone(),
+			None
+		));
+
+		// 重新创建和放弃存证同样改变存证集合，也占用序号
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::renounce_claim(RuntimeOrigin::signed(ALICE), vec![], claim));
+
+		// 同一区块内的事件序号严格递增
+		let indices: Vec<u64> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::PoeModule(Event::ClaimCreated(.., index))
+				| RuntimeEvent::PoeModule(Event::ClaimTransfered(.., index))
+				| RuntimeEvent::PoeModule(Event::ClaimRevoked(.., index))
+				| RuntimeEvent::PoeModule(Event::ClaimRecreated(.., index))
+				| RuntimeEvent::PoeModule(Event::ClaimRenounced(.., index)) => Some(index),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(indices, vec![0, 1, 2, 3, 4]);
+		assert_eq!(EventSeq::<Test>::get(), 5);
+	});
+}
+
+#[test]
+fn ownership_challenge_is_derived_from_claim_owner_and_epoch() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_eq!(PoeModule::ownership_challenge(vec![], claim.clone(), None), None);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 按文档独立推导挑战值
+		let expected = |epoch: u64| {
+			let claim_hash = H256(sp_io::hashing::blake2_256(&claim));
+			let challenge =
+				(b"pallet-poe::ownership".to_vec(), Vec::<u8>::new(), claim_hash, ALICE, epoch);
+			sp_io::hashing::blake2_256(&challenge.encode())
+		};
+		System::set_block_number(9);
+		assert_eq!(PoeModule::ownership_challenge(vec![], claim.clone(), None), Some(expected(0)));
+
+		// 进入下一个周期后挑战值随之改变
+		System::set_block_number(10);
+		assert_eq!(PoeModule::ownership_challenge(vec![], claim.clone(), None), Some(expected(1)));
+		assert_ne!(expected(0), expected(1));
+		assert_eq!(PoeModule::ownership_challenge(b"a".to_vec(), claim, None), None);
+	});
+}
+
+#[test]
+fn case_insensitive_claims_collide_with_their_case_variants() {
+	new_test_ext().execute_with(|| {
+		let create = |who: u64, claim: &[u8]| {
+			PoeModule::create_claim(
+				RuntimeOrigin::signed(who),
+				vec![],
+				claim.to_vec(),
+				None,
+				None,
+				None,
+				None,
+				None,
+			)
+		};
+
+		// 未开启时大小写不同的存证互不影响
+		RequireUtf8::set(true);
+		assert_ok!(create(ALICE, b"Hello"));
+		assert_ok!(create(ALICE, b"hello"));
+
+		// 开启后按小写形式存储，大小写不同的存证视为同一个
+		CaseInsensitiveClaims::set(true);
+		assert_ok!(create(ALICE, b"World"));
+		assert!(Proofs::<Test>::contains_key(&key(b"world")));
+		assert_noop!(create(BOB, b"world"), Error::<Test>::ProofAlreadyExistOwnedByOther);
+		assert_noop!(create(ALICE, b"WORLD"), Error::<Test>::ProofAlreadyExistOwnedBySelf);
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			b"wOrLd".to_vec(),
+			None
+		));
+	});
+}
+
+#[test]
+fn private_claims_are_only_visible_to_owners_and_viewers() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_noop!(
+			PoeModule::set_private(RuntimeOrigin::signed(BOB), vec![], claim.clone(), true),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::set_private(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			true
+		));
+		assert_ok!(PoeModule::add_viewer(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+		assert_noop!(
+			PoeModule::add_viewer(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::AlreadyViewer
+		);
+
+		let active = ClaimStatus::Active { owner: ALICE, expires_in: Some(10) };
+		assert_eq!(PoeModule::claim_status(vec![], claim.clone(), Some(ALICE)), active);
+		assert_eq!(PoeModule::claim_status(vec![], claim.clone(), Some(BOB)), active);
+		assert_eq!(PoeModule::get_claim(vec![], claim.clone(), Some(BOB)), Some((ALICE, 1)));
+		assert_eq!(
+			PoeModule::claim_status(vec![], claim.clone(), Some(CHARLIE)),
+			ClaimStatus::NotFound
+		);
+		assert_eq!(PoeModule::claim_status(vec![], claim.clone(), None), ClaimStatus::NotFound);
+		assert_eq!(PoeModule::get_claim(vec![], claim.clone(), Some(CHARLIE)), None);
+		assert!(PoeModule::claims_with_prefix(&[0], 10).is_empty());
+
+		// 其他只读接口同样对陌生人隐藏私有存证
+		assert!(PoeModule::claim_exists(vec![], claim.clone(), Some(BOB)));
+		assert!(!PoeModule::claim_exists(vec![], claim.clone(), Some(CHARLIE)));
+		assert!(!PoeModule::claim_exists(vec![], claim.clone(), None));
+		assert_eq!(PoeModule::claim_history(vec![], claim.clone(), Some(BOB)), vec![(ALICE, 1)]);
+		assert!(PoeModule::claim_history(vec![], claim.clone(), Some(CHARLIE)).is_empty());
+		assert!(PoeModule::ownership_challenge(vec![], claim.clone(), Some(ALICE)).is_some());
+		assert_eq!(PoeModule::ownership_challenge(vec![], claim.clone(), Some(CHARLIE)), None);
+		assert!(PoeModule::recent_claims(10).is_empty());
+
+		// 移除查看者后只有所有者还能查到
+		assert_ok!(PoeModule::remove_viewer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_noop!(
+			PoeModule::remove_viewer(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::NotViewer
+		);
+		assert_eq!(
+			PoeModule::claim_status(vec![], claim.clone(), Some(BOB)),
+			ClaimStatus::NotFound
+		);
+		assert_eq!(PoeModule::claim_status(vec![], claim.clone(), Some(ALICE)), active);
+
+		// 重新公开后所有人都能查到
+		assert_ok!(PoeModule::set_private(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			false
+		));
+		assert_eq!(PoeModule::claim_status(vec![], claim.clone(), Some(CHARLIE)), active);
+		assert!(PoeModule::claim_exists(vec![], claim.clone(), Some(CHARLIE)));
+		assert_eq!(PoeModule::recent_claims(10), vec![(0, bounded(&claim), ALICE)]);
+		System::assert_last_event(Event::ClaimPrivacySet(ALICE, vec![], claim, false).into());
+	});
+}
+
+#[test]
+fn viewers_are_capped_and_cleared_with_the_claim() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		assert_ok!(PoeModule::add_viewer(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB));
+		assert_ok!(PoeModule::add_viewer(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			CHARLIE
+		));
+		assert_noop!(
+			PoeModule::add_viewer(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), TREASURY),
+			Error::<Test>::TooManyViewers
+		);
+
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None
+		));
+		assert!(!Viewers::<Test>::contains_key(&key(&claim)));
+	});
+}
+
+#[test]
+fn revocations_record_their_reason_code() {
+	new_test_ext().execute_with(|| {
+		for claim in [vec![0, 1], vec![0, 2], vec![0, 3]] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+
+		assert_ok!(PoeModule::revoke_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 1],
+			Some(RevokeReason::ERASURE_REQUEST)
+		));
+		System::assert_last_event(
+			Event::ClaimRevoked(ALICE, ALICE, vec![], vec![0, 1], 1, 7, 3).into(),
+		);
+
+		// 未给出原因时记录为 0
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], vec![0, 2], None));
+		System::assert_last_event(
+			Event::ClaimRevoked(ALICE, ALICE, vec![], vec![0, 2], 1, RevokeReason::UNSPECIFIED, 4)
+				.into(),
+		);
+
+		assert_ok!(PoeModule::force_revoke(
+			RuntimeOrigin::root(),
+			vec![],
+			vec![0, 3],
+			Some(RevokeReason::LEGAL_ORDER)
+		));
+		System::assert_last_event(Event::ClaimForceRevoked(vec![], vec![0, 3], 6, 5).into());
+	});
+}
+
+#[test]
+fn claim_history_keeps_only_the_most_recent_owners() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 依次转移给四个账户，存证共经历五个所有者
+		let mut owner = ALICE;
+		for (block, dest) in [(2, BOB), (3, CHARLIE), (4, TREASURY), (5, ALICE)] {
+			System::set_block_number(block);
+			assert_ok!(PoeModule::transfer_claim(
+				RuntimeOrigin::signed(owner),
+				vec![],
+				claim.clone(),
+				dest
+			));
+			owner = dest;
+		}
+
+		assert_eq!(<Test as crate::Config>::MaxHistoryLength::get(), 3);
+		assert_eq!(
+			PoeModule::claim_history(vec![], claim, None),
+			vec![(CHARLIE, 3), (TREASURY, 4), (ALICE, 5)]
+		);
+	});
+}
+
+#[test]
+fn claim_ids_increase_and_are_never_reused() {
+	new_test_ext().execute_with(|| {
+		for claim in [vec![0, 1], vec![0, 2]] {
+			assert_ok!(PoeModule::create_claim(
+				RuntimeOrigin::signed(ALICE),
+				vec![],
+				claim,
+				None,
+				None,
+				None,
+				None,
+				None
+			));
+		}
+		let first = PoeModule::claim_id(vec![], vec![0, 1]).unwrap();
+		let second = PoeModule::claim_id(vec![], vec![0, 2]).unwrap();
+		assert!(second > first);
+
+		// 转移不改变编号
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 2],
+			BOB
+		));
+		assert_eq!(PoeModule::claim_id(vec![], vec![0, 2]), Some(second));
+
+		// 吊销后的编号不会再分配
+		assert_ok!(PoeModule::revoke_claim(RuntimeOrigin::signed(ALICE), vec![], vec![0, 1], None));
+		assert_eq!(PoeModule::claim_id(vec![], vec![0, 1]), None);
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			vec![0, 3],
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+		let third = PoeModule::claim_id(vec![], vec![0, 3]).unwrap();
+		assert!(third > first && third > second);
+		assert!(matches!(
+			System::events().last().map(|record| record.event.clone()),
+			Some(RuntimeEvent::PoeModule(Event::ClaimCreated(.., id, _))) if id == third
+		));
+	});
+}
+
+#[test]
+fn transfers_respect_the_recipient_opt_out() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0, 1];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		assert_ok!(PoeModule::set_accepts_claims(RuntimeOrigin::signed(BOB), false));
+		System::assert_last_event(Event::AcceptsClaimsSet(BOB, false).into());
+		assert_eq!(AcceptsClaims::<Test>::get(BOB), Some(false));
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), BOB),
+			Error::<Test>::RecipientRefusesClaims
+		);
+
+		assert_ok!(PoeModule::set_accepts_claims(RuntimeOrigin::signed(BOB), true));
+		assert_ok!(PoeModule::transfer_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			BOB
+		));
+		assert_eq!(Proofs::<Test>::get(&key(&claim)).unwrap().owners.into_inner(), vec![BOB]);
+
+		// 默认拒绝时，只有明确同意接收的账户才能收到存证
+		DefaultAcceptsClaims::set(false);
+		assert_noop!(
+			PoeModule::transfer_claim(RuntimeOrigin::signed(BOB), vec![], claim.clone(), CHARLIE),
+			Error::<Test>::RecipientRefusesClaims
+		);
+		assert_ok!(PoeModule::set_accepts_claims(RuntimeOrigin::signed(ALICE), true));
+		assert_ok!(PoeModule::transfer_claim(RuntimeOrigin::signed(BOB), vec![], claim, ALICE));
+	});
+}
+
+#[test]
+fn on_idle_purges_tombstones_within_the_weight_budget() {
+	new_test_ext().execute_with(|| {
+		let claims: Vec<Vec<u8>> = (0u8..10).map(|i| vec![i; 2]).collect();
+		for claim in &claims {
+			PoeModule::bury(&key(claim), 1);
+		}
+		assert_eq!(TombstoneQueueBounds::<Test>::get(), (0, 10));
+
+		// 预算不够读取队列边界时什么也不做
+		let too_small = <() as WeightInfo>::on_idle(0).saturating_sub(Weight::from_ref_time(1));
+		assert_eq!(PoeModule::on_idle(1, too_small), Weight::zero());
+		assert_eq!(Tombstones::<Test>::iter().count(), 10);
+
+		// 只清理预算允许的数量，且不超过预算
+		let budget = <() as WeightInfo>::on_idle(3).saturating_add(Weight::from_ref_time(1));
+		let used = PoeModule::on_idle(1, budget);
+		assert!(used.ref_time() <= budget.ref_time());
+		assert_eq!(used, <() as WeightInfo>::on_idle(3));
+		assert_eq!(Tombstones::<Test>::iter().count(), 7);
+		assert!(!Tombstones::<Test>::contains_key(&key(&claims[0])));
+		assert!(Tombstones::<Test>::contains_key(&key(&claims[3])));
+
+		// 之后的空闲调用继续清理剩下的墓碑
+		let mut calls = 0;
+		while Tombstones::<Test>::iter().count() > 0 {
+			assert!(PoeModule::on_idle(1, budget).ref_time() <= budget.ref_time());
+			calls += 1;
+		}
+		assert_eq!(calls, 3);
+		assert_eq!(TombstoneQueue::<Test>::iter().count(), 0);
+		assert_eq!(TombstoneQueueBounds::<Test>::get(), (10, 10));
+		assert_eq!(PoeModule::on_idle(1, budget), <() as WeightInfo>::on_idle(0));
+
+		// 墓碑被清理后存证可以重新创建
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claims[0].clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 保留期内的墓碑不会被清理
+		TombstoneRetention::set(5);
+		assert_ok!(PoeModule::renounce_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claims[0].clone()
+		));
+		PoeModule::on_idle(5, budget);
+		assert!(Tombstones::<Test>::contains_key(&key(&claims[0])));
+		PoeModule::on_idle(6, budget);
+		assert!(!Tombstones::<Test>::contains_key(&key(&claims[0])));
+	});
+}
+
+#[test]
+fn patch_claim_supersedes_with_the_patched_bytes() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![1, 2, 3, 4, 5, 6];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		// 保留前四个字节，追加一个新字节，再重复开头的两个字节
+		let patch = patch::encode(&[
+			PatchOp::Copy { offset: 0, len: 4 },
+			PatchOp::Insert(vec![9]),
+			PatchOp::Copy { offset: 0, len: 2 },
+		]);
+		let mut patched = claim[..4].to_vec();
+		patched.push(9);
+		patched.extend_from_slice(&claim[..2]);
+		assert_eq!(patch::apply(&claim, &patch, 10), Ok(patched.clone()));
+
+		assert_noop!(
+			PoeModule::patch_claim(
+				RuntimeOrigin::signed(BOB),
+				vec![],
+				claim.clone(),
+				patch.clone()
+			),
+			Error::<Test>::NotClaimOwner
+		);
+		assert_ok!(PoeModule::patch_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			patch
+		));
+		System::assert_last_event(
+			Event::ClaimPatched(ALICE, vec![], claim.clone(), patched.clone(), 1).into(),
+		);
+
+		// 与 supersede_claim 一样，新存证取代旧存证并由调用者拥有
+		assert!(!Proofs::<Test>::contains_key(&key(&claim)));
+		assert_eq!(Proofs::<Test>::get(&key(&patched)).unwrap().owners.into_inner(), vec![ALICE]);
+		assert_eq!(Superseded::<Test>::get(&key(&claim)), Some(bounded(&patched)));
+		#[cfg(feature = "claim-deposits")]
+		assert_eq!(Balances::reserved_balance(ALICE), 17);
+	});
+}
+
+#[test]
+fn patch_claim_rejects_bad_patches() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![1, 2, 3, 4, 5, 6];
+		assert_ok!(PoeModule::create_claim(
+			RuntimeOrigin::signed(ALICE),
+			vec![],
+			claim.clone(),
+			None,
+			None,
+			None,
+			None,
+			None
+		));
+
+		let patch_claim = |patch: Vec<u8>| {
+			PoeModule::patch_claim(RuntimeOrigin::signed(ALICE), vec![], claim.clone(), patch)
+		};
+		assert_noop!(patch_claim(vec![0; 11]), Error::<Test>::PatchTooLong);
+		assert_noop!(patch_claim(vec![0xff]), Error::<Test>::InvalidPatch);
+		assert_noop!(
+			patch_claim(patch::encode(&[PatchOp::Copy { offset: 4, len: 3 }])),
+			Error::<Test>::InvalidPatch
+		);
+		assert_noop!(
+			patch_claim(patch::encode(&[
+				PatchOp::Copy { offset: 0, len: 6 },
+				PatchOp::Copy { offset: 0, len: 5 },
+			])),
+			Error::<Test>::PatchResultTooLong
+		);
+	});
+}
@@ -0,0 +1,1844 @@
+//! Weights for pallet_poe
+//!
+//! HAND-ESTIMATED PLACEHOLDER WEIGHTS, NOT BENCHMARKED.
+//!
+//! The numbers below were picked by hand from the storage each call touches; they were never
+//! measured. Replace this file with the output of the benchmark command below, run on reference
+//! hardware, before relying on these weights on a live chain.
+//!
+//! Until then the runtime does not use [`SubstrateWeight`] and falls back to the `()`
+//! implementation.
+
+// Command to generate real weights:
+// ./target/release/node-template
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_poe
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --output=pallets/poe/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_poe.
+pub trait WeightInfo {
+	fn create_claim(l: u32, c: u32, ) -> Weight;
+	fn revoke_claim(l: u32, ) -> Weight;
+	fn transfer_claim(l: u32, ) -> Weight;
+	fn create_claims(c: u32, ) -> Weight;
+	fn revoke_claims(c: u32, ) -> Weight;
+	fn propose_transfer(l: u32, ) -> Weight;
+	fn accept_transfer(l: u32, ) -> Weight;
+	fn cancel_transfer(l: u32, ) -> Weight;
+	fn force_revoke(l: u32, ) -> Weight;
+	fn create_hashed_claim(l: u32, ) -> Weight;
+	fn revoke_hashed_claim(l: u32, ) -> Weight;
+	fn approve(l: u32, ) -> Weight;
+	fn cancel_approval(l: u32, ) -> Weight;
+	fn renew_claim(l: u32, ) -> Weight;
+	fn renounce_claim(l: u32, ) -> Weight;
+	fn set_metadata(l: u32, ) -> Weight;
+	fn set_transferable(l: u32, ) -> Weight;
+	fn add_owner(l: u32, ) -> Weight;
+	fn remove_owner(l: u32, ) -> Weight;
+	fn add_creator() -> Weight;
+	fn remove_creator() -> Weight;
+	fn add_revoke_delegate(l: u32, ) -> Weight;
+	fn remove_revoke_delegate(l: u32, ) -> Weight;
+	fn revoke_my_expired(r: u32, ) -> Weight;
+	fn transfer_claim_with_metadata(l: u32, m: u32, ) -> Weight;
+	fn set_account_quota() -> Weight;
+	fn revoke_claim_by_hash() -> Weight;
+	fn create_claims_best_effort(c: u32, ) -> Weight;
+	fn set_frozen(l: u32, ) -> Weight;
+	fn create_claim_if_absent(l: u32, ) -> Weight;
+	fn supersede_claim(l: u32, ) -> Weight;
+	fn allow_transfer_to(l: u32, ) -> Weight;
+	fn disallow_transfer_to(l: u32, ) -> Weight;
+	fn claim_transfer(l: u32, ) -> Weight;
+	fn force_revoke_and_slash(l: u32, ) -> Weight;
+	fn transfer_all_claims(c: u32, ) -> Weight;
+	fn register_namespace() -> Weight;
+	fn release_namespace() -> Weight;
+	fn emit_proof_count() -> Weight;
+	fn set_content_type(l: u32, ) -> Weight;
+	fn patch_claim(l: u32, ) -> Weight;
+	fn set_label(l: u32, ) -> Weight;
+	fn escrow_claim(l: u32, ) -> Weight;
+	fn release_escrow(l: u32, ) -> Weight;
+	fn cancel_escrow(l: u32, ) -> Weight;
+	fn offer_swap(l: u32, ) -> Weight;
+	fn swap_claims(l: u32, ) -> Weight;
+	fn transfer_many(c: u32, ) -> Weight;
+	fn set_private(l: u32, ) -> Weight;
+	fn add_viewer(l: u32, ) -> Weight;
+	fn remove_viewer(l: u32, ) -> Weight;
+	fn set_accepts_claims() -> Weight;
+	fn cancel_swap_offer() -> Weight;
+	fn set_paused() -> Weight;
+	fn report_expiring_soon() -> Weight;
+	fn on_initialize(r: u32, ) -> Weight;
+	fn on_idle(t: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_poe, estimated by hand rather than benchmarked.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule Proofs (r:2 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule CapacityWarned (r:1 w:1)
+	// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule Superseded (r:0 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:1 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:2)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	/// The range of component `c` is `[0, 999]`.
+	fn create_claim(l: u32, c: u32, ) -> Weight {
+		Weight::from_ref_time(60_102_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(Weight::from_ref_time(61_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(21 as u64))
+			.saturating_add(T::DbWeight::get().writes(20 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn revoke_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(45_518_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn transfer_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(31_906_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule Proofs (r:32 w:32)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule CapacityWarned (r:1 w:1)
+	// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:32 w:32)
+	// Storage: PoeModule Superseded (r:0 w:32)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:32 w:32)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:64)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:32)
+	/// The range of component `c` is `[1, 32]`.
+	fn create_claims(c: u32, ) -> Weight {
+		Weight::from_ref_time(39_084_000 as u64)
+			.saturating_add(Weight::from_ref_time(29_804_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+			.saturating_add(T::DbWeight::get().writes((8 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:32 w:32)
+	// Storage: PoeModule FrozenClaims (r:32 w:32)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:32)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:32 w:32)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:32)
+	// Storage: PoeModule RevokeDelegates (r:0 w:32)
+	// Storage: PoeModule TransferAllowList (r:0 w:32)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `c` is `[1, 32]`.
+	fn revoke_claims(c: u32, ) -> Weight {
+		Weight::from_ref_time(25_806_000 as u64)
+			.saturating_add(Weight::from_ref_time(31_457_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().writes((7 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn propose_transfer(l: u32, ) -> Weight {
+		Weight::from_ref_time(18_205_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn accept_transfer(l: u32, ) -> Weight {
+		Weight::from_ref_time(36_118_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn cancel_transfer(l: u32, ) -> Weight {
+		Weight::from_ref_time(19_073_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule FrozenClaims (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn force_revoke(l: u32, ) -> Weight {
+		Weight::from_ref_time(41_310_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule HashedProofs (r:1 w:1)
+	// Storage: PoeModule Tombstones (r:1 w:0)
+	// Storage: PoeModule HashedClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:0)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule CapacityWarned (r:1 w:1)
+	// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn create_hashed_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(47_322_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule HashedProofs (r:1 w:1)
+	// Storage: PoeModule HashedClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn revoke_hashed_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(33_609_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn approve(l: u32, ) -> Weight {
+		Weight::from_ref_time(19_342_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn cancel_approval(l: u32, ) -> Weight {
+		Weight::from_ref_time(20_118_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ExpiringAt (r:2 w:2)
+	/// The range of component `l` is `[4, 512]`.
+	fn renew_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(27_916_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule Tombstones (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn renounce_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(49_106_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(14 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_metadata(l: u32, ) -> Weight {
+		Weight::from_ref_time(21_487_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_transferable(l: u32, ) -> Weight {
+		Weight::from_ref_time(20_934_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn add_owner(l: u32, ) -> Weight {
+		Weight::from_ref_time(27_461_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn remove_owner(l: u32, ) -> Weight {
+		Weight::from_ref_time(26_874_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PoeModule AllowedCreators (r:0 w:1)
+	fn add_creator() -> Weight {
+		Weight::from_ref_time(14_106_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule AllowedCreators (r:1 w:1)
+	fn remove_creator() -> Weight {
+		Weight::from_ref_time(17_352_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule RevokeDelegates (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn add_revoke_delegate(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_733_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule RevokeDelegates (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn remove_revoke_delegate(l: u32, ) -> Weight {
+		Weight::from_ref_time(25_091_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1000 w:1000)
+	// Storage: PoeModule FrozenClaims (r:1000 w:1000)
+	// Storage: PoeModule ExpiringAt (r:1000 w:1000)
+	// Storage: PoeModule PendingTransfers (r:0 w:1000)
+	// Storage: System Account (r:1000 w:1000)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1000)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:1000)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1000)
+	// Storage: PoeModule TransferAllowList (r:0 w:1000)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `r` is `[0, 1000]`.
+	fn revoke_my_expired(r: u32, ) -> Weight {
+		Weight::from_ref_time(21_560_000 as u64)
+			.saturating_add(Weight::from_ref_time(27_913_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((5 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((10 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	/// The range of component `m` is `[0, 256]`.
+	fn transfer_claim_with_metadata(l: u32, m: u32, ) -> Weight {
+		Weight::from_ref_time(33_517_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: PoeModule AccountQuota (r:0 w:1)
+	fn set_account_quota() -> Weight {
+		Weight::from_ref_time(14_208_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule HashedProofs (r:1 w:1)
+	// Storage: PoeModule HashedClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	fn revoke_claim_by_hash() -> Weight {
+		Weight::from_ref_time(32_581_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule Proofs (r:32 w:32)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule CapacityWarned (r:1 w:1)
+	// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:32 w:32)
+	// Storage: PoeModule Superseded (r:0 w:32)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:32 w:32)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:64)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:32)
+	/// The range of component `c` is `[1, 32]`.
+	fn create_claims_best_effort(c: u32, ) -> Weight {
+		Weight::from_ref_time(35_112_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_655_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+			.saturating_add(T::DbWeight::get().writes((8 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_frozen(l: u32, ) -> Weight {
+		Weight::from_ref_time(17_402_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule CapacityWarned (r:1 w:1)
+	// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule Superseded (r:0 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:1 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:2)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn create_claim_if_absent(l: u32, ) -> Weight {
+		Weight::from_ref_time(53_127_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(20 as u64))
+			.saturating_add(T::DbWeight::get().writes(20 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:2 w:2)
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	// Storage: PoeModule Tombstones (r:1 w:0)
+	// Storage: PoeModule ClaimHistory (r:1 w:2)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:1 w:1)
+	// Storage: PoeModule Superseded (r:0 w:2)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule Viewers (r:1 w:2)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:2)
+	/// The range of component `l` is `[4, 512]`.
+	fn supersede_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(61_435_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(T::DbWeight::get().writes(26 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule TransferAllowList (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn allow_transfer_to(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_915_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule TransferAllowList (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn disallow_transfer_to(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_402_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule TransferAllowList (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn claim_transfer(l: u32, ) -> Weight {
+		Weight::from_ref_time(36_870_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule FrozenClaims (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn force_revoke_and_slash(l: u32, ) -> Weight {
+		Weight::from_ref_time(46_526_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(14 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule Proofs (r:1000 w:1000)
+	// Storage: PoeModule FrozenClaims (r:1000 w:0)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:0 w:1000)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1000)
+	// Storage: PoeModule ClaimHistory (r:1000 w:1000)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1000)
+	// Storage: PoeModule TransferAllowList (r:0 w:1000)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `c` is `[0, 1000]`.
+	fn transfer_all_claims(c: u32, ) -> Weight {
+		Weight::from_ref_time(21_604_000 as u64)
+			.saturating_add(Weight::from_ref_time(27_318_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((7 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	fn register_namespace() -> Weight {
+		Weight::from_ref_time(20_861_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	fn release_namespace() -> Weight {
+		Weight::from_ref_time(21_337_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PoeModule ProofCount (r:1 w:0)
+	fn emit_proof_count() -> Weight {
+		Weight::from_ref_time(9_472_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_content_type(l: u32, ) -> Weight {
+		Weight::from_ref_time(21_912_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:2 w:2)
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	// Storage: PoeModule Tombstones (r:1 w:0)
+	// Storage: PoeModule ClaimHistory (r:1 w:2)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:1 w:1)
+	// Storage: PoeModule Superseded (r:0 w:2)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule Viewers (r:1 w:2)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:2)
+	/// The range of component `l` is `[4, 512]`.
+	fn patch_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(64_018_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(T::DbWeight::get().writes(26 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule Labels (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_label(l: u32, ) -> Weight {
+		Weight::from_ref_time(21_604_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn escrow_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_118_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule Escrows (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn release_escrow(l: u32, ) -> Weight {
+		Weight::from_ref_time(33_245_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn cancel_escrow(l: u32, ) -> Weight {
+		Weight::from_ref_time(19_873_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule SwapOffers (r:2 w:1)
+	// Storage: PoeModule SwapOffersOf (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn offer_swap(l: u32, ) -> Weight {
+		Weight::from_ref_time(27_914_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule SwapOffers (r:1 w:1)
+	// Storage: PoeModule SwapOffersOf (r:4 w:2)
+	// Storage: PoeModule Proofs (r:2 w:2)
+	// Storage: PoeModule FrozenClaims (r:2 w:0)
+	// Storage: PoeModule Escrows (r:2 w:0)
+	// Storage: System Account (r:2 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule PendingTransfers (r:0 w:2)
+	// Storage: PoeModule ClaimApprovals (r:2 w:2)
+	// Storage: PoeModule ClaimHistory (r:2 w:2)
+	// Storage: PoeModule RevokeDelegates (r:0 w:2)
+	// Storage: PoeModule TransferAllowList (r:0 w:2)
+	// Storage: PoeModule AccountQuota (r:2 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn swap_claims(l: u32, ) -> Weight {
+		Weight::from_ref_time(66_208_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(23 as u64))
+			.saturating_add(T::DbWeight::get().writes(19 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:32 w:32)
+	// Storage: PoeModule FrozenClaims (r:32 w:0)
+	// Storage: PoeModule Escrows (r:32 w:0)
+	// Storage: System Account (r:32 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:33 w:33)
+	// Storage: PoeModule PendingTransfers (r:0 w:32)
+	// Storage: PoeModule ClaimApprovals (r:32 w:32)
+	// Storage: PoeModule ClaimHistory (r:32 w:32)
+	// Storage: PoeModule RevokeDelegates (r:0 w:32)
+	// Storage: PoeModule TransferAllowList (r:0 w:32)
+	// Storage: PoeModule AccountQuota (r:32 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `c` is `[1, 32]`.
+	fn transfer_many(c: u32, ) -> Weight {
+		Weight::from_ref_time(14_212_000 as u64)
+			.saturating_add(Weight::from_ref_time(27_384_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((7 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_private(l: u32, ) -> Weight {
+		Weight::from_ref_time(22_815_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule Viewers (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn add_viewer(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_377_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule Viewers (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn remove_viewer(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_102_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AcceptsClaims (r:0 w:1)
+	fn set_accepts_claims() -> Weight {
+		Weight::from_ref_time(12_403_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule SwapOffers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule SwapOffersOf (r:2 w:2)
+	fn cancel_swap_offer() -> Weight {
+		Weight::from_ref_time(24_117_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: PoeModule Paused (r:0 w:1)
+	fn set_paused() -> Weight {
+		Weight::from_ref_time(12_410_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	fn report_expiring_soon() -> Weight {
+		Weight::from_ref_time(15_204_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Storage: PoeModule MigrationCursor (r:1 w:0)
+	// Storage: PoeModule ExpiryCursor (r:1 w:0)
+	// Storage: PoeModule ExpiringAt (r:2 w:1)
+	// Storage: PoeModule Proofs (r:64 w:64)
+	// Storage: PoeModule ClaimsByOwner (r:64 w:64)
+	// Storage: PoeModule PendingTransfers (r:0 w:64)
+	// Storage: System Account (r:64 w:64)
+	// Storage: PoeModule ClaimApprovals (r:0 w:64)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:64)
+	// Storage: PoeModule RevokeDelegates (r:0 w:64)
+	// Storage: PoeModule TransferAllowList (r:0 w:64)
+	// Storage: PoeModule FrozenClaims (r:0 w:64)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `r` is `[0, 64]`.
+	fn on_initialize(r: u32, ) -> Weight {
+		Weight::from_ref_time(3_215_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_742_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((5 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((11 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: PoeModule TombstoneQueueBounds (r:1 w:1)
+	// Storage: PoeModule TombstoneQueue (r:64 w:64)
+	// Storage: PoeModule Tombstones (r:64 w:64)
+	/// The range of component `t` is `[0, 64]`.
+	fn on_idle(t: u32, ) -> Weight {
+		Weight::from_ref_time(2_104_000 as u64)
+			.saturating_add(Weight::from_ref_time(9_873_000 as u64).saturating_mul(t as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(t as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule Proofs (r:2 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule CapacityWarned (r:1 w:1)
+	// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule Superseded (r:0 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:1 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:2)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	/// The range of component `c` is `[0, 999]`.
+	fn create_claim(l: u32, c: u32, ) -> Weight {
+		Weight::from_ref_time(60_102_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(Weight::from_ref_time(61_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(21 as u64))
+			.saturating_add(RocksDbWeight::get().writes(20 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn revoke_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(45_518_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(13 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn transfer_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(31_906_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule Proofs (r:32 w:32)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule CapacityWarned (r:1 w:1)
+	// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:32 w:32)
+	// Storage: PoeModule Superseded (r:0 w:32)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:32 w:32)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:64)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:32)
+	/// The range of component `c` is `[1, 32]`.
+	fn create_claims(c: u32, ) -> Weight {
+		Weight::from_ref_time(39_084_000 as u64)
+			.saturating_add(Weight::from_ref_time(29_804_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(16 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes((8 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:32 w:32)
+	// Storage: PoeModule FrozenClaims (r:32 w:32)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:32)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:32 w:32)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:32)
+	// Storage: PoeModule RevokeDelegates (r:0 w:32)
+	// Storage: PoeModule TransferAllowList (r:0 w:32)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `c` is `[1, 32]`.
+	fn revoke_claims(c: u32, ) -> Weight {
+		Weight::from_ref_time(25_806_000 as u64)
+			.saturating_add(Weight::from_ref_time(31_457_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes((7 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn propose_transfer(l: u32, ) -> Weight {
+		Weight::from_ref_time(18_205_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn accept_transfer(l: u32, ) -> Weight {
+		Weight::from_ref_time(36_118_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn cancel_transfer(l: u32, ) -> Weight {
+		Weight::from_ref_time(19_073_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule FrozenClaims (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn force_revoke(l: u32, ) -> Weight {
+		Weight::from_ref_time(41_310_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(13 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule HashedProofs (r:1 w:1)
+	// Storage: PoeModule Tombstones (r:1 w:0)
+	// Storage: PoeModule HashedClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:0)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule CapacityWarned (r:1 w:1)
+	// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn create_hashed_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(47_322_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(17 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule HashedProofs (r:1 w:1)
+	// Storage: PoeModule HashedClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn revoke_hashed_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(33_609_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn approve(l: u32, ) -> Weight {
+		Weight::from_ref_time(19_342_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn cancel_approval(l: u32, ) -> Weight {
+		Weight::from_ref_time(20_118_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ExpiringAt (r:2 w:2)
+	/// The range of component `l` is `[4, 512]`.
+	fn renew_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(27_916_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule Tombstones (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn renounce_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(49_106_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(14 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_metadata(l: u32, ) -> Weight {
+		Weight::from_ref_time(21_487_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_transferable(l: u32, ) -> Weight {
+		Weight::from_ref_time(20_934_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn add_owner(l: u32, ) -> Weight {
+		Weight::from_ref_time(27_461_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn remove_owner(l: u32, ) -> Weight {
+		Weight::from_ref_time(26_874_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PoeModule AllowedCreators (r:0 w:1)
+	fn add_creator() -> Weight {
+		Weight::from_ref_time(14_106_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule AllowedCreators (r:1 w:1)
+	fn remove_creator() -> Weight {
+		Weight::from_ref_time(17_352_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule RevokeDelegates (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn add_revoke_delegate(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_733_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule RevokeDelegates (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn remove_revoke_delegate(l: u32, ) -> Weight {
+		Weight::from_ref_time(25_091_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1000 w:1000)
+	// Storage: PoeModule FrozenClaims (r:1000 w:1000)
+	// Storage: PoeModule ExpiringAt (r:1000 w:1000)
+	// Storage: PoeModule PendingTransfers (r:0 w:1000)
+	// Storage: System Account (r:1000 w:1000)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1000)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:1000)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1000)
+	// Storage: PoeModule TransferAllowList (r:0 w:1000)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `r` is `[0, 1000]`.
+	fn revoke_my_expired(r: u32, ) -> Weight {
+		Weight::from_ref_time(21_560_000 as u64)
+			.saturating_add(Weight::from_ref_time(27_913_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((5 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((10 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	/// The range of component `m` is `[0, 256]`.
+	fn transfer_claim_with_metadata(l: u32, m: u32, ) -> Weight {
+		Weight::from_ref_time(33_517_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: PoeModule AccountQuota (r:0 w:1)
+	fn set_account_quota() -> Weight {
+		Weight::from_ref_time(14_208_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule HashedProofs (r:1 w:1)
+	// Storage: PoeModule HashedClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	fn revoke_claim_by_hash() -> Weight {
+		Weight::from_ref_time(32_581_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule Proofs (r:32 w:32)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule CapacityWarned (r:1 w:1)
+	// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:32 w:32)
+	// Storage: PoeModule Superseded (r:0 w:32)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:32 w:32)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:64)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:32)
+	/// The range of component `c` is `[1, 32]`.
+	fn create_claims_best_effort(c: u32, ) -> Weight {
+		Weight::from_ref_time(35_112_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_655_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes((8 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_frozen(l: u32, ) -> Weight {
+		Weight::from_ref_time(17_402_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AllowedCreators (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule CapacityWarned (r:1 w:1)
+	// Storage: PoeModule ClaimsThisBlock (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule Superseded (r:0 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:1 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:2)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn create_claim_if_absent(l: u32, ) -> Weight {
+		Weight::from_ref_time(53_127_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(20 as u64))
+			.saturating_add(RocksDbWeight::get().writes(20 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:2 w:2)
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	// Storage: PoeModule Tombstones (r:1 w:0)
+	// Storage: PoeModule ClaimHistory (r:1 w:2)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:1 w:1)
+	// Storage: PoeModule Superseded (r:0 w:2)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule Viewers (r:1 w:2)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:2)
+	/// The range of component `l` is `[4, 512]`.
+	fn supersede_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(61_435_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(18 as u64))
+			.saturating_add(RocksDbWeight::get().writes(26 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule TransferAllowList (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn allow_transfer_to(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_915_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule TransferAllowList (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn disallow_transfer_to(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_402_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule TransferAllowList (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn claim_transfer(l: u32, ) -> Weight {
+		Weight::from_ref_time(36_870_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule FrozenClaims (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn force_revoke_and_slash(l: u32, ) -> Weight {
+		Weight::from_ref_time(46_526_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(14 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule Proofs (r:1000 w:1000)
+	// Storage: PoeModule FrozenClaims (r:1000 w:0)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule PendingTransfers (r:0 w:1000)
+	// Storage: PoeModule ClaimApprovals (r:0 w:1000)
+	// Storage: PoeModule ClaimHistory (r:1000 w:1000)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1000)
+	// Storage: PoeModule TransferAllowList (r:0 w:1000)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `c` is `[0, 1000]`.
+	fn transfer_all_claims(c: u32, ) -> Weight {
+		Weight::from_ref_time(21_604_000 as u64)
+			.saturating_add(Weight::from_ref_time(27_318_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((7 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	fn register_namespace() -> Weight {
+		Weight::from_ref_time(20_861_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule NamespaceOwners (r:1 w:1)
+	// Storage: PoeModule NamespacesOwned (r:1 w:1)
+	fn release_namespace() -> Weight {
+		Weight::from_ref_time(21_337_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PoeModule ProofCount (r:1 w:0)
+	fn emit_proof_count() -> Weight {
+		Weight::from_ref_time(9_472_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_content_type(l: u32, ) -> Weight {
+		Weight::from_ref_time(21_912_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:2 w:2)
+	// Storage: PoeModule FrozenClaims (r:1 w:1)
+	// Storage: PoeModule Tombstones (r:1 w:0)
+	// Storage: PoeModule ClaimHistory (r:1 w:2)
+	// Storage: PoeModule ExpiringAt (r:1 w:1)
+	// Storage: PoeModule ClaimsByOwner (r:1 w:1)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule ClaimGenerations (r:1 w:1)
+	// Storage: PoeModule Superseded (r:0 w:2)
+	// Storage: PoeModule NextClaimId (r:1 w:1)
+	// Storage: PoeModule ClaimIds (r:0 w:1)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	// Storage: PoeModule Viewers (r:1 w:2)
+	// Storage: PoeModule NextSeq (r:1 w:1)
+	// Storage: PoeModule CreationLog (r:0 w:2)
+	/// The range of component `l` is `[4, 512]`.
+	fn patch_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(64_018_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(18 as u64))
+			.saturating_add(RocksDbWeight::get().writes(26 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule Labels (r:0 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_label(l: u32, ) -> Weight {
+		Weight::from_ref_time(21_604_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn escrow_claim(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_118_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule Escrows (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule PendingTransfers (r:0 w:1)
+	// Storage: PoeModule ClaimApprovals (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:1 w:1)
+	// Storage: PoeModule RevokeDelegates (r:0 w:1)
+	// Storage: PoeModule TransferAllowList (r:0 w:1)
+	// Storage: PoeModule AccountQuota (r:1 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn release_escrow(l: u32, ) -> Weight {
+		Weight::from_ref_time(33_245_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:1)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn cancel_escrow(l: u32, ) -> Weight {
+		Weight::from_ref_time(19_873_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule SwapOffers (r:2 w:1)
+	// Storage: PoeModule SwapOffersOf (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn offer_swap(l: u32, ) -> Weight {
+		Weight::from_ref_time(27_914_000 as u64)
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule SwapOffers (r:1 w:1)
+	// Storage: PoeModule SwapOffersOf (r:4 w:2)
+	// Storage: PoeModule Proofs (r:2 w:2)
+	// Storage: PoeModule FrozenClaims (r:2 w:0)
+	// Storage: PoeModule Escrows (r:2 w:0)
+	// Storage: System Account (r:2 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:2 w:2)
+	// Storage: PoeModule PendingTransfers (r:0 w:2)
+	// Storage: PoeModule ClaimApprovals (r:2 w:2)
+	// Storage: PoeModule ClaimHistory (r:2 w:2)
+	// Storage: PoeModule RevokeDelegates (r:0 w:2)
+	// Storage: PoeModule TransferAllowList (r:0 w:2)
+	// Storage: PoeModule AccountQuota (r:2 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn swap_claims(l: u32, ) -> Weight {
+		Weight::from_ref_time(66_208_000 as u64)
+			.saturating_add(Weight::from_ref_time(4_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(23 as u64))
+			.saturating_add(RocksDbWeight::get().writes(19 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:32 w:32)
+	// Storage: PoeModule FrozenClaims (r:32 w:0)
+	// Storage: PoeModule Escrows (r:32 w:0)
+	// Storage: System Account (r:32 w:0)
+	// Storage: PoeModule ClaimsByOwner (r:33 w:33)
+	// Storage: PoeModule PendingTransfers (r:0 w:32)
+	// Storage: PoeModule ClaimApprovals (r:32 w:32)
+	// Storage: PoeModule ClaimHistory (r:32 w:32)
+	// Storage: PoeModule RevokeDelegates (r:0 w:32)
+	// Storage: PoeModule TransferAllowList (r:0 w:32)
+	// Storage: PoeModule AccountQuota (r:32 w:0)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `c` is `[1, 32]`.
+	fn transfer_many(c: u32, ) -> Weight {
+		Weight::from_ref_time(14_212_000 as u64)
+			.saturating_add(Weight::from_ref_time(27_384_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((7 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	/// The range of component `l` is `[4, 512]`.
+	fn set_private(l: u32, ) -> Weight {
+		Weight::from_ref_time(22_815_000 as u64)
+			.saturating_add(Weight::from_ref_time(3_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule Viewers (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn add_viewer(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_377_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule Proofs (r:1 w:0)
+	// Storage: PoeModule FrozenClaims (r:1 w:0)
+	// Storage: PoeModule Escrows (r:1 w:0)
+	// Storage: PoeModule Viewers (r:1 w:1)
+	/// The range of component `l` is `[4, 512]`.
+	fn remove_viewer(l: u32, ) -> Weight {
+		Weight::from_ref_time(24_102_000 as u64)
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(l as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule AcceptsClaims (r:0 w:1)
+	fn set_accepts_claims() -> Weight {
+		Weight::from_ref_time(12_403_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Paused (r:1 w:0)
+	// Storage: PoeModule SwapOffers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule SwapOffersOf (r:2 w:2)
+	fn cancel_swap_offer() -> Weight {
+		Weight::from_ref_time(24_117_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: PoeModule Paused (r:0 w:1)
+	fn set_paused() -> Weight {
+		Weight::from_ref_time(12_410_000 as u64)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PoeModule Proofs (r:1 w:0)
+	fn report_expiring_soon() -> Weight {
+		Weight::from_ref_time(15_204_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	// Storage: PoeModule MigrationCursor (r:1 w:0)
+	// Storage: PoeModule ExpiryCursor (r:1 w:0)
+	// Storage: PoeModule ExpiringAt (r:2 w:1)
+	// Storage: PoeModule Proofs (r:64 w:64)
+	// Storage: PoeModule ClaimsByOwner (r:64 w:64)
+	// Storage: PoeModule PendingTransfers (r:0 w:64)
+	// Storage: System Account (r:64 w:64)
+	// Storage: PoeModule ClaimApprovals (r:0 w:64)
+	// Storage: PoeModule ProofCount (r:1 w:1)
+	// Storage: PoeModule NamespaceCount (r:1 w:1)
+	// Storage: PoeModule ClaimHistory (r:0 w:64)
+	// Storage: PoeModule RevokeDelegates (r:0 w:64)
+	// Storage: PoeModule TransferAllowList (r:0 w:64)
+	// Storage: PoeModule FrozenClaims (r:0 w:64)
+	// Storage: PoeModule EventSeq (r:1 w:1)
+	/// The range of component `r` is `[0, 64]`.
+	fn on_initialize(r: u32, ) -> Weight {
+		Weight::from_ref_time(3_215_000 as u64)
+			.saturating_add(Weight::from_ref_time(30_742_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((5 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((11 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: PoeModule TombstoneQueueBounds (r:1 w:1)
+	// Storage: PoeModule TombstoneQueue (r:64 w:64)
+	// Storage: PoeModule Tombstones (r:64 w:64)
+	/// The range of component `t` is `[0, 64]`.
+	fn on_idle(t: u32, ) -> Weight {
+		Weight::from_ref_time(2_104_000 as u64)
+			.saturating_add(Weight::from_ref_time(9_873_000 as u64).saturating_mul(t as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(t as u64)))
+	}
+}
@@ -15,9 +15,10 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+		NumberFor, One, Verify,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
 use sp_std::prelude::*;
@@ -188,7 +189,7 @@ impl frame_system::Config for Runtime {
 	/// What to do if a new account is created.
 	type OnNewAccount = ();
 	/// What to do if an account is fully reaped from the system.
-	type OnKilledAccount = ();
+	type OnKilledAccount = PeoModule;
 	/// The data to be stored in an account.
 	type AccountData = pallet_balances::AccountData<Balance>;
 	/// Weight information for the extrinsics of this pallet.
@@ -275,10 +276,75 @@ impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+parameter_types! {
+	pub PoeFeeDestination: AccountId =
+		frame_support::PalletId(*b"py/poefe").into_account_truncating();
+}
+
 /// Configure the pallet-poe in pallets/poe.
 impl pallet_poe::Config for Runtime {
 	type MaxClaimLength = ConstU32<512>;
+	type ClaimData = frame_support::BoundedVec<u8, ConstU32<512>>;
+	type ProofsHasher = frame_support::Blake2_128Concat;
+	type MaxNamespaceLength = ConstU32<32>;
+	type MinClaimLength = ConstU32<4>;
+	type DefaultClaimExpiry = ConstU32<{ 365 * DAYS }>;
+	type MaxClaimsPerExpiryBlock = ConstU32<256>;
+	type MaxExpiredPerBlock = ConstU32<64>;
+	type MaxMetadataLength = ConstU32<256>;
+	type MaxClaimsPerAccount = ConstU32<1_000>;
+	type MaxBatchSize = ConstU32<32>;
 	type RuntimeEvent = RuntimeEvent;
+	// pallet_poe::weights has not been benchmarked yet; switch to its SubstrateWeight once
+	//   weights.rs is regenerated on reference hardware.
+	type WeightInfo = ();
+	type Deposits = pallet_poe::deposits::HoldDeposits<Balances, ()>;
+	type ClaimBaseDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+	type ClaimByteDeposit = ConstU128<{ EXISTENTIAL_DEPOSIT / 50 }>;
+	type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxTotalProofs = ConstU32<1_000_000>;
+	type MaxClaimsPerBlockPerAccount = ConstU32<16>;
+	type ExpiryWarningWindow = ConstU32<HOURS>;
+	type UnsignedPriority = ConstU64<{ TransactionPriority::MAX / 2 }>;
+	type MaxOwners = ConstU32<8>;
+	type CreatorAdminOrigin = frame_system::EnsureRoot<AccountId>;
+	type EnforceCreatorWhitelist = frame_support::traits::ConstBool<false>;
+	type MaxHistoryLength = ConstU32<32>;
+	type FeeDestination = PoeFeeDestination;
+	type ClaimCreationFee = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type TransferLockPeriod = ConstU32<HOURS>;
+	type MaxDelegates = ConstU32<8>;
+	type DefaultAccountQuota = ConstU32<100>;
+	type QuotaOrigin = frame_system::EnsureRoot<AccountId>;
+	type FreezeOrigin = frame_system::EnsureRoot<AccountId>;
+	type CreateOrigin = frame_system::EnsureSigned<AccountId>;
+	type UnixTime = Timestamp;
+	type RequireRecipientExists = frame_support::traits::ConstBool<true>;
+	type RequireUtf8 = frame_support::traits::ConstBool<false>;
+	type MaxAllowed = ConstU32<8>;
+	type OnClaimTransfer = ();
+	type MaxNamespacesPerAccount = ConstU32<16>;
+	type TransferCooldown = ConstU32<HOURS>;
+	type CapacityWarningThresholdPercent = ConstU8<90>;
+	type MaxClaimsPerNamespace = ConstU32<100_000>;
+	type MaxClaimTtl = ConstU32<{ 10 * 365 * DAYS }>;
+	type ChallengeEpochLength = ConstU32<HOURS>;
+	type CaseInsensitiveClaims = frame_support::traits::ConstBool<false>;
+	type MaxViewers = ConstU32<16>;
+	type MigrationBatchSize = ConstU32<1_000>;
+	type DefaultAcceptsClaims = frame_support::traits::ConstBool<true>;
+	type TombstoneRetention = ConstU32<{ 30 * DAYS }>;
+	type SwapOfferDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type MaxSwapOffersPerClaim = ConstU32<16>;
+	type MaxRecentClaims = ConstU32<1_000>;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -319,6 +385,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_poe::signed_extensions::CheckClaimLength<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -333,8 +400,29 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations applied on the next runtime upgrade.
+pub type Migrations = (
+	pallet_poe::migrations::MigrateToV2<Runtime>,
+	pallet_poe::migrations::MigrateToV3<Runtime>,
+	pallet_poe::migrations::MigrateToV4<Runtime>,
+	pallet_poe::migrations::MigrateToV5<Runtime>,
+	pallet_poe::migrations::MigrateToV6<Runtime>,
+	pallet_poe::migrations::MigrateToV7<Runtime>,
+	pallet_poe::migrations::MigrateToV8<Runtime>,
+	pallet_poe::migrations::MigrateToV9<Runtime>,
+	pallet_poe::migrations::MigrateToV10<Runtime>,
+	pallet_poe::migrations::MigrateToV11<Runtime>,
+	pallet_poe::migrations::MigrateToV12<Runtime>,
+	pallet_poe::migrations::MigrateToV13<Runtime>,
+	pallet_poe::migrations::MigrateToV14<Runtime>,
+	pallet_poe::migrations::MigrateToV15<Runtime>,
+	pallet_poe::migrations::MigrateToV16<Runtime>,
+	pallet_poe::migrations::MigrateToV17<Runtime>,
+);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -347,6 +435,7 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_template, TemplateModule]
+		[pallet_poe, PeoModule]
 	);
 }
 
@@ -466,6 +555,78 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_poe_runtime_api::PoeApi<Block, AccountId, BlockNumber, Balance> for Runtime {
+		fn get_claim(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<AccountId>,
+		) -> Option<(AccountId, BlockNumber)> {
+			PeoModule::get_claim(namespace, claim, viewer)
+		}
+
+		fn claim_exists(namespace: Vec<u8>, claim: Vec<u8>, viewer: Option<AccountId>) -> bool {
+			PeoModule::claim_exists(namespace, claim, viewer)
+		}
+
+		fn claim_history(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<AccountId>,
+		) -> Vec<(AccountId, BlockNumber)> {
+			PeoModule::claim_history(namespace, claim, viewer)
+		}
+
+		fn claims_with_prefix(prefix: Vec<u8>, limit: u32) -> Vec<(Vec<u8>, AccountId)> {
+			PeoModule::claims_with_prefix(&prefix, limit)
+				.into_iter()
+				.map(|(claim, owner)| (claim.into(), owner))
+				.collect()
+		}
+
+		fn claim_status(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<AccountId>,
+		) -> pallet_poe_runtime_api::ClaimStatus<AccountId, BlockNumber> {
+			PeoModule::claim_status(namespace, claim, viewer)
+		}
+
+		fn list_claims(
+			start_key: Option<Vec<u8>>,
+			limit: u32,
+		) -> (Vec<(Vec<u8>, AccountId, BlockNumber)>, Option<Vec<u8>>) {
+			let (claims, cursor) = PeoModule::list_claims(start_key, limit);
+			let claims = claims
+				.into_iter()
+				.map(|(claim, owner, created_at)| (claim.into(), owner, created_at))
+				.collect();
+			(claims, cursor)
+		}
+
+		fn simulate_create(
+			who: AccountId,
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+		) -> pallet_poe_runtime_api::SimulateResult<Balance> {
+			PeoModule::simulate_create(who, namespace, claim)
+		}
+
+		fn recent_claims(count: u32) -> Vec<(u64, Vec<u8>, AccountId)> {
+			PeoModule::recent_claims(count)
+				.into_iter()
+				.map(|(seq, claim, creator)| (seq, claim.into(), creator))
+				.collect()
+		}
+
+		fn ownership_challenge(
+			namespace: Vec<u8>,
+			claim: Vec<u8>,
+			viewer: Option<AccountId>,
+		) -> Option<[u8; 32]> {
+			PeoModule::ownership_challenge(namespace, claim, viewer)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -596,4 +757,56 @@ mod tests {
 			whitelist.contains("26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7")
 		);
 	}
+
+	#[test]
+	fn poe_api_reads_stored_claims() {
+		use pallet_poe_runtime_api::PoeApi;
+
+		let owner = AccountId::from([1u8; 32]);
+		let storage = GenesisConfig {
+			peo_module: PeoModuleConfig {
+				initial_claims: vec![(b"proof".to_vec(), owner.clone())],
+			},
+			..Default::default()
+		}
+		.build_storage()
+		.unwrap();
+
+		sp_io::TestExternalities::new(storage).execute_with(|| {
+			assert_eq!(
+				<Runtime as PoeApi<Block, AccountId, BlockNumber, Balance>>::get_claim(
+					vec![],
+					b"proof".to_vec(),
+					None
+				),
+				Some((owner.clone(), 0))
+			);
+			assert_eq!(
+				<Runtime as PoeApi<Block, AccountId, BlockNumber, Balance>>::claim_history(
+					vec![],
+					b"proof".to_vec(),
+					None
+				),
+				vec![(owner.clone(), 0)]
+			);
+			assert_eq!(
+				<Runtime as PoeApi<Block, AccountId, BlockNumber, Balance>>::claim_status(
+					vec![],
+					b"proof".to_vec(),
+					None
+				),
+				pallet_poe_runtime_api::ClaimStatus::Active { owner, expires_in: None }
+			);
+			assert!(<Runtime as PoeApi<Block, AccountId, BlockNumber, Balance>>::claim_exists(
+				vec![],
+				b"proof".to_vec(),
+				None
+			));
+			assert!(!<Runtime as PoeApi<Block, AccountId, BlockNumber, Balance>>::claim_exists(
+				vec![],
+				b"missing".to_vec(),
+				None
+			));
+		});
+	}
 }